@@ -11,12 +11,31 @@
 # except according to those terms.
 
 # This script uses the following Unicode tables:
+# - DerivedCoreProperties.txt
 # - EastAsianWidth.txt
+# - PropList.txt
 # - ReadMe.txt
+# - Scripts.txt
 # - UnicodeData.txt
+# - emoji/emoji-data.txt
 #
 # Since this should not require frequent updates, we just store this
 # out-of-line and check the generated module into git.
+#
+# To review a Unicode version bump, run `unicode.py --diff OLD_DIR` in the
+# directory holding the new tables, where `OLD_DIR` holds the old ones.
+#
+# To generate a trimmed module for applications which only display a known set
+# of characters, run `unicode.py --subset WHITELIST [FALLBACK]`. See
+# `subset_main` for the whitelist format.
+#
+# By default, the tables are fetched from the latest version of Unicode and
+# written to `tables.rs`, which the crate uses unless a version feature is
+# enabled. To generate the module for a specific version, run
+# `unicode.py --version X.Y.Z` in an empty directory, which fetches the tables
+# of that version instead, checks that they're the version requested, and
+# writes them to `tables_X_Y.rs`. Each such module is selected by the crate's
+# `unicode-X-Y` feature (see the `tables` module declarations in `lib.rs`).
 
 import enum
 import math
@@ -55,25 +74,61 @@ codepoint and those tables offsets are stored according to `offset_type`.
 
 If this is edited, you must ensure that `emit_module` reflects your changes."""
 
+FAST_PATH_END = 0xA0
+"""Codepoints below this are measured by the hardcoded fast path in the emitted `width`
+function.
+
+If this is edited, you must ensure that `emit_module` and `fast_path_width` reflect your changes."""
+
+LATIN1_END = 0x100
+"""Codepoints from `FAST_PATH_END` up to (but excluding) this are measured by the emitted
+`width` function using the single-level `LATIN1_TABLE`. Together with the hardcoded fast path,
+this means the multi-level lookup table entries of codepoints below `LATIN1_END` are never read.
+
+If this is edited, you must ensure that `emit_module` reflects your changes."""
+
 MODULE_FILENAME = "tables.rs"
 """The filename of the emitted Rust module (will be created in the working directory)"""
 
+
+def versioned_module_filename(version: str) -> str:
+    """Returns the filename of the emitted Rust module for Unicode `version` (such as `"15.1.0"`),
+    which is selected by the crate's corresponding `unicode-X-Y` feature."""
+    [major, minor, _] = version.split(".")
+    return f"tables_{major}_{minor}.rs"
+
+UCD_URL = "http://www.unicode.org/Public/UNIDATA/"
+"""The URL of the directory which Unicode tables missing from the working directory are fetched
+from. This is the latest version of Unicode unless `pin_version` changes it."""
+
+PINNED_VERSION = None
+"""The Unicode version which `main` must generate the module for, if `pin_version` was called."""
+
 Codepoint = int
 BitPos = int
 
 
 def fetch_open(filename: str):
     """Opens `filename` and return its corresponding file object. If `filename` isn't on disk,
-    fetches it from `http://www.unicode.org/Public/UNIDATA/`. Exits with code 1 on failure."""
+    fetches it from `UCD_URL`. Exits with code 1 on failure."""
     if not os.path.exists(os.path.basename(filename)):
-        os.system(f"curl -O http://www.unicode.org/Public/UNIDATA/{filename}")
+        os.system(f"curl -O {UCD_URL}{filename}")
     try:
-        return open(filename, encoding="utf-8")
+        return open(os.path.basename(filename), encoding="utf-8")
     except OSError:
         sys.stderr.write(f"cannot load {filename}")
         sys.exit(1)
 
 
+def pin_version(version: str):
+    """Makes `fetch_open` fetch the tables of Unicode `version` (such as `"15.1.0"`) rather than
+    the latest ones, and makes `main` check that the tables it's given are that version."""
+    global UCD_URL, PINNED_VERSION
+    PINNED_VERSION = tuple(map(int, version.split(".")))
+    assert len(PINNED_VERSION) == 3, "the version must be of the form X.Y.Z"
+    UCD_URL = f"http://www.unicode.org/Public/{version}/ucd/"
+
+
 def load_unicode_version() -> "tuple[int, int, int]":
     """Returns the current Unicode version by fetching and processing `ReadMe.txt`."""
     with fetch_open("ReadMe.txt") as readme:
@@ -95,28 +150,27 @@ class EffectiveWidth(enum.IntEnum):
     """ Two columns wide in a CJK context. One column wide in all other contexts. """
 
 
-def load_east_asian_widths() -> "list[EffectiveWidth]":
-    """Return a list of effective widths, indexed by codepoint.
-    Widths are determined by fetching and parsing `EastAsianWidth.txt`.
+EAW_CATEGORIES = {
+    "N": "Neutral",
+    "Na": "Narrow",
+    "W": "Wide",
+    "F": "FullWidth",
+    "H": "HalfWidth",
+    "A": "Ambiguous",
+}
+"""Maps each East Asian Width category code to the name of its `EastAsianWidth` variant."""
 
-    `Neutral`, `Narrow`, and `Halfwidth` characters are assigned `EffectiveWidth.NARROW`.
-
-    `Wide` and `Fullwidth` characters are assigned `EffectiveWidth.WIDE`.
 
-    `Ambiguous` chracters are assigned `EffectiveWidth.AMBIGUOUS`."""
+def load_east_asian_width_categories() -> "list[str]":
+    """Returns a list `l` where `l[c]` is the East Asian Width category code (such as `"Na"`)
+    of codepoint `c`, determined by fetching and parsing `EastAsianWidth.txt`. Codepoints which
+    aren't listed are given the category `"N"` (Neutral)."""
     with fetch_open("EastAsianWidth.txt") as eaw:
         # matches a width assignment for a single codepoint, i.e. "1F336;N  # ..."
         single = re.compile(r"^([0-9A-F]+);(\w+) +# (\w+)")
         # matches a width assignment for a range of codepoints, i.e. "3001..3003;W  # ..."
         multiple = re.compile(r"^([0-9A-F]+)\.\.([0-9A-F]+);(\w+) +# (\w+)")
-        # map between width category code and condensed width
-        width_codes = {
-            **{c: EffectiveWidth.NARROW for c in ["N", "Na", "H"]},
-            **{c: EffectiveWidth.WIDE for c in ["W", "F"]},
-            "A": EffectiveWidth.AMBIGUOUS,
-        }
-
-        width_map = []
+        cat_map = []
         current = 0
         for line in eaw.readlines():
             raw_data = None  # (low, high, width)
@@ -128,55 +182,164 @@ def load_east_asian_widths() -> "list[EffectiveWidth]":
                 continue
             low = int(raw_data[0], 16)
             high = int(raw_data[1], 16)
-            width = width_codes[raw_data[2]]
+            cat = raw_data[2]
 
             assert current <= high
             while current <= high:
                 # Some codepoints don't fall into any of the ranges in EastAsianWidth.txt.
-                # All such codepoints are implicitly given Neural width (resolves to narrow)
-                width_map.append(EffectiveWidth.NARROW if current < low else width)
+                # All such codepoints are implicitly given Neutral width
+                cat_map.append("N" if current < low else cat)
                 current += 1
 
-        while len(width_map) < NUM_CODEPOINTS:
-            # Catch any leftover codepoints and assign them implicit Neutral/narrow width.
-            width_map.append(EffectiveWidth.NARROW)
+        while len(cat_map) < NUM_CODEPOINTS:
+            # Catch any leftover codepoints and assign them implicit Neutral width.
+            cat_map.append("N")
 
-        return width_map
+        return cat_map
 
 
-def load_zero_widths() -> "list[bool]":
-    """Returns a list `l` where `l[c]` is true if codepoint `c` is considered a zero-width
-    character. `c` is considered a zero-width character if `c` is in general categories
-    `Cc`, `Cf`, `Mn`, or `Me` (determined by fetching and processing `UnicodeData.txt`)."""
-    with fetch_open("UnicodeData.txt") as categories:
-        zw_map = []
+def load_east_asian_widths() -> "list[EffectiveWidth]":
+    """Return a list of effective widths, indexed by codepoint.
+    Widths are determined by fetching and parsing `EastAsianWidth.txt`.
+
+    `Neutral`, `Narrow`, and `Halfwidth` characters are assigned `EffectiveWidth.NARROW`.
+
+    `Wide` and `Fullwidth` characters are assigned `EffectiveWidth.WIDE`.
+
+    `Ambiguous` chracters are assigned `EffectiveWidth.AMBIGUOUS`."""
+    # map between width category code and condensed width
+    width_codes = {
+        **{c: EffectiveWidth.NARROW for c in ["N", "Na", "H"]},
+        **{c: EffectiveWidth.WIDE for c in ["W", "F"]},
+        "A": EffectiveWidth.AMBIGUOUS,
+    }
+    return [width_codes[cat] for cat in load_east_asian_width_categories()]
+
+
+def load_unicode_data_field(field: int, unassigned: str) -> "list[str]":
+    """Returns a list `l` where `l[c]` is the value of the `field`th (0-based) field of codepoint
+    `c`'s entry in `UnicodeData.txt`, determined by fetching and processing that file.
+    Unassigned codepoints are given the value `unassigned`."""
+    with fetch_open("UnicodeData.txt") as entries:
+        value_map = []
         current = 0
-        for line in categories.readlines():
+        for line in entries.readlines():
             if len(raw_data := line.split(";")) != 15:
                 continue
-            [codepoint, name, cat_code] = [
+            [codepoint, name, value] = [
                 int(raw_data[0], 16),
                 raw_data[1],
-                raw_data[2],
+                raw_data[field],
             ]
-            zero_width = cat_code in ["Cc", "Cf", "Mn", "Me"]
 
             assert current <= codepoint
             while current <= codepoint:
                 if name.endswith(", Last>") or current == codepoint:
-                    # if name ends with Last, we backfill the width value to all codepoints since
+                    # if name ends with Last, we backfill the value to all codepoints since
                     # the previous codepoint (aka the start of the range)
-                    zw_map.append(zero_width)
+                    value_map.append(value)
                 else:
-                    # unassigned characters are implicitly given Neutral width, which is nonzero
-                    zw_map.append(False)
+                    # codepoints skipped over by UnicodeData.txt are unassigned
+                    value_map.append(unassigned)
                 current += 1
 
-        while len(zw_map) < NUM_CODEPOINTS:
-            # Catch any leftover codepoints. They must be unassigned (so nonzero width)
-            zw_map.append(False)
+        while len(value_map) < NUM_CODEPOINTS:
+            # Catch any leftover codepoints. They must be unassigned.
+            value_map.append(unassigned)
 
-        return zw_map
+        return value_map
+
+
+def load_general_categories() -> "list[str]":
+    """Returns a list `l` where `l[c]` is the two-letter general category code of codepoint `c`
+    (determined by fetching and processing `UnicodeData.txt`). Unassigned codepoints are given
+    the category `Cn`."""
+    return load_unicode_data_field(2, "Cn")
+
+
+def load_zero_widths() -> "list[bool]":
+    """Returns a list `l` where `l[c]` is true if codepoint `c` is considered a zero-width
+    character. `c` is considered a zero-width character if `c` is in general categories
+    `Cc`, `Cf`, `Mn`, or `Me` (determined by fetching and processing `UnicodeData.txt`).
+    Unassigned characters are implicitly given Neutral width, which is nonzero.
+
+    The spacing marks (`Mc`) in the Musical Symbols block are also zero-width: despite their
+    category, stems, flags, and augmentation dots are drawn onto the preceding note head."""
+    return [
+        cat in ["Cc", "Cf", "Mn", "Me"]
+        or (cat == "Mc" and 0x1D100 <= codepoint <= 0x1D1FF)
+        for codepoint, cat in enumerate(load_general_categories())
+    ]
+
+
+def load_assigned() -> "list[bool]":
+    """Returns a list `l` where `l[c]` is true if codepoint `c` is assigned, meaning that its
+    general category (determined by fetching and processing `UnicodeData.txt`) isn't `Cn`.
+    Private-use codepoints and surrogates are considered assigned; noncharacters aren't."""
+    return [cat != "Cn" for cat in load_general_categories()]
+
+
+def load_marks() -> "list[bool]":
+    """Returns a list `l` where `l[c]` is true if codepoint `c` is a combining mark, meaning that its
+    general category (determined by fetching and processing `UnicodeData.txt`) is `Mn`, `Mc`, or
+    `Me`."""
+    return [cat in ["Mn", "Mc", "Me"] for cat in load_general_categories()]
+
+
+def load_strong_rtl() -> "list[bool]":
+    """Returns a list `l` where `l[c]` is true if codepoint `c` is a strong right-to-left
+    character, meaning that its bidi class (determined by fetching and processing
+    `UnicodeData.txt`) is `R` or `AL`. Unassigned codepoints are never strong right-to-left."""
+    return [bidi_class in ["R", "AL"] for bidi_class in load_unicode_data_field(4, "")]
+
+
+def load_property(filename: str, property_name: str) -> "list[bool]":
+    """Returns a list `l` where `l[c]` is true if codepoint `c` has the binary property
+    `property_name`, determined by fetching and processing the UCD file `filename`."""
+    with fetch_open(filename) as properties:
+        # matches a property assignment for a codepoint or range, i.e. "200B..200F    ; Prop # ..."
+        assignment = re.compile(r"^([0-9A-F]+)(?:\.\.([0-9A-F]+))?\s*;\s*(\w+)")
+        prop_map = [False] * NUM_CODEPOINTS
+        for line in properties.readlines():
+            if not (match := assignment.match(line)) or match.group(3) != property_name:
+                continue
+            low = int(match.group(1), 16)
+            high = int(match.group(2) or match.group(1), 16)
+            for codepoint in range(low, high + 1):
+                prop_map[codepoint] = True
+        return prop_map
+
+
+def load_scripts() -> "list[str]":
+    """Returns a list `l` where `l[c]` is the name of codepoint `c`'s script (such as `"Latin"`),
+    determined by fetching and parsing `Scripts.txt`. Codepoints which aren't listed are given
+    the script `"Unknown"`."""
+    with fetch_open("Scripts.txt") as scripts:
+        # matches a script assignment for a codepoint or range, i.e. "0041..005A    ; Latin # ..."
+        assignment = re.compile(r"^([0-9A-F]+)(?:\.\.([0-9A-F]+))?\s*;\s*(\w+)")
+        script_map = ["Unknown"] * NUM_CODEPOINTS
+        for line in scripts.readlines():
+            if not (match := assignment.match(line)):
+                continue
+            low = int(match.group(1), 16)
+            high = int(match.group(2) or match.group(1), 16)
+            for codepoint in range(low, high + 1):
+                script_map[codepoint] = match.group(3)
+        return script_map
+
+
+def to_ranges(flags: "list[bool]") -> "list[tuple[Codepoint, Codepoint]]":
+    """Returns the inclusive `(low, high)` codepoint ranges for which `flags` is true, in
+    increasing order. Surrogates are skipped, since they can't be represented as a Rust `char`."""
+    ranges = []
+    for (codepoint, flag) in enumerate(flags):
+        if not flag or 0xD800 <= codepoint <= 0xDFFF:
+            continue
+        if ranges and ranges[-1][1] == codepoint - 1:
+            ranges[-1] = (ranges[-1][0], codepoint)
+        else:
+            ranges.append((codepoint, codepoint))
+    return ranges
 
 
 class Bucket:
@@ -322,11 +485,196 @@ def make_tables(
     return tables
 
 
+def table_lookup(tables: "list[Table]", codepoint: Codepoint) -> EffectiveWidth:
+    """Returns the width stored for `codepoint` in the multi-level lookup table `tables`, as built
+    by `make_tables` (and before `indices_to_widths` is called). This mirrors the emitted
+    `lookup_width` function."""
+    offset = 0
+    for table in tables:
+        num_bits = table.cap_bit - table.low_bit
+        index = (codepoint >> table.low_bit) & ((1 << num_bits) - 1)
+        offset = table.entries[(offset << num_bits) + index]
+    return tables[-1].buckets()[offset].width()
+
+
+def fnv1a_64(data: "list[int]") -> int:
+    """Returns the 64-bit FNV-1a hash of the bytes in `data`."""
+    result = 0xCBF29CE484222325
+    for byte in data:
+        result ^= byte
+        result = (result * 0x100000001B3) & 0xFFFFFFFFFFFFFFFF
+    return result
+
+
+def to_category_ranges(
+    categories: "list[str]", default: str
+) -> "list[tuple[Codepoint, Codepoint, str]]":
+    """Returns the maximal inclusive ranges of consecutive codepoints which share a category
+    other than `default`, as `(low, high, category)` entries."""
+    ranges = []
+    for codepoint, cat in enumerate(categories):
+        if cat == default:
+            continue
+        assert not 0xD800 <= codepoint <= 0xDFFF, "surrogates can't be chars"
+        if ranges and ranges[-1][1] == codepoint - 1 and ranges[-1][2] == cat:
+            ranges[-1] = (ranges[-1][0], codepoint, cat)
+        else:
+            ranges.append((codepoint, codepoint, cat))
+    return ranges
+
+
+CJK_PUNCTUATION_RANGES = [
+    (0x2014, 0x2015),  # EM DASH, HORIZONTAL BAR
+    (0x2018, 0x2019),  # LEFT and RIGHT SINGLE QUOTATION MARK
+    (0x201C, 0x201D),  # LEFT and RIGHT DOUBLE QUOTATION MARK
+    (0x2025, 0x2026),  # TWO DOT LEADER, HORIZONTAL ELLIPSIS
+    (0x2030, 0x2030),  # PER MILLE SIGN
+    (0x2032, 0x2033),  # PRIME, DOUBLE PRIME
+    (0x203B, 0x203B),  # REFERENCE MARK
+]
+"""The ambiguous width punctuation which the `cjk-punctuation-wide` feature treats as double width
+even when `is_cjk == false`, because CJK fonts draw it full width. This list is maintained by hand,
+and `main` checks that every codepoint in it is ambiguous width. It must not include anything in
+the Latin-1 Supplement, whose widths `width` resolves without consulting it."""
+
+HANGUL_CONJOINING_RANGES = [
+    (0x1160, 0x11FF),  # Hangul Jamo medial vowels (jungseong) and final consonants (jongseong)
+    (0xD7B0, 0xD7C6),  # Hangul Jamo Extended-B medial vowels
+    (0xD7CB, 0xD7FB),  # Hangul Jamo Extended-B final consonants
+]
+"""The Hangul conjoining jamo which follow a leading consonant (choseong) and are drawn in the
+same syllable block as it, which `load_width_map` makes zero-width. The leading consonants in
+U+1100..U+115F and U+A960..U+A97C are East Asian Wide, so a decomposed syllable measures 2
+columns like its precomposed form."""
+
+NONZERO_DEFAULT_IGNORABLES = [
+    0x00AD,  # SOFT HYPHEN, which is displayed as a hyphen at a line break
+    0x115F,  # HANGUL CHOSEONG FILLER, which is East Asian Wide
+    0x3164,  # HANGUL FILLER, which is East Asian Wide
+    0xFFA0,  # HALFWIDTH HANGUL FILLER
+]
+"""The assigned `Default_Ignorable_Code_Point` characters which aren't zero-width, either because
+of an override in `load_width_map` or because they aren't in a zero-width general category.
+`check_zero_widths` checks that every other assigned default-ignorable codepoint is zero-width,
+so that a new version of Unicode can't silently change which are. Unassigned default-ignorable
+codepoints (like U+2065 and U+E0000) are measured like other unassigned codepoints."""
+
+RANGE_TABLE_FEATURES = {
+    "EMOJI_COMPONENT_TABLE": "segmentation",
+    "CJK_PUNCTUATION_TABLE": "cjk-punctuation-wide",
+}
+"""Maps the names of range tables which are only used when a cargo feature is enabled to that
+feature. The accessors for these tables in `emit_module` must be gated on the same feature."""
+
+
+def emit_range_table(module, name: str, ranges: "list[tuple[Codepoint, Codepoint]]"):
+    """Writes the inclusive codepoint ranges in `ranges` to `module` as a sorted static array of
+    `(char, char)` pairs named `name`, suitable for `bsearch_range_table`."""
+    cfg = ""
+    if name in RANGE_TABLE_FEATURES:
+        cfg = f'\n    #[cfg(feature = "{RANGE_TABLE_FEATURES[name]}")]'
+    module.write(
+        f"""
+    /// Autogenerated. {len(ranges)} sorted, non-overlapping range(s).{cfg}
+    static {name}: [(char, char); {len(ranges)}] = ["""
+    )
+    for (low, high) in ranges:
+        # One entry per line (chosen to match what rustfmt does)
+        module.write(f"\n        ('\\u{{{low:x}}}', '\\u{{{high:x}}}'),")
+    module.write("\n    ];\n")
+
+
+SCRIPT_DOCS = {
+    "Common": "Characters which are used by several scripts (`Common`), such as digits.",
+    "Inherited": "Characters which take on the script of the previous character (`Inherited`).",
+    "Unknown": "Unassigned, private use and surrogate codepoints (`Unknown`).",
+}
+"""Doc comments for the `Script` variants which don't correspond to a single writing system."""
+
+
+def script_variant(name: str) -> str:
+    """Returns the name of the `Script` variant for the script named `name` in `Scripts.txt`."""
+    return name.replace("_", "")
+
+
+def emit_script_enum(module, script_ranges: "list[tuple[Codepoint, Codepoint, str]]"):
+    """Writes a `Script` enum with a variant for each script in `script_ranges` (plus `Unknown`)
+    to `module`. Variants are declared in alphabetical order, which their derived `Ord` follows."""
+    names = sorted({cat for (_, _, cat) in script_ranges} | {"Unknown"}, key=script_variant)
+    module.write(
+        """
+/// The script of a character, as listed in `Scripts.txt` and reported by `width_by_script` and
+/// `width_and_scripts`. Variants are named after the long names of the scripts, without
+/// underscores.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Script {"""
+    )
+    for name in names:
+        doc = SCRIPT_DOCS.get(name, f"The `{name}` script.")
+        module.write(f"\n    /// {doc}\n    {script_variant(name)},")
+    module.write(
+        f"""
+}}
+
+impl Script {{
+    /// Every script, in the order the variants are declared.
+    pub const ALL: [Script; {len(names)}] = ["""
+    )
+    for name in names:
+        module.write(f"\n        Script::{script_variant(name)},")
+    module.write("\n    ];\n}\n")
+
+
+def emit_script_table(module, script_ranges: "list[tuple[Codepoint, Codepoint, str]]"):
+    """Writes the script ranges in `script_ranges` to `module` as a sorted static array of
+    `(char, char, Script)` entries named `SCRIPT_TABLE`, which is consulted by `script`."""
+    module.write(
+        f"""
+    /// Autogenerated. {len(script_ranges)} sorted, non-overlapping range(s) of characters with a known script.
+    static SCRIPT_TABLE: [(char, char, Script); {len(script_ranges)}] = ["""
+    )
+    for (low, high, name) in script_ranges:
+        module.write(
+            f"\n        ('\\u{{{low:x}}}', '\\u{{{high:x}}}', Script::{script_variant(name)}),"
+        )
+    module.write("\n    ];\n")
+
+
 def emit_module(
-    out_name: str, unicode_version: "tuple[int, int, int]", tables: "list[Table]"
+    out_name: str,
+    unicode_version: "tuple[int, int, int]",
+    tables: "list[Table]",
+    latin1_widths: "list[EffectiveWidth]",
+    range_tables: "dict[str, list[tuple[Codepoint, Codepoint]]]",
+    eaw_ranges: "list[tuple[Codepoint, Codepoint, str]]",
+    script_ranges: "list[tuple[Codepoint, Codepoint, str]]",
+    ambiguous_ranges: "list[tuple[Codepoint, Codepoint]]",
 ):
-    """Outputs a Rust module to `out_name` using table data from `tables`.
+    """Outputs a Rust module to `out_name` using table data from `tables`, plus a static range
+    table for each name/ranges pair in `range_tables`, a table of the non-Neutral East Asian
+    Width categories in `eaw_ranges`, a table of the known scripts in `script_ranges` and a
+    public list of the codepoints in `ambiguous_ranges`, which should be those whose width is
+    `EffectiveWidth.AMBIGUOUS` in the tables.
     If `TABLE_CFGS` is edited, you may need to edit the included code for `lookup_width`."""
+    subtable_counts = [1] + [len(table.buckets()) for table in tables[:-1]]
+    tables[-1].indices_to_widths()  # for the last table, indices == widths
+    byte_arrays = [table.to_bytes() for table in tables]
+
+    # Every table written to the module is hashed, so that any change to the emitted data
+    # changes `TABLE_HASH`
+    hashed = [byte for byte_array in byte_arrays for byte in byte_array]
+    hashed.extend(int(width) for width in latin1_widths)
+    for ranges in [*range_tables.values(), CJK_PUNCTUATION_RANGES, ambiguous_ranges]:
+        for (low, high) in ranges:
+            hashed.extend(low.to_bytes(4, "little") + high.to_bytes(4, "little"))
+    for (low, high, cat) in eaw_ranges:
+        hashed.extend(low.to_bytes(4, "little") + high.to_bytes(4, "little"))
+        hashed.extend(cat.encode("ascii"))
+    for (low, high, name) in script_ranges:
+        hashed.extend(low.to_bytes(4, "little") + high.to_bytes(4, "little"))
+        hashed.extend(name.encode("ascii"))
+    table_hash = fnv1a_64(hashed)
+
     if os.path.exists(out_name):
         os.remove(out_name)
     with open(out_name, "w", newline="\n", encoding="utf-8") as module:
@@ -342,6 +690,8 @@ def emit_module(
 // except according to those terms.
 
 // NOTE: The following code was generated by "scripts/unicode.py", do not edit directly
+
+use core::ops::RangeInclusive;
 """
         )
         module.write(
@@ -349,18 +699,35 @@ def emit_module(
 /// The version of [Unicode](http://www.unicode.org/)
 /// that this version of unicode-width is based on.
 pub const UNICODE_VERSION: (u8, u8, u8) = {unicode_version};
+
+/// A 64-bit [FNV-1a](http://www.isthe.com/chongo/tech/comp/fnv/) hash of the generated lookup
+/// tables used by this version of unicode-width, computed by `unicode.py`.
+/// Builds generated from the same Unicode data by the same generator have the same hash.
+pub const TABLE_HASH: u64 = 0x{table_hash:016X};
 """
         )
+        emit_script_enum(module, script_ranges)
+        module.write(
+            """
+/// Autogenerated. The ranges of characters which are 1 column wide, except in CJK contexts
+/// (such as [`width_cjk`](crate::UnicodeWidthChar::width_cjk)), where they're 2 columns wide.
+/// These are the Ambiguous characters which aren't zero-width.
+pub const AMBIGUOUS_RANGES: &[RangeInclusive<char>] = &["""
+        )
+        for (low, high) in ambiguous_ranges:
+            module.write(f"\n    '\\u{{{low:x}}}'..='\\u{{{high:x}}}',")
+        module.write("\n];\n")
 
         module.write(
             """
 pub mod charwidth {
     use core::option::Option::{self, None, Some};
+    use EastAsianWidth;
+    use Script;
 
-    /// Returns the [UAX #11](https://www.unicode.org/reports/tr11/) based width of `c` by
-    /// consulting a multi-level lookup table.
-    /// If `is_cjk == true`, ambiguous width characters are treated as double width; otherwise,
-    /// they're treated as single width.
+    /// Returns the encoded [UAX #11](https://www.unicode.org/reports/tr11/) based width of `c` by
+    /// consulting a multi-level lookup table. Use `resolve_width` to convert it into a column
+    /// count.
     ///
     /// # Maintenance
     /// The tables themselves are autogenerated but this function is hardcoded. You should have
@@ -368,7 +735,7 @@ pub mod charwidth {
     /// However, if you change the *actual structure* of the lookup tables (perhaps by editing the
     /// `TABLE_CFGS` global in `unicode.py`) you must ensure that this code reflects those changes.
     #[inline]
-    fn lookup_width(c: char, is_cjk: bool) -> usize {
+    const fn lookup_width(c: char) -> u8 {
         let cp = c as usize;
 
         let t1_offset = TABLES_0[cp >> 13 & 0xFF];
@@ -376,17 +743,21 @@ pub mod charwidth {
         // Each sub-table in TABLES_1 is 7 bits, and each stored entry is a byte,
         // so each sub-table is 128 bytes in size.
         // (Sub-tables are selected using the computed offset from the previous table.)
-        let t2_offset = TABLES_1[128 * usize::from(t1_offset) + (cp >> 6 & 0x7F)];
+        let t2_offset = TABLES_1[128 * t1_offset as usize + (cp >> 6 & 0x7F)];
 
         // Each sub-table in TABLES_2 is 6 bits, but each stored entry is 2 bits.
         // This is accomplished by packing four stored entries into one byte.
         // So each sub-table is 2**(6-2) == 16 bytes in size.
         // Since this is the last table, each entry represents an encoded width.
-        let packed_widths = TABLES_2[16 * usize::from(t2_offset) + (cp >> 2 & 0xF)];
+        let packed_widths = TABLES_2[16 * t2_offset as usize + (cp >> 2 & 0xF)];
 
         // Extract the packed width
-        let width = packed_widths >> (2 * (cp & 0b11)) & 0b11;
+        packed_widths >> (2 * (cp & 0b11)) & 0b11
+    }
 
+    /// Converts an encoded width from one of the lookup tables into a column count.
+    #[inline]
+    const fn resolve_width(width: u8, is_cjk: bool) -> usize {
         // A width of 3 signifies that the codepoint is ambiguous width.
         if width == 3 {
             if is_cjk {
@@ -395,9 +766,23 @@ pub mod charwidth {
                 1
             }
         } else {
-            width.into()
+            width as usize
         }
     }
+
+    /// Converts the encoded width of `c` from one of the lookup tables into a column count.
+    /// With the `cjk-punctuation-wide` feature, the ambiguous width punctuation in
+    /// `CJK_PUNCTUATION_TABLE` is double width regardless of `is_cjk`.
+    #[inline]
+    const fn resolve_char_width(c: char, width: u8, is_cjk: bool) -> usize {
+        #[cfg(feature = "cjk-punctuation-wide")]
+        if width == 3 && bsearch_range_table(c, &CJK_PUNCTUATION_TABLE) {
+            return 2;
+        }
+        #[cfg(not(feature = "cjk-punctuation-wide"))]
+        let _ = c;
+        resolve_width(width, is_cjk)
+    }
 """
         )
 
@@ -408,7 +793,7 @@ pub mod charwidth {
     /// If `is_cjk == true`, ambiguous width characters are treated as double width; otherwise,
     /// they're treated as single width.
     #[inline]
-    pub fn width(c: char, is_cjk: bool) -> Option<usize> {
+    pub const fn width(c: char, is_cjk: bool) -> Option<usize> {
         if c < '\\u{7F}' {
             if c >= '\\u{20}' {
                 // U+0020 to U+007F (exclusive) are single-width ASCII codepoints
@@ -420,26 +805,182 @@ pub mod charwidth {
                 // U+0001 to U+0020 (exclusive) are control codes
                 None
             }
-        } else if c >= '\\u{A0}' {
-            // No characters >= U+00A0 are control codes, so we can consult the lookup tables
-            Some(lookup_width(c, is_cjk))
-        } else {
+        } else if c < '\\u{A0}' {
             // U+007F to U+00A0 (exclusive) are control codes
             None
+        } else if c < '\\u{100}' {
+            // U+00A0 to U+0100 (exclusive) are common enough in European text to get their own
+            // table, which skips the multi-level lookup
+            Some(resolve_width(LATIN1_TABLE[c as usize - 0xA0], is_cjk))
+        } else {
+            // No characters >= U+00A0 are control codes, so we can consult the lookup tables
+            Some(resolve_char_width(c, lookup_width(c), is_cjk))
+        }
+    }
+
+    /// Returns the [UAX #11](https://www.unicode.org/reports/tr11/) based widths of `c` with
+    /// ambiguous width characters treated as single width and as double width, in that order,
+    /// or `None` if `c` is a control character other than `'\\0'`.
+    /// This is equivalent to calling `width` twice, but only looks `c` up once.
+    #[inline]
+    pub fn width_pair(c: char) -> Option<(usize, usize)> {
+        if c < '\\u{A0}' {
+            // ASCII and the C0 and C1 control codes aren't ambiguous width
+            return width(c, false).map(|w| (w, w));
+        }
+        let encoded = if c < '\\u{100}' {
+            LATIN1_TABLE[c as usize - 0xA0]
+        } else {
+            lookup_width(c)
+        };
+        Some((
+            resolve_char_width(c, encoded, false),
+            resolve_char_width(c, encoded, true),
+        ))
+    }
+
+    /// Returns the width of `c` derived from the Unicode data alone, without special-casing
+    /// control characters: like every codepoint in general category `Cc`, they're zero-width.
+    /// If `is_cjk == true`, ambiguous width characters are treated as double width; otherwise,
+    /// they're treated as single width.
+    #[inline]
+    pub const fn table_width(c: char, is_cjk: bool) -> usize {
+        if c < '\\u{A0}' {
+            // The multi-level tables store everything below U+0100 as narrow, so ASCII and the
+            // C0 and C1 control codes are resolved here, as in `width`
+            (c >= '\\u{20}' && c < '\\u{7F}') as usize
+        } else if c < '\\u{100}' {
+            resolve_width(LATIN1_TABLE[c as usize - 0xA0], is_cjk)
+        } else {
+            resolve_char_width(c, lookup_width(c), is_cjk)
+        }
+    }
+
+    /// Returns `true` if `c` lies within one of the ranges in `r`, which must be sorted and
+    /// non-overlapping. This is a `while` loop rather than `binary_search_by` so that it can be
+    /// used in `const` evaluation.
+    #[inline]
+    const fn bsearch_range_table(c: char, r: &[(char, char)]) -> bool {
+        let (mut lo, mut hi) = (0, r.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let (low, high) = r[mid];
+            if low > c {
+                hi = mid;
+            } else if high < c {
+                lo = mid + 1;
+            } else {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Returns `true` if `c` has been assigned a general category other than `Cn` (Unassigned).
+    /// Private-use characters are considered assigned; noncharacters are not.
+    #[inline]
+    pub fn is_assigned(c: char) -> bool {
+        bsearch_range_table(c, &ASSIGNED_TABLE)
+    }
+
+    /// Returns `true` if `c` has the `Default_Ignorable_Code_Point` property, meaning that it
+    /// should be invisible when not specially supported. Notably, this excludes the
+    /// `Prepended_Concatenation_Mark` characters, which are visible despite being format
+    /// characters.
+    #[inline]
+    pub fn is_default_ignorable(c: char) -> bool {
+        bsearch_range_table(c, &DEFAULT_IGNORABLE_TABLE)
+    }
+
+    /// Returns `true` if `c` has the `Emoji` property. This includes characters like U+2600
+    /// BLACK SUN WITH RAYS which default to text presentation, but can be presented as emoji
+    /// when followed by U+FE0F VARIATION SELECTOR-16.
+    #[inline]
+    pub fn is_emoji(c: char) -> bool {
+        bsearch_range_table(c, &EMOJI_TABLE)
+    }
+
+    /// Returns `true` if `c` has the `Emoji_Component` property, meaning that it can appear as
+    /// part of an emoji sequence. This includes U+200D ZERO WIDTH JOINER, U+FE0F VARIATION
+    /// SELECTOR-16, the skin tone modifiers, the hair components, the regional indicators, and
+    /// the keycap and tag characters.
+    #[cfg(feature = "segmentation")]
+    #[inline]
+    pub fn is_emoji_component(c: char) -> bool {
+        bsearch_range_table(c, &EMOJI_COMPONENT_TABLE)
+    }
+
+    /// Returns `true` if `c` has the `Grapheme_Extend` property, meaning that it extends the
+    /// grapheme cluster of the preceding character. This includes all nonspacing and enclosing
+    /// marks, as well as a few spacing marks and other characters.
+    #[inline]
+    pub fn is_grapheme_extend(c: char) -> bool {
+        bsearch_range_table(c, &GRAPHEME_EXTEND_TABLE)
+    }
+
+    /// Returns `true` if `c` is a combining mark: a nonspacing (`Mn`), spacing (`Mc`), or
+    /// enclosing (`Me`) mark.
+    #[inline]
+    pub fn is_mark(c: char) -> bool {
+        bsearch_range_table(c, &MARK_TABLE)
+    }
+
+    /// Returns `true` if `c` has the `Pattern_White_Space` property, the stable set of
+    /// characters which programming languages and other syntaxes should treat as whitespace.
+    #[inline]
+    pub fn is_pattern_white_space(c: char) -> bool {
+        bsearch_range_table(c, &PATTERN_WHITE_SPACE_TABLE)
+    }
+
+    /// Returns `true` if `c` is a strong right-to-left character, with the bidi class `R` (such
+    /// as Hebrew letters) or `AL` (such as Arabic letters).
+    #[inline]
+    pub fn is_strong_rtl(c: char) -> bool {
+        bsearch_range_table(c, &RTL_TABLE)
+    }
+
+    /// Returns the East Asian Width category of `c`, as listed in `EastAsianWidth.txt`.
+    #[inline]
+    pub fn east_asian_width(c: char) -> EastAsianWidth {
+        use core::cmp::Ordering::{Equal, Greater, Less};
+        match EAW_TABLE.binary_search_by(|&(low, high, _)| {
+            if low > c {
+                Greater
+            } else if high < c {
+                Less
+            } else {
+                Equal
+            }
+        }) {
+            Ok(i) => EAW_TABLE[i].2,
+            Err(_) => EastAsianWidth::Neutral,
+        }
+    }
+
+    /// Returns the script of `c`, as listed in `Scripts.txt`.
+    #[inline]
+    pub fn script(c: char) -> Script {
+        use core::cmp::Ordering::{Equal, Greater, Less};
+        match SCRIPT_TABLE.binary_search_by(|&(low, high, _)| {
+            if low > c {
+                Greater
+            } else if high < c {
+                Less
+            } else {
+                Equal
+            }
+        }) {
+            Ok(i) => SCRIPT_TABLE[i].2,
+            Err(_) => Script::Unknown,
         }
     }
 """
         )
 
-        subtable_count = 1
-        for (i, table) in enumerate(tables):
-            new_subtable_count = len(table.buckets())
-            if i == len(tables) - 1:
-                table.indices_to_widths()  # for the last table, indices == widths
-            byte_array = table.to_bytes()
+        for (i, byte_array) in enumerate(byte_arrays):
             module.write(
                 f"""
-    /// Autogenerated. {subtable_count} sub-table(s). Consult [`lookup_width`] for layout info.
+    /// Autogenerated. {subtable_counts[i]} sub-table(s). Consult [`lookup_width`] for layout info.
     static TABLES_{i}: [u8; {len(byte_array)}] = ["""
             )
             for (j, byte) in enumerate(byte_array):
@@ -448,18 +989,61 @@ pub mod charwidth {
                     module.write("\n       ")
                 module.write(f" 0x{byte:02X},")
             module.write("\n    ];\n")
-            subtable_count = new_subtable_count
+        module.write(
+            f"""
+    /// Autogenerated. The encoded widths of U+00A0..U+0100, consulted by [`width`].
+    static LATIN1_TABLE: [u8; {len(latin1_widths)}] = ["""
+        )
+        for (j, width) in enumerate(latin1_widths):
+            if j % 30 == 0:
+                module.write("\n       ")
+            module.write(f" {int(width)},")
+        module.write("\n    ];\n")
+        for (name, ranges) in range_tables.items():
+            emit_range_table(module, name, ranges)
+        emit_range_table(module, "CJK_PUNCTUATION_TABLE", CJK_PUNCTUATION_RANGES)
+        module.write(
+            f"""
+    /// Autogenerated. {len(eaw_ranges)} sorted, non-overlapping range(s) of non-Neutral characters.
+    static EAW_TABLE: [(char, char, EastAsianWidth); {len(eaw_ranges)}] = ["""
+        )
+        for (low, high, cat) in eaw_ranges:
+            module.write(
+                f"\n        ('\\u{{{low:x}}}', '\\u{{{high:x}}}', EastAsianWidth::{EAW_CATEGORIES[cat]}),"
+            )
+        module.write("\n    ];\n")
+        emit_script_table(module, script_ranges)
         module.write("}\n")
 
 
-def main(module_filename: str):
-    """Obtain character data from the latest version of Unicode, transform it into a multi-level
-    lookup table for character width, and write a Rust module utilizing that table to
-    `module_filename`.
+def fast_path_width(codepoint: Codepoint) -> EffectiveWidth:
+    """Returns the width that the emitted `width` function hardcodes for `codepoint`, which must
+    be below `FAST_PATH_END`. Control codes (which `width` maps to `None`) are given
+    `EffectiveWidth.ZERO`, which is how they're stored in the width map."""
+    assert codepoint < FAST_PATH_END
+    if 0x20 <= codepoint < 0x7F:
+        return EffectiveWidth.NARROW
+    return EffectiveWidth.ZERO
+
+
+def exclude_fast_path(width_map: "list[EffectiveWidth]") -> "list[EffectiveWidth]":
+    """Validates that the fast path in the emitted `width` function agrees with `width_map` for
+    every codepoint below `FAST_PATH_END`, then returns a copy of `width_map` in which all
+    codepoints below `LATIN1_END` are stored as narrow. Their multi-level table entries are never
+    read, and a uniform width lets their sub-tables merge with other all-narrow sub-tables."""
+    for codepoint in range(0, FAST_PATH_END):
+        assert width_map[codepoint] == fast_path_width(codepoint), f"U+{codepoint:04X}"
+    return [EffectiveWidth.NARROW] * LATIN1_END + width_map[LATIN1_END:]
+
+
+def load_width_map() -> "list[EffectiveWidth]":
+    """Returns a list `l` where `l[c]` is the effective width of codepoint `c`, determined by
+    fetching and processing the Unicode tables in the working directory.
 
     We obey the following rules in decreasing order of importance:
     - The soft hyphen (`U+00AD`) is single-width.
-    - Hangul Jamo medial vowels & final consonants (`U+1160..=U+11FF`) are zero-width.
+    - Hangul Jamo medial vowels & final consonants (`U+1160..=U+11FF`, and `U+D7B0..=U+D7C6` and
+    `U+D7CB..=U+D7FB` in Hangul Jamo Extended-B) are zero-width.
     - All codepoints in general categories `Cc`, `Cf`, `Mn`, and `Me` are zero-width.
     - All codepoints with an East Asian Width of `Ambigous` are ambiguous-width.
     - All codepoints with an East Asian Width of `Wide` or `Fullwidth` are double-width.
@@ -468,9 +1052,6 @@ def main(module_filename: str):
 
     These rules are based off of Markus Kuhn's free `wcwidth()` implementation:
     http://www.cl.cam.ac.uk/~mgk25/ucs/wcwidth.c"""
-    version = load_unicode_version()
-    print(f"Generating module for Unicode {version[0]}.{version[1]}.{version[2]}")
-
     eaw_map = load_east_asian_widths()
     zw_map = load_zero_widths()
 
@@ -482,11 +1063,192 @@ def main(module_filename: str):
     # Override for soft hyphen
     width_map[0x00AD] = EffectiveWidth.NARROW
 
-    # Override for Hangul Jamo medial vowels & final consonants
-    for i in range(0x1160, 0x11FF + 1):
-        width_map[i] = EffectiveWidth.ZERO
+    # Override for Hangul Jamo medial vowels & final consonants, which conjoin with the leading
+    # consonant before them into a single syllable block
+    for (low, high) in HANGUL_CONJOINING_RANGES:
+        for i in range(low, high + 1):
+            width_map[i] = EffectiveWidth.ZERO
 
-    tables = make_tables(TABLE_CFGS, enumerate(width_map))
+    return width_map
+
+
+def load_whitelist(filename: str) -> "list[bool]":
+    """Returns a list `l` where `l[c]` is true if codepoint `c` is listed in `filename`.
+
+    Each line of `filename` holds a single codepoint or an inclusive range of codepoints, in the
+    same hexadecimal format as the Unicode data files (`4E00` or `4E00..9FFF`). Text after a `#`
+    is a comment, and blank lines are ignored."""
+    whitelist = [False] * NUM_CODEPOINTS
+    line_regex = re.compile(r"^([0-9A-F]+)(?:\.\.([0-9A-F]+))?\s*$", re.IGNORECASE)
+    with open(filename, encoding="utf-8") as lines:
+        for line in lines:
+            line = line.split("#", 1)[0].strip()
+            if not line:
+                continue
+            raw_data = line_regex.match(line)
+            assert raw_data, f"malformed whitelist line: {line!r}"
+            low = int(raw_data.group(1), 16)
+            high = int(raw_data.group(2) or raw_data.group(1), 16)
+            for codepoint in range(low, high + 1):
+                whitelist[codepoint] = True
+    return whitelist
+
+
+def restrict_width_map(
+    width_map: "list[EffectiveWidth]", whitelist: "list[bool]", fallback: EffectiveWidth
+) -> "list[EffectiveWidth]":
+    """Returns a copy of `width_map` in which every codepoint not in `whitelist` is given the
+    width `fallback`. Uniform runs of `fallback` let most sub-tables merge, so the lookup table
+    built from the result is much smaller when the whitelist covers only a few scripts."""
+    return [
+        width if listed else fallback for (width, listed) in zip(width_map, whitelist)
+    ]
+
+
+def diff_width_maps(
+    old: "list[EffectiveWidth]", new: "list[EffectiveWidth]"
+) -> "list[tuple[Codepoint, Codepoint, EffectiveWidth, EffectiveWidth]]":
+    """Returns every codepoint whose width differs between `old` and `new`, as a sorted list of
+    `(low, high, old_width, new_width)` entries. Each entry is a maximal inclusive range of
+    consecutive codepoints which all changed from `old_width` to `new_width`."""
+    changes = []
+    for codepoint, (old_width, new_width) in enumerate(zip(old, new)):
+        if old_width == new_width:
+            continue
+        prev = changes[-1] if changes else None
+        if prev and prev[1] == codepoint - 1 and prev[2:] == (old_width, new_width):
+            changes[-1] = (prev[0], codepoint, old_width, new_width)
+        else:
+            changes.append((codepoint, codepoint, old_width, new_width))
+    return changes
+
+
+def diff_main(old_dir: str):
+    """Compares the character widths computed from the Unicode tables in `old_dir` against those
+    computed from the tables in the working directory, and prints every change to stderr.
+
+    This doesn't write a module. It's meant to be run before regenerating `tables.rs` for a new
+    Unicode version, so that the behavioral impact of the bump can be reviewed."""
+    new_dir = os.getcwd()
+    os.chdir(old_dir)
+    old_version, old_map = load_unicode_version(), load_width_map()
+    os.chdir(new_dir)
+    new_version, new_map = load_unicode_version(), load_width_map()
+
+    changes = diff_width_maps(old_map, new_map)
+    old_version = ".".join(map(str, old_version))
+    new_version = ".".join(map(str, new_version))
+    sys.stderr.write(f"Unicode {old_version} -> {new_version}: {len(changes)} change(s)\n")
+    for (low, high, old_width, new_width) in changes:
+        span = f"U+{low:04X}" if low == high else f"U+{low:04X}..U+{high:04X}"
+        sys.stderr.write(f"{span}: {old_width.name} -> {new_width.name}\n")
+
+
+def subset_main(module_filename: str, whitelist_filename: str, fallback: EffectiveWidth):
+    """Like `main`, but the lookup table only stores the widths of the codepoints listed in
+    `whitelist_filename` (see `load_whitelist`). Every other codepoint is measured as `fallback`
+    by the emitted `width` function, except for those below `LATIN1_END`, which are always
+    measured exactly.
+
+    This is meant for applications which only ever display a known set of scripts and want a
+    smaller table. Before writing the module, this checks that the trimmed table agrees with the
+    full one on every whitelisted codepoint."""
+    version = load_unicode_version()
+    print(f"Generating subset module for Unicode {version[0]}.{version[1]}.{version[2]}")
+
+    width_map = load_width_map()
+    whitelist = load_whitelist(whitelist_filename)
+    full_map = exclude_fast_path(width_map)
+    subset_map = restrict_width_map(full_map, whitelist, fallback)
+    full_tables = make_tables(TABLE_CFGS, enumerate(full_map))
+    tables = make_tables(TABLE_CFGS, enumerate(subset_map))
+
+    for codepoint in range(LATIN1_END, NUM_CODEPOINTS):
+        expected = width_map[codepoint] if whitelist[codepoint] else fallback
+        assert table_lookup(tables, codepoint) == expected, f"U+{codepoint:04X}"
+        if whitelist[codepoint]:
+            assert table_lookup(full_tables, codepoint) == expected, f"U+{codepoint:04X}"
+
+    full_size = sum(len(table.to_bytes()) for table in full_tables)
+    subset_size = sum(len(table.to_bytes()) for table in tables)
+    print(f"{sum(whitelist)} whitelisted codepoint(s), fallback width {fallback.name}")
+    print(f"Table Size: {subset_size} bytes (full table: {full_size} bytes)")
+
+    latin1_widths = width_map[FAST_PATH_END:LATIN1_END]
+    eaw_ranges = to_category_ranges(load_east_asian_width_categories(), "N")
+    script_ranges = to_category_ranges(load_scripts(), "Unknown")
+    emitted_map = width_map[:LATIN1_END] + subset_map[LATIN1_END:]
+    ambiguous_ranges = to_ranges([width == EffectiveWidth.AMBIGUOUS for width in emitted_map])
+    emit_module(
+        module_filename,
+        version,
+        tables,
+        latin1_widths,
+        load_range_tables(),
+        eaw_ranges,
+        script_ranges,
+        ambiguous_ranges,
+    )
+    print(f'Wrote to "{module_filename}"')
+
+
+def check_zero_widths(width_map: "list[EffectiveWidth]"):
+    """Checks that every codepoint in general categories `Mn` and `Me` is zero-width in
+    `width_map`, as well as every assigned `Default_Ignorable_Code_Point` codepoint other than
+    those in `NONZERO_DEFAULT_IGNORABLES`. This pins down the widths of characters like U+0301
+    COMBINING ACUTE ACCENT, U+200B ZERO WIDTH SPACE, U+2060 WORD JOINER, and U+FEFF ZERO WIDTH
+    NO-BREAK SPACE regardless of how `load_width_map`'s rules evolve."""
+    categories = load_general_categories()
+    ignorables = load_property("DerivedCoreProperties.txt", "Default_Ignorable_Code_Point")
+    for codepoint, width in enumerate(width_map):
+        cat = categories[codepoint]
+        if cat in ["Mn", "Me"] or (
+            ignorables[codepoint]
+            and cat != "Cn"
+            and codepoint not in NONZERO_DEFAULT_IGNORABLES
+        ):
+            assert width == EffectiveWidth.ZERO, f"U+{codepoint:04X}"
+
+
+def load_range_tables() -> "dict[str, list[tuple[Codepoint, Codepoint]]]":
+    """Returns the inclusive codepoint ranges of each static range table in the emitted module,
+    keyed by the table's name."""
+    return {
+        "ASSIGNED_TABLE": to_ranges(load_assigned()),
+        "DEFAULT_IGNORABLE_TABLE": to_ranges(
+            load_property("DerivedCoreProperties.txt", "Default_Ignorable_Code_Point")
+        ),
+        "EMOJI_TABLE": to_ranges(load_property("emoji/emoji-data.txt", "Emoji")),
+        "EMOJI_COMPONENT_TABLE": to_ranges(
+            load_property("emoji/emoji-data.txt", "Emoji_Component")
+        ),
+        "GRAPHEME_EXTEND_TABLE": to_ranges(
+            load_property("DerivedCoreProperties.txt", "Grapheme_Extend")
+        ),
+        "MARK_TABLE": to_ranges(load_marks()),
+        "PATTERN_WHITE_SPACE_TABLE": to_ranges(
+            load_property("PropList.txt", "Pattern_White_Space")
+        ),
+        "RTL_TABLE": to_ranges(load_strong_rtl()),
+    }
+
+
+def main(module_filename: str):
+    """Obtain character data from the latest version of Unicode, transform it into a multi-level
+    lookup table for character width, and write a Rust module utilizing that table to
+    `module_filename`. See `load_width_map` for how widths are determined."""
+    version = load_unicode_version()
+    if PINNED_VERSION is not None and version != PINNED_VERSION:
+        sys.stderr.write(f"expected the tables of Unicode {PINNED_VERSION}, found {version}")
+        sys.exit(1)
+    print(f"Generating module for Unicode {version[0]}.{version[1]}.{version[2]}")
+
+    width_map = load_width_map()
+    check_zero_widths(width_map)
+    tables = make_tables(TABLE_CFGS, enumerate(exclude_fast_path(width_map)))
+    latin1_widths = width_map[FAST_PATH_END:LATIN1_END]
+
+    range_tables = load_range_tables()
 
     print("------------------------")
     total_size = 0
@@ -497,9 +1259,43 @@ def main(module_filename: str):
     print("------------------------")
     print(f"  Total Size: {total_size} bytes")
 
-    emit_module(module_filename, version, tables)
+    for (name, ranges) in range_tables.items():
+        print(f"{name} Ranges: {len(ranges)}")
+    eaw_ranges = to_category_ranges(load_east_asian_width_categories(), "N")
+    print(f"EAW_TABLE Ranges: {len(eaw_ranges)}")
+    script_ranges = to_category_ranges(load_scripts(), "Unknown")
+    print(f"SCRIPT_TABLE Ranges: {len(script_ranges)}")
+    ambiguous_ranges = to_ranges([width == EffectiveWidth.AMBIGUOUS for width in width_map])
+    print(f"AMBIGUOUS_RANGES Ranges: {len(ambiguous_ranges)}")
+    for (low, high) in CJK_PUNCTUATION_RANGES:
+        assert low >= LATIN1_END
+        for codepoint in range(low, high + 1):
+            assert width_map[codepoint] == EffectiveWidth.AMBIGUOUS, f"U+{codepoint:04X}"
+
+    emit_module(
+        module_filename,
+        version,
+        tables,
+        latin1_widths,
+        range_tables,
+        eaw_ranges,
+        script_ranges,
+        ambiguous_ranges,
+    )
     print(f'Wrote to "{module_filename}"')
 
 
 if __name__ == "__main__":
-    main(MODULE_FILENAME)
+    # `unicode.py --diff OLD_DIR` reports width changes since the tables in `OLD_DIR`
+    if len(sys.argv) == 3 and sys.argv[1] == "--diff":
+        diff_main(sys.argv[2])
+    # `unicode.py --subset WHITELIST [FALLBACK]` only stores the widths of whitelisted codepoints
+    elif len(sys.argv) in (3, 4) and sys.argv[1] == "--subset":
+        fallback = EffectiveWidth[sys.argv[3]] if len(sys.argv) == 4 else EffectiveWidth.NARROW
+        subset_main(MODULE_FILENAME, sys.argv[2], fallback)
+    # `unicode.py --version X.Y.Z` generates the module for a specific version of Unicode
+    elif len(sys.argv) == 3 and sys.argv[1] == "--version":
+        pin_version(sys.argv[2])
+        main(versioned_module_filename(sys.argv[2]))
+    else:
+        main(MODULE_FILENAME)
@@ -0,0 +1,43 @@
+#!/usr/bin/env python3
+#
+# Copyright 2011-2022 The Rust Project Developers. See the COPYRIGHT
+# file at the top-level directory of this distribution and at
+# http://rust-lang.org/COPYRIGHT.
+#
+# Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+# http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+# <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+# option. This file may not be copied, modified, or distributed
+# except according to those terms.
+
+# This script prints the output of the Python `wcwidth` package (`pip install wcwidth`) for each
+# codepoint listed in `conformance/wcwidth.txt`, in the format used by that file, so that the
+# expectations can be refreshed when either `wcwidth` or unicode-width updates its Unicode data.
+#
+# Documented divergences (the optional third field) are carried over unchanged; review any
+# codepoint whose `wcwidth` value changed before committing the result.
+
+import os
+import re
+import sys
+
+import wcwidth
+
+EXPECTATIONS = os.path.join(os.path.dirname(__file__), "..", "conformance", "wcwidth.txt")
+
+
+def main():
+    line_re = re.compile(r"^([0-9A-F]+);(-?\d+)(;-?\d+)?(.*)$")
+    with open(EXPECTATIONS, encoding="utf-8") as expectations:
+        for line in expectations.read().splitlines():
+            if not (match := line_re.match(line)):
+                print(line)
+                continue
+            codepoint = int(match.group(1), 16)
+            python = wcwidth.wcwidth(chr(codepoint))
+            print(f"{codepoint:04X};{python}{match.group(3) or ''}{match.group(4)}")
+    sys.stderr.write(f"wcwidth {wcwidth.__version__}\n")
+
+
+if __name__ == "__main__":
+    main()
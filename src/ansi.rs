@@ -0,0 +1,83 @@
+// Copyright 2012-2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::str::Chars;
+
+use WidthState;
+
+/// Returns the displayed width of `s` in columns, ignoring any ANSI escape sequences.
+///
+/// The following sequences are treated as zero-width:
+/// - Control Sequence Introducer (CSI) sequences such as `"\x1b[31m"`, which run until a final
+///   byte in `'\x40'..='\x7E'`.
+/// - Operating System Command (OSC) sequences such as OSC 8 hyperlinks
+///   (`"\x1b]8;;URL\x1b\\"`), which run until a BEL (`'\x07'`) or an ST (`"\x1b\\"`).
+///   Only the visible link text of a hyperlink is counted.
+/// - Device Control String, Start of String, Privacy Message, and Application Program Command
+///   sequences, which are terminated like OSC sequences.
+/// - Any other two-character escape sequence, such as `"\x1b7"`.
+///
+/// An unterminated sequence extends to the end of the string. All other characters are measured
+/// as by [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width), as though the escape
+/// sequences weren't there, so a variation selector after a color change still applies to the
+/// emoji before it.
+///
+/// ```rust
+/// use unicode_width::visible_width;
+///
+/// let link = "\x1b]8;;https://example.com\x1b\\Example\x1b]8;;\x1b\\";
+/// assert_eq!(visible_width(link), 7);
+/// assert_eq!(visible_width("\x1b[1;31mＲｅｄ\x1b[0m"), 6);
+/// ```
+pub fn visible_width(s: &str) -> usize {
+    let (mut width, mut state) = (0, WidthState::new());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            skip_escape(&mut chars);
+        } else {
+            width = state.add_width(width, c, false);
+            state = state.after(c);
+        }
+    }
+    width
+}
+
+/// Advances `chars` past the rest of an escape sequence whose leading ESC was just consumed.
+fn skip_escape(chars: &mut Chars) {
+    match chars.next() {
+        Some('[') => {
+            // CSI parameter and intermediate bytes end at the final byte
+            for c in chars.by_ref() {
+                if ('\x40'..='\x7e').contains(&c) {
+                    break;
+                }
+            }
+        }
+        Some(']') | Some('P') | Some('X') | Some('^') | Some('_') => {
+            while let Some(c) = chars.next() {
+                match c {
+                    '\x07' => break,
+                    '\x1b' => {
+                        if chars.clone().next() == Some('\\') {
+                            chars.next();
+                        } else {
+                            // ESC without a backslash aborts this string and starts a new sequence
+                            skip_escape(chars);
+                        }
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        _ => {}
+    }
+}
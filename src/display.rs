@@ -0,0 +1,133 @@
+// Copyright 2012-2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt::{self, Write};
+
+use CharWidthIndices;
+
+/// A string that is displayed in exactly `self.1` columns.
+///
+/// When formatted, the string is truncated to fit the requested width, or padded with
+/// trailing spaces if it is narrower. If a double-width character would straddle the
+/// truncation point, it is dropped and its remaining column is filled with a space instead.
+///
+/// Widths are measured the same way as [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width),
+/// so control characters are written out but occupy zero columns.
+///
+/// ```rust
+/// use unicode_width::Fit;
+///
+/// assert_eq!(format!("[{}]", Fit("Ｈｉ", 6)), "[Ｈｉ  ]");
+/// assert_eq!(format!("[{}]", Fit("Ｈｉ", 3)), "[Ｈ ]");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Fit<'a>(pub &'a str, pub usize);
+
+impl<'a> fmt::Display for Fit<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Fit(s, columns) = *self;
+        let mut used = 0;
+        let mut end = s.len();
+        for (i, _, width) in CharWidthIndices::new(s, false) {
+            if used + width > columns {
+                end = i;
+                break;
+            }
+            used += width;
+        }
+        f.write_str(&s[..end])?;
+        for _ in used..columns {
+            f.write_char(' ')?;
+        }
+        Ok(())
+    }
+}
+
+/// A string whose [`Display`](fmt::Display) implementation interprets the width and precision
+/// of the format spec as columns rather than `char`s.
+///
+/// The standard formatter pads and truncates strings by counting `char`s, which misaligns text
+/// with double-width or zero-width characters. When an `Aligned` string is formatted, the
+/// precision (if any) is the most columns to display: the string is truncated to the longest
+/// prefix which fits, dropping a double-width character which would straddle the limit. Then
+/// the width (if any) is the least columns to display: the string is padded with the fill
+/// character according to the alignment, which defaults to the left. The fill character is
+/// assumed to be 1 column wide.
+///
+/// Widths are measured the same way as [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+///
+/// ```rust
+/// use unicode_width::Aligned;
+///
+/// assert_eq!(format!("[{:>6}]", Aligned("Ｈｉ")), "[  Ｈｉ]");
+/// assert_eq!(format!("[{:*^7.3}]", Aligned("Ｈｉ")), "[**Ｈ***]");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Aligned<'a>(pub &'a str);
+
+impl<'a> fmt::Display for Aligned<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let max = f.precision().unwrap_or(usize::MAX);
+        let mut used = 0;
+        let mut end = self.0.len();
+        for (i, _, width) in CharWidthIndices::new(self.0, false) {
+            if used + width > max {
+                end = i;
+                break;
+            }
+            used += width;
+        }
+        let padding = f.width().unwrap_or(0).saturating_sub(used);
+        let (before, after) = match f.align() {
+            None | Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        };
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        f.write_str(&self.0[..end])?;
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
+/// Panics with the failure message of [`assert_width!`](crate::assert_width), given the string
+/// being measured along with its expected and actual widths.
+#[doc(hidden)]
+#[cold]
+#[track_caller]
+pub fn assert_width_failed(s: &str, expected: usize, actual: usize) -> ! {
+    panic!(
+        "assertion failed: `width({:?}) == {}`\n  actual width: {}\n  widths:\n{}",
+        s,
+        expected,
+        actual,
+        WidthBreakdown(s)
+    )
+}
+
+/// Lists the width of each character in a string, one per line, along with its byte offset.
+/// Control characters are listed as zero-width, and widths are as given by
+/// [`CharWidthIndices`], so they add up to the string's width.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct WidthBreakdown<'a>(pub &'a str);
+
+impl<'a> fmt::Display for WidthBreakdown<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, c, width) in CharWidthIndices::new(self.0, false) {
+            writeln!(f, "    {:>4}: U+{:04X} {:?} => {}", i, c as u32, c, width)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright 2012-2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::ops::Range;
+
+use unicode_segmentation::{GraphemeIndices, UnicodeSegmentation};
+
+use is_regional_indicator;
+use tables::charwidth as cw;
+use WidthState;
+
+/// Returns the displayed width of `s` in columns, measuring each extended grapheme cluster
+/// (as determined by [UAX #29](https://www.unicode.org/reports/tr29/)) as a unit.
+///
+/// Each cluster is measured by [`cluster_width`]. If `is_cjk == true`, characters in the
+/// Ambiguous category are treated as 2 columns wide; otherwise, they're treated as 1 column wide.
+///
+/// ```rust
+/// use unicode_width::width_graphemes;
+///
+/// assert_eq!(width_graphemes("#\u{FE0F}\u{20E3}", false), 2);
+/// ```
+pub fn width_graphemes(s: &str, is_cjk: bool) -> usize {
+    // In a leading run of ASCII, every character but the last is a cluster on its own (or part
+    // of a CRLF, which is zero-width either way), so only the rest needs to be segmented. The
+    // last one may start a longer cluster, like a keycap sequence.
+    let ascii = s.bytes().take_while(u8::is_ascii).count();
+    let (head, tail) = s.split_at(if ascii == s.len() {
+        ascii
+    } else {
+        ascii.saturating_sub(1)
+    });
+    let head_width = head.bytes().filter(|b| matches!(b, 0x20..=0x7E)).count();
+    head_width + tail.graphemes(true).map(|g| cluster_width(g, is_cjk)).sum::<usize>()
+}
+
+/// Returns the byte range and width of the grapheme cluster occupying the 0-based `column` of
+/// `s`, or `None` if `s` is narrower than `column + 1` columns.
+///
+/// Clusters are measured by [`cluster_width`] with Ambiguous characters treated as 1 column
+/// wide. Every column of a multi-column cluster maps to that cluster, which makes this suitable
+/// for mouse hit-testing. Zero-width clusters occupy no column, so they're never returned.
+///
+/// ```rust
+/// use unicode_width::grapheme_at_column;
+///
+/// assert_eq!(grapheme_at_column("a\u{FF22}c", 2), Some((1..4, 2)));
+/// assert_eq!(grapheme_at_column("a\u{FF22}c", 4), None);
+/// ```
+pub fn grapheme_at_column(s: &str, column: usize) -> Option<(Range<usize>, usize)> {
+    let mut start = 0;
+    for (i, g) in s.grapheme_indices(true) {
+        let width = cluster_width(g, false);
+        if column < start + width {
+            return Some((i..i + g.len(), width));
+        }
+        start += width;
+    }
+    None
+}
+
+/// Returns the number of cursor stops in `s`, for editors which move the cursor one extended
+/// grapheme cluster at a time.
+///
+/// This is the number of clusters, so unlike
+/// [`UnicodeWidthStr::cursor_stops`](crate::UnicodeWidthStr::cursor_stops), an emoji ZWJ
+/// sequence or a pair of regional indicators is a single stop. Like it, the count doesn't depend
+/// on width: a double-width cluster is still a single stop.
+///
+/// ```rust
+/// use unicode_width::cursor_stops_graphemes;
+///
+/// assert_eq!(cursor_stops_graphemes("a\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}"), 2);
+/// ```
+pub fn cursor_stops_graphemes(s: &str) -> usize {
+    s.graphemes(true).count()
+}
+
+/// An iterator over the extended grapheme clusters of a string, returned by
+/// [`UnicodeWidthStr::grapheme_cells`](crate::UnicodeWidthStr::grapheme_cells).
+///
+/// Each item is a `(range, width)` pair: the byte range of a cluster in the string, and its
+/// width in columns as measured by [`cluster_width`] with Ambiguous characters treated as 1
+/// column wide. Zero-width clusters, like a combining mark at the start of the string, are
+/// yielded with a width of 0.
+#[derive(Clone, Debug)]
+pub struct GraphemeCells<'a> {
+    graphemes: GraphemeIndices<'a>,
+}
+
+impl<'a> GraphemeCells<'a> {
+    pub(crate) fn new(s: &'a str) -> GraphemeCells<'a> {
+        GraphemeCells {
+            graphemes: s.grapheme_indices(true),
+        }
+    }
+}
+
+impl<'a> Iterator for GraphemeCells<'a> {
+    type Item = (Range<usize>, usize);
+
+    fn next(&mut self) -> Option<(Range<usize>, usize)> {
+        let (i, g) = self.graphemes.next()?;
+        Some((i..i + g.len(), cluster_width(g, false)))
+    }
+}
+
+/// Returns the displayed width of the extended grapheme cluster `g` in columns.
+///
+/// Emoji sequences are rendered as a single emoji, so they're 2 columns wide. This includes
+/// emoji keycap sequences (a `'#'`, `'*'`, or ASCII digit, optionally followed by U+FE0F
+/// VARIATION SELECTOR-16, followed by U+20E3 COMBINING ENCLOSING KEYCAP) and emoji tag
+/// sequences, such as the flags of England, Scotland and Wales (an emoji, optionally followed by
+/// U+FE0F, followed by one or more tag characters U+E0020..U+E007E, followed by U+E007F CANCEL
+/// TAG). Incomplete tag sequences are measured like any other cluster.
+///
+/// Emoji components (characters with the `Emoji_Component` property, such as the skin tone
+/// modifiers and the hair components U+1F9B0..U+1F9B3) are rendered as part of the emoji they
+/// follow, so they add no width to a cluster which starts with an emoji. Regional indicators are
+/// the exception, since they only combine with each other: a flag (a pair of regional
+/// indicators) is 2 columns wide, as is an unpaired regional indicator, like in
+/// [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width). A component on its own, such as a
+/// lone hair component, keeps its usual width. Likewise, the characters which U+200D ZERO WIDTH
+/// JOINER joins to an emoji are drawn as part of it, so an emoji ZWJ sequence like a family is
+/// as wide as the emoji it starts with. That emoji's width follows the variation selector after
+/// it, if any, as in [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width): U+FE0F
+/// VARIATION SELECTOR-16 makes a narrow emoji 2 columns wide, and U+FE0E VARIATION SELECTOR-15
+/// makes a wide one 1 column wide.
+///
+/// ```rust
+/// use unicode_width::cluster_width;
+///
+/// // MAN, ZERO WIDTH JOINER, EMOJI COMPONENT RED HAIR
+/// assert_eq!(cluster_width("\u{1F468}\u{200D}\u{1F9B0}", false), 2);
+/// assert_eq!(cluster_width("\u{1F9B0}", false), 2);
+/// // MAN, ZWJ, WOMAN, ZWJ, GIRL
+/// assert_eq!(cluster_width("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", false), 2);
+/// // HEAVY BLACK HEART, VARIATION SELECTOR-16
+/// assert_eq!(cluster_width("\u{2764}\u{FE0F}", false), 2);
+/// ```
+///
+/// Any other cluster is as wide as the sum of its characters' widths, with control
+/// characters treated as zero-width. So a cluster is as wide as its base character plus any
+/// spacing marks (general category `Mc`) attached to it: non-spacing and enclosing marks don't
+/// add any width, even when they're attached to a double-width base.
+pub fn cluster_width(g: &str, is_cjk: bool) -> usize {
+    if is_keycap_sequence(g) || is_tag_sequence(g) {
+        return 2;
+    }
+    let mut chars = g.chars();
+    let first = match chars.next() {
+        Some(first) => first,
+        None => return 0,
+    };
+    let is_emoji = cw::is_emoji(first);
+    let is_flag = is_regional_indicator(first);
+    // The first character takes the presentation a variation selector after it requests, and
+    // a flag's width is counted at its first regional indicator
+    let first_width = WidthState::new().width_before(first, chars.clone().next(), is_cjk);
+    let mut joined = false;
+    let rest = chars
+        .filter(|&c| {
+            let merged = joined || (cw::is_emoji_component(c) && !is_regional_indicator(c));
+            joined = c == '\u{200D}';
+            !((is_emoji && merged) || (is_flag && is_regional_indicator(c)))
+        })
+        .map(|c| cw::width(c, is_cjk).unwrap_or(0));
+    first_width + rest.sum::<usize>()
+}
+
+/// Returns `true` if `g` is an emoji keycap sequence, either fully-qualified (`"#\u{FE0F}\u{20E3}"`)
+/// or unqualified (`"#\u{20E3}"`).
+fn is_keycap_sequence(g: &str) -> bool {
+    let mut chars = g.chars();
+    match chars.next() {
+        Some('0'..='9') | Some('#') | Some('*') => {}
+        _ => return false,
+    }
+    match chars.next() {
+        Some('\u{FE0F}') => chars.next() == Some('\u{20E3}') && chars.next().is_none(),
+        Some('\u{20E3}') => chars.next().is_none(),
+        _ => false,
+    }
+}
+
+/// Returns `true` if `g` is an emoji tag sequence, such as the flag of Wales
+/// (`"\u{1F3F4}\u{E0067}\u{E0062}\u{E0077}\u{E006C}\u{E0073}\u{E007F}"`).
+fn is_tag_sequence(g: &str) -> bool {
+    let mut chars = g.chars().peekable();
+    match chars.next() {
+        Some(c) if cw::is_emoji(c) => {}
+        _ => return false,
+    }
+    chars.next_if_eq(&'\u{FE0F}');
+    let mut tags = 0;
+    while chars.next_if(|c| matches!(c, '\u{E0020}'..='\u{E007E}')).is_some() {
+        tags += 1;
+    }
+    tags > 0 && chars.next() == Some('\u{E007F}') && chars.next().is_none()
+}
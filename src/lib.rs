@@ -27,11 +27,55 @@
 //! }
 //! ```
 //!
+//! The width tables are generated from Unicode 17.0.0 (see [`UNICODE_VERSION`]), unless one of
+//! the `unicode-15-0` and `unicode-15-1` features selects the tables of Unicode 15.0.0 or
+//! 15.1.0 instead. Characters assigned in later versions are measured like any other unassigned
+//! codepoint, as 1 column wide.
+//!
 //! # features
 //!
 //! unicode-width supports a `no_std` feature. This eliminates dependence
 //! on std, and instead uses equivalent functions from core.
 //!
+//! The `ansi` feature adds `visible_width`, which measures strings
+//! containing ANSI escape sequences such as colors and OSC 8 hyperlinks.
+//!
+//! The `alloc` feature implements `UnicodeWidthStr` for `Box<str>`,
+//! `Rc<str>`, and `Arc<str>`, and adds `ellipsize`, `truncate_with_marker`,
+//! `join_within_width`, `width_by_script` and `UnicodeWidthStr::wrap_to_width`
+//! (and `wrap_to_width_cjk`).
+//! These are the only APIs that allocate; `width`, `width_cjk` and the
+//! iterator helpers such as `char_width_indices` are available without it.
+//!
+//! The `std` feature links to the standard library and adds `measure_reader`,
+//! which measures each line read from an `io::BufRead` source.
+//!
+//! The `segmentation` feature adds `width_graphemes` and `cluster_width`,
+//! which measure grapheme clusters (such as emoji sequences) as a unit,
+//! `grapheme_at_column`, which finds the cluster displayed at a column,
+//! `cursor_stops_graphemes`, which counts clusters as cursor stops, and
+//! `UnicodeWidthStr::grapheme_cells`, which yields each cluster with its width.
+//!
+//! The `normalization` feature (which depends on `unicode-normalization`) adds
+//! `composed_width`, which measures a string after composing it to NFC.
+//!
+//! The `cjk-punctuation-wide` feature makes the ambiguous width punctuation which CJK fonts draw
+//! full width double width everywhere, including `width` and other non-CJK measurements. This
+//! covers U+2014 EM DASH, U+2015 HORIZONTAL BAR, the curly quotation marks
+//! U+2018, U+2019, U+201C and U+201D, U+2025 TWO DOT LEADER, U+2026 HORIZONTAL ELLIPSIS,
+//! U+2030 PER MILLE SIGN, U+2032 PRIME, U+2033 DOUBLE PRIME and U+203B REFERENCE MARK. Other
+//! ambiguous width characters are unaffected.
+//!
+//! The `capi` feature adds `cstr_width`, which measures a `CStr` as lossily decoded UTF-8. It
+//! requires Rust 1.64 or later.
+//!
+//! The `unicode-15-0` and `unicode-15-1` features pin the width tables, along with
+//! [`UNICODE_VERSION`], [`TABLE_HASH`] and [`Script`], to Unicode 15.0.0 and 15.1.0
+//! respectively. They're mutually exclusive: enabling both is a compile error.
+//!
+//! The `conformance` feature enables tests comparing [`width_wcwidth`]
+//! against the outputs of the Python `wcwidth` package.
+//!
 //! # crates.io
 //!
 //! You can use this package in your project by adding the following
@@ -49,24 +93,115 @@
 #![cfg_attr(feature = "bench", feature(test))]
 #![no_std]
 
-#[cfg(test)]
-#[macro_use]
+#[cfg(any(test, feature = "std"))]
 extern crate std;
 
 #[cfg(feature = "bench")]
 extern crate test;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "segmentation")]
+extern crate unicode_segmentation;
+
+#[cfg(feature = "normalization")]
+extern crate unicode_normalization;
+
 use tables::charwidth as cw;
-pub use tables::UNICODE_VERSION;
 
-use core::ops::Add;
+use core::cmp::{self, Ordering};
+use core::ops::{Range, RangeInclusive};
+pub use tables::{AMBIGUOUS_RANGES, TABLE_HASH, UNICODE_VERSION};
+pub use tables::Script;
+#[cfg(feature = "ansi")]
+pub use ansi::visible_width;
+pub use display::{Aligned, Fit};
+#[doc(hidden)]
+pub use display::assert_width_failed;
+#[cfg(feature = "segmentation")]
+pub use graphemes::{
+    cluster_width, cursor_stops_graphemes, grapheme_at_column, width_graphemes, GraphemeCells,
+};
+pub use options::{AmbiguousPolicy, ControlPolicy, WidthCalculator, WidthOptions};
+#[cfg(feature = "std")]
+pub use reader::{measure_reader, LineWidths};
+pub use runs::{CellKind, CellKinds, CharWidthIndices, WideCharColumns, WidthRuns};
+pub use scripts::ScriptSet;
 
+/// Asserts that a string's displayed width in columns, as computed by
+/// [`UnicodeWidthStr::width`], equals an expected value.
+///
+/// On failure, this panics with a message showing the string, its actual width, and the width
+/// of each of its characters (along with their byte offsets), to make layout bugs easier to
+/// track down.
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate unicode_width;
+///
+/// fn main() {
+///     assert_width!("日本語", 6);
+///     assert_width!("e\u{301}", 1);
+/// }
+/// ```
+///
+/// ```rust,should_panic
+/// #[macro_use]
+/// extern crate unicode_width;
+///
+/// fn main() {
+///     // panics with:
+///     // assertion failed: `width("a\u{FF22}") == 2`
+///     //   actual width: 3
+///     //   widths:
+///     //        0: U+0061 'a' => 1
+///     //        1: U+FF22 'Ｂ' => 2
+///     assert_width!("a\u{FF22}", 2);
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_width {
+    ($s:expr, $expected:expr $(,)*) => {{
+        let s: &str = &$s;
+        let expected: usize = $expected;
+        let actual = $crate::UnicodeWidthStr::width(s);
+        if actual != expected {
+            $crate::assert_width_failed(s, expected, actual);
+        }
+    }};
+}
+
+#[cfg(feature = "ansi")]
+mod ansi;
+mod display;
+#[cfg(feature = "segmentation")]
+mod graphemes;
+mod options;
+#[cfg(feature = "std")]
+mod reader;
+mod runs;
+mod scripts;
+#[cfg(not(any(feature = "unicode-15-0", feature = "unicode-15-1")))]
+mod tables;
+#[cfg(all(feature = "unicode-15-0", not(feature = "unicode-15-1")))]
+#[path = "tables_15_0.rs"]
 mod tables;
+#[cfg(feature = "unicode-15-1")]
+#[path = "tables_15_1.rs"]
+mod tables;
+
+#[cfg(all(feature = "unicode-15-0", feature = "unicode-15-1"))]
+compile_error!("the `unicode-15-0` and `unicode-15-1` features are mutually exclusive");
 
 #[cfg(test)]
 mod tests;
 
 /// Methods for determining displayed width of Unicode characters.
+///
+/// Implementors only need to provide [`width`](UnicodeWidthChar::width) and
+/// [`width_cjk`](UnicodeWidthChar::width_cjk). The other methods have default implementations,
+/// which are available to types that convert `Into<char>`.
 pub trait UnicodeWidthChar {
     /// Returns the character's displayed width in columns, or `None` if the
     /// character is a control character other than `'\x00'`.
@@ -75,6 +210,17 @@ pub trait UnicodeWidthChar {
     /// to [Unicode Standard Annex #11](http://www.unicode.org/reports/tr11/)
     /// as 1 column wide. This is consistent with the recommendations for non-CJK
     /// contexts, or when the context cannot be reliably determined.
+    ///
+    /// Nonspacing and enclosing combining marks (general categories `Mn` and `Me`), format
+    /// characters (`Cf`), and assigned `Default_Ignorable_Code_Point` characters such as
+    /// U+200B ZERO WIDTH SPACE, U+2060 WORD JOINER and U+FEFF ZERO WIDTH NO-BREAK SPACE are
+    /// zero-width, as are the Hangul conjoining jamo medial vowels and final consonants
+    /// (U+1160..=U+11FF, U+D7B0..=U+D7C6 and U+D7CB..=U+D7FB), which are drawn in the same
+    /// syllable block as the wide leading consonant before them. The exceptions are U+00AD SOFT
+    /// HYPHEN, which is 1 column wide, and the Hangul fillers U+115F and U+3164 (2 columns wide)
+    /// and U+FFA0 (1 column wide). Unassigned
+    /// default-ignorable codepoints, such as U+2065 and U+E0000, are 1 column wide like other
+    /// unassigned codepoints.
     fn width(self) -> Option<usize>;
 
     /// Returns the character's displayed width in columns, or `None` if the
@@ -85,6 +231,123 @@ pub trait UnicodeWidthChar {
     /// as 2 columns wide. This is consistent with the recommendations for
     /// CJK contexts.
     fn width_cjk(self) -> Option<usize>;
+
+    /// Returns the character's displayed width in columns according to `opts`, or `None` if
+    /// the character is a control character other than `'\x00'`.
+    #[inline]
+    fn width_with(self, opts: WidthOptions) -> Option<usize>
+    where
+        Self: Into<char>,
+    {
+        opts.char_width(self.into())
+    }
+
+    /// Returns the same width as [`width`](UnicodeWidthChar::width), as a `u16`.
+    #[inline]
+    fn width_u16(self) -> Option<u16>
+    where
+        Self: Sized,
+    {
+        self.width().map(|w| w as u16)
+    }
+
+    /// Returns `Some(n)` if the character is the variation selector VS*n*, or `None` otherwise.
+    ///
+    /// U+FE00..=U+FE0F are VS1 through VS16, and U+E0100..=U+E01EF (the Variation Selectors
+    /// Supplement) are VS17 through VS256. Variation selectors select a glyph variant of the
+    /// preceding character; for instance, VS16 requests emoji presentation. They are always
+    /// zero-width on their own.
+    #[inline]
+    fn variation_selector_index(self) -> Option<u16>
+    where
+        Self: Into<char>,
+    {
+        match self.into() {
+            c @ '\u{FE00}'..='\u{FE0F}' => Some(c as u16 - 0xFE00 + 1),
+            c @ '\u{E0100}'..='\u{E01EF}' => Some((c as u32 - 0xE0100 + 17) as u16),
+            _ => None,
+        }
+    }
+
+    /// Returns the character's East Asian Width category, as defined by
+    /// [Unicode Standard Annex #11](http://www.unicode.org/reports/tr11/).
+    ///
+    /// This is the raw property, before it's resolved into a width: for instance, zero-width
+    /// combining marks are usually `Ambiguous` or `Neutral`.
+    #[inline]
+    fn east_asian_width(self) -> EastAsianWidth
+    where
+        Self: Into<char>,
+    {
+        cw::east_asian_width(self.into())
+    }
+
+    /// Returns `true` if the character is a private-use codepoint: in the BMP Private Use Area
+    /// (U+E000..=U+F8FF), or in Supplementary Private Use Area-A or -B (planes 15 and 16).
+    ///
+    /// Private-use characters have no standard glyph, so fonts like Nerd Fonts assign their own,
+    /// and their displayed width depends on the font. They're measured as 1 column wide by
+    /// default (or 2 columns wide in CJK contexts, since they're Ambiguous), so applications
+    /// which know better can use this to detect them and override their widths, for instance
+    /// with [`AmbiguousPolicy::Custom`].
+    #[allow(clippy::wrong_self_convention)]
+    #[inline]
+    fn is_private_use(self) -> bool
+    where
+        Self: Into<char>,
+    {
+        matches!(
+            self.into(),
+            '\u{E000}'..='\u{F8FF}' | '\u{F0000}'..='\u{FFFFD}' | '\u{100000}'..='\u{10FFFD}'
+        )
+    }
+
+    /// Returns `true` if the character is one of the 66 noncharacters: U+FDD0..=U+FDEF, and the
+    /// last two codepoints of every plane, from U+FFFE and U+FFFF up to U+10FFFE and U+10FFFF.
+    ///
+    /// Noncharacters are permanently reserved for internal use, so they shouldn't appear in
+    /// interchanged text. They're measured as 1 column wide, since they're usually rendered as
+    /// a "tofu" box; use [`WidthOptions::noncharacter_width`] to measure them differently.
+    #[allow(clippy::wrong_self_convention)]
+    #[inline]
+    fn is_noncharacter(self) -> bool
+    where
+        Self: Into<char>,
+    {
+        let c = self.into();
+        matches!(c, '\u{FDD0}'..='\u{FDEF}') || (c as u32) & 0xFFFE == 0xFFFE
+    }
+}
+
+/// How [`UnicodeWidthStr::width_with_mark_classifier`] should measure a combining mark.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MarkKind {
+    /// The mark advances the cursor by 1 column.
+    Spacing,
+    /// The mark is drawn over or around the previous character, without advancing the cursor.
+    NonSpacing,
+    /// The mark should be measured like any other character, using its usual width.
+    NotAMark,
+}
+
+/// The East Asian Width category of a character, as defined by
+/// [Unicode Standard Annex #11](http://www.unicode.org/reports/tr11/) and returned by
+/// [`UnicodeWidthChar::east_asian_width`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EastAsianWidth {
+    /// Characters which don't occur in East Asian typography (`N`), which are narrow.
+    Neutral,
+    /// Narrow characters with wide counterparts (`Na`), such as ASCII.
+    Narrow,
+    /// Wide characters (`W`), such as ideographs.
+    Wide,
+    /// Fullwidth compatibility forms of narrow characters (`F`), such as U+FF21 FULLWIDTH
+    /// LATIN CAPITAL LETTER A.
+    FullWidth,
+    /// Halfwidth compatibility forms of wide characters (`H`), such as halfwidth katakana.
+    HalfWidth,
+    /// Characters which are wide in East Asian legacy encodings and narrow elsewhere (`A`).
+    Ambiguous,
 }
 
 impl UnicodeWidthChar for char {
@@ -96,6 +359,10 @@ impl UnicodeWidthChar for char {
 }
 
 /// Methods for determining displayed width of Unicode strings.
+///
+/// Implementors only need to provide [`width`](UnicodeWidthStr::width) and
+/// [`width_cjk`](UnicodeWidthStr::width_cjk). The other methods have default implementations,
+/// which are available to types that implement `AsRef<str>`.
 pub trait UnicodeWidthStr {
     /// Returns the string's displayed width in columns.
     ///
@@ -105,7 +372,27 @@ pub trait UnicodeWidthStr {
     /// to [Unicode Standard Annex #11](http://www.unicode.org/reports/tr11/)
     /// as 1 column wide. This is consistent with the recommendations for
     /// non-CJK contexts, or when the context cannot be reliably determined.
-    fn width<'a>(&'a self) -> usize;
+    ///
+    /// Variation selectors which change the presentation of an emoji change its width: a
+    /// 1-column emoji followed by U+FE0F VARIATION SELECTOR-16 (emoji presentation) is 2 columns
+    /// wide, and a 2-column emoji followed by U+FE0E VARIATION SELECTOR-15 (text presentation)
+    /// is 1 column wide. Ambiguous emoji, which are only 2 columns wide in CJK contexts, stay 2
+    /// columns wide there. Otherwise, variation selectors are zero-width.
+    ///
+    /// Consecutive regional indicators (U+1F1E6..U+1F1FF) pair up into flags, each of which is
+    /// 2 columns wide. An unpaired regional indicator is 2 columns wide as well.
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// assert_eq!("\u{2600}".width(), 1);
+    /// assert_eq!("\u{2600}\u{FE0F}".width(), 2);
+    /// assert_eq!("\u{231A}".width(), 2);
+    /// assert_eq!("\u{231A}\u{FE0E}".width(), 1);
+    /// // Flags of the United States and Japan, then a lone regional indicator
+    /// assert_eq!("\u{1F1FA}\u{1F1F8}\u{1F1EF}\u{1F1F5}\u{1F1EF}".width(), 6);
+    /// ```
+    fn width(&self) -> usize;
 
     /// Returns the string's displayed width in columns.
     ///
@@ -114,18 +401,2411 @@ pub trait UnicodeWidthStr {
     /// This function treats characters in the Ambiguous category according
     /// to [Unicode Standard Annex #11](http://www.unicode.org/reports/tr11/)
     /// as 2 column wide. This is consistent with the recommendations for
-    /// CJK contexts.
-    fn width_cjk<'a>(&'a self) -> usize;
+    /// CJK contexts. Variation selectors are handled as in
+    /// [`width`](UnicodeWidthStr::width).
+    fn width_cjk(&self) -> usize;
+
+    /// Returns the string's displayed width in columns according to `opts`.
+    ///
+    /// Control characters are treated as having zero width, unless `opts` gives them a width.
+    /// Variation selectors adjust the width of the emoji before them as in
+    /// [`width`](UnicodeWidthStr::width), so the default options measure exactly like `width`,
+    /// and `WidthOptions::new().ambiguous_wide(true)` exactly like
+    /// [`width_cjk`](UnicodeWidthStr::width_cjk).
+    ///
+    /// ```rust
+    /// use unicode_width::{UnicodeWidthStr, WidthOptions};
+    ///
+    /// let opts = WidthOptions::new().ambiguous_wide(true).control_width(Some(1));
+    /// assert_eq!("\u{3B1}\x1B".width_with(opts), 3);
+    /// ```
+    #[inline]
+    fn width_with(&self, opts: WidthOptions) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        WidthCalculator::new().options(opts).measure(self.as_ref())
+    }
+
+    /// Returns the same width as [`width`](UnicodeWidthStr::width), as a `u16`. Widths which
+    /// don't fit saturate at `u16::MAX`.
+    #[inline]
+    fn width_u16(&self) -> u16 {
+        cmp::min(self.width(), u16::MAX as usize) as u16
+    }
+
+    /// Returns the same width as [`width`](UnicodeWidthStr::width), or `None` if it overflows
+    /// `usize`.
+    ///
+    /// The width is summed with checked arithmetic, one character at a time, so that code
+    /// measuring untrusted input (such as on 16-bit targets) can guard against overflow
+    /// explicitly rather than relying on no character being wider than its UTF-8 encoding.
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// assert_eq!("Ｈｅｌｌｏ".checked_width(), Some(10));
+    /// ```
+    #[inline]
+    fn checked_width(&self) -> Option<usize>
+    where
+        Self: AsRef<str>,
+    {
+        accumulate_width(self.as_ref(), 0, usize::checked_add)
+    }
+
+    /// Returns the same width as [`width`](UnicodeWidthStr::width), saturating at `usize::MAX`
+    /// instead of overflowing.
+    #[inline]
+    fn saturating_width(&self) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        let add = |total: usize, w| Some(total.saturating_add(w));
+        accumulate_width(self.as_ref(), 0, add).unwrap_or(usize::MAX)
+    }
+
+    /// Returns the string's length in `char`s and its displayed width in columns, computed in a
+    /// single pass. This is equivalent to `(self.chars().count(), self.width())`.
+    #[inline]
+    fn len_and_width(&self) -> (usize, usize)
+    where
+        Self: AsRef<str>,
+    {
+        let start = (0, 0, WidthState::new());
+        let (len, width, _) = self.as_ref().chars().fold(start, |(len, width, state), c| {
+            (len + 1, state.add_width(width, c, false), state.after(c))
+        });
+        (len, width)
+    }
+
+    /// Returns the string's length in `char`s and its displayed width in columns with
+    /// Ambiguous characters treated as 2 columns wide. This is equivalent to
+    /// `(self.chars().count(), self.width_cjk())`.
+    #[inline]
+    fn len_and_width_cjk(&self) -> (usize, usize)
+    where
+        Self: AsRef<str>,
+    {
+        let start = (0, 0, WidthState::new());
+        let (len, width, _) = self.as_ref().chars().fold(start, |(len, width, state), c| {
+            (len + 1, state.add_width(width, c, true), state.after(c))
+        });
+        (len, width)
+    }
+
+    /// Returns the string's displayed width in columns if ASCII control characters are shown in
+    /// caret notation, as hex viewers and `cat -v` do: `'\x01'` is shown as `^A`, `'\x00'` as
+    /// `^@`, and DEL as `^?`.
+    ///
+    /// Each C0 control character (including `'\x00'`) and DEL therefore contributes 2 columns.
+    /// All other characters, including C1 control characters, are measured as by
+    /// [`width`](UnicodeWidthStr::width).
+    #[inline]
+    fn width_caret_notation(&self) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        self.as_ref()
+            .chars()
+            .map(|c| match c {
+                '\0'..='\x1F' | '\x7F' => 2,
+                _ => cw::width(c, false).unwrap_or(0),
+            })
+            .sum()
+    }
+
+    /// Returns `true` if every character in the string is printable ASCII (U+0020..=U+007E) or
+    /// in the Latin-1 Supplement, Latin Extended-A, or Latin Extended-B blocks, excluding the C1
+    /// control characters (U+00A0..=U+024F).
+    ///
+    /// These characters are all 1 column wide and none of them are combining marks, so if this
+    /// returns `true`, [`width`](UnicodeWidthStr::width) is `self.chars().count()`. (Some of
+    /// them are Ambiguous, so this doesn't hold for [`width_cjk`](UnicodeWidthStr::width_cjk).)
+    /// Precomposed accented letters like `'é'` qualify, but decomposed ones don't.
+    #[inline]
+    fn is_simple_latin(&self) -> bool
+    where
+        Self: AsRef<str>,
+    {
+        self.as_ref()
+            .chars()
+            .all(|c| matches!(c, '\u{20}'..='\u{7E}' | '\u{A0}'..='\u{24F}'))
+    }
+
+    /// Returns an iterator over the runs of consecutive characters with equal widths in the
+    /// string, as `(width, count)` pairs. This lets renderers handle same-width cells in
+    /// batches.
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// let runs: Vec<_> = "ab\u{FF23}\u{FF24}e".width_runs().collect();
+    /// assert_eq!(runs, [(1, 2), (2, 2), (1, 1)]);
+    /// ```
+    #[inline]
+    fn width_runs(&self) -> WidthRuns<'_>
+    where
+        Self: AsRef<str>,
+    {
+        WidthRuns::new(self.as_ref(), false)
+    }
+
+    /// Returns an iterator over the runs of consecutive characters with equal widths in the
+    /// string like [`width_runs`](UnicodeWidthStr::width_runs), with Ambiguous characters
+    /// treated as 2 columns wide.
+    #[inline]
+    fn width_runs_cjk(&self) -> WidthRuns<'_>
+    where
+        Self: AsRef<str>,
+    {
+        WidthRuns::new(self.as_ref(), true)
+    }
+
+    /// Returns an iterator over the 0-based starting columns of the double-width characters in
+    /// the string.
+    ///
+    /// This is meant for diagnostics like column rulers, which show where double-width cells
+    /// sit. Columns are measured as by [`width`](UnicodeWidthStr::width).
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// let columns: Vec<_> = "a\u{FF22}c\u{FF24}".wide_char_columns().collect();
+    /// assert_eq!(columns, [1, 4]);
+    /// ```
+    #[inline]
+    fn wide_char_columns(&self) -> WideCharColumns<'_>
+    where
+        Self: AsRef<str>,
+    {
+        WideCharColumns::new(self.as_ref(), false)
+    }
+
+    /// Returns an iterator over the 0-based starting columns of the double-width characters in
+    /// the string like [`wide_char_columns`](UnicodeWidthStr::wide_char_columns), with
+    /// Ambiguous characters treated as 2 columns wide.
+    #[inline]
+    fn wide_char_columns_cjk(&self) -> WideCharColumns<'_>
+    where
+        Self: AsRef<str>,
+    {
+        WideCharColumns::new(self.as_ref(), true)
+    }
+
+    /// Returns an iterator over the terminal cells the string occupies, yielding one
+    /// [`CellKind`] per column.
+    ///
+    /// A double-width character yields [`CellKind::WideLeft`] for its first column and
+    /// [`CellKind::WideRight`] for the continuation cell, so a renderer can tell which half of a
+    /// character falls at a viewport edge. Zero-width characters (including control characters)
+    /// yield [`CellKind::ZeroWidth`], which doesn't occupy a column, so that combining marks can
+    /// be attached to the preceding cell. Widths are measured as by
+    /// [`width`](UnicodeWidthStr::width).
+    ///
+    /// ```rust
+    /// use unicode_width::{CellKind, UnicodeWidthStr};
+    ///
+    /// let cells: Vec<_> = "a\u{FF22}".cell_kinds().collect();
+    /// assert_eq!(
+    ///     cells,
+    ///     [CellKind::Narrow('a'), CellKind::WideLeft('\u{FF22}'), CellKind::WideRight]
+    /// );
+    /// ```
+    #[inline]
+    fn cell_kinds(&self) -> CellKinds<'_>
+    where
+        Self: AsRef<str>,
+    {
+        CellKinds::new(self.as_ref(), false)
+    }
+
+    /// Returns an iterator over the terminal cells the string occupies like
+    /// [`cell_kinds`](UnicodeWidthStr::cell_kinds), with Ambiguous characters treated as 2
+    /// columns wide.
+    #[inline]
+    fn cell_kinds_cjk(&self) -> CellKinds<'_>
+    where
+        Self: AsRef<str>,
+    {
+        CellKinds::new(self.as_ref(), true)
+    }
+
+    /// Returns an iterator over the characters of the string along with their byte offsets and
+    /// displayed widths in columns, as `(offset, char, width)` triples.
+    ///
+    /// This is meant for mapping between columns and byte offsets, as when moving a cursor,
+    /// without measuring the string twice. Control characters are 0 columns wide, and an emoji
+    /// followed by a variation selector has the width the selector gives it (the selector itself
+    /// is 0 columns wide), so the widths add up to [`width`](UnicodeWidthStr::width).
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// let chars: Vec<_> = "a\u{FF22}\u{301}".char_width_indices().collect();
+    /// assert_eq!(chars, [(0, 'a', 1), (1, '\u{FF22}', 2), (4, '\u{301}', 0)]);
+    /// ```
+    #[inline]
+    fn char_width_indices(&self) -> CharWidthIndices<'_>
+    where
+        Self: AsRef<str>,
+    {
+        CharWidthIndices::new(self.as_ref(), false)
+    }
+
+    /// Returns an iterator over the characters of the string along with their byte offsets and
+    /// displayed widths like [`char_width_indices`](UnicodeWidthStr::char_width_indices), with
+    /// Ambiguous characters treated as 2 columns wide.
+    #[inline]
+    fn char_width_indices_cjk(&self) -> CharWidthIndices<'_>
+    where
+        Self: AsRef<str>,
+    {
+        CharWidthIndices::new(self.as_ref(), true)
+    }
+
+    /// Returns the byte offset of the character occupying the 0-based column `col`, or `None`
+    /// if the string is narrower than `col + 1` columns.
+    ///
+    /// Every column of a wide character maps to the start of that character, so clicking on
+    /// either half of it resolves to the same offset, which makes this suitable for mouse
+    /// hit-testing. Zero-width characters occupy no column, so their offsets are never returned.
+    /// Widths are as given by [`char_width_indices`](UnicodeWidthStr::char_width_indices).
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// assert_eq!("a\u{FF22}c".byte_offset_at_column(1), Some(1));
+    /// assert_eq!("a\u{FF22}c".byte_offset_at_column(2), Some(1));
+    /// assert_eq!("a\u{FF22}c".byte_offset_at_column(3), Some(4));
+    /// assert_eq!("a\u{FF22}c".byte_offset_at_column(4), None);
+    /// ```
+    #[inline]
+    fn byte_offset_at_column(&self, col: usize) -> Option<usize>
+    where
+        Self: AsRef<str>,
+    {
+        let mut start = 0;
+        for (i, _, width) in self.char_width_indices() {
+            if col < start + width {
+                return Some(i);
+            }
+            start += width;
+        }
+        None
+    }
+
+    /// Returns the 0-based column at which the character containing byte offset `off` starts,
+    /// which is the inverse of [`byte_offset_at_column`](UnicodeWidthStr::byte_offset_at_column).
+    ///
+    /// An offset in the middle of a character maps to that character's column, and an offset
+    /// at or past the end of the string maps to its [`width`](UnicodeWidthStr::width).
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// assert_eq!("a\u{FF22}c".column_at_byte_offset(1), 1);
+    /// assert_eq!("a\u{FF22}c".column_at_byte_offset(4), 3);
+    /// assert_eq!("a\u{FF22}c".column_at_byte_offset(5), 4);
+    /// ```
+    #[inline]
+    fn column_at_byte_offset(&self, off: usize) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        self.char_width_indices()
+            .take_while(|&(i, c, _)| i + c.len_utf8() <= off)
+            .map(|(_, _, width)| width)
+            .sum()
+    }
+
+    /// Returns the displayed width in columns of the string's first line, or of the whole
+    /// string if it has only one line.
+    ///
+    /// Lines end at `'\n'` (possibly preceded by `'\r'`, which is zero-width), U+2028 LINE
+    /// SEPARATOR, or U+2029 PARAGRAPH SEPARATOR. A lone `'\r'` doesn't end a line.
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// assert_eq!("\u{FF21}\u{FF22}\ncd".first_line_width(), 4);
+    /// ```
+    #[inline]
+    fn first_line_width(&self) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        str_width(first_line(self.as_ref()), false)
+    }
+
+    /// Returns the displayed width in columns of the string's first line like
+    /// [`first_line_width`](UnicodeWidthStr::first_line_width), with Ambiguous characters
+    /// treated as 2 columns wide.
+    #[inline]
+    fn first_line_width_cjk(&self) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        str_width(first_line(self.as_ref()), true)
+    }
+
+    /// Returns the string's displayed width in columns, letting `classify` decide how wide each
+    /// combining mark (general category `Mn`, `Mc`, or `Me`) is.
+    ///
+    /// `classify` is only called for combining marks. A [`MarkKind::Spacing`] mark is 1 column
+    /// wide, a [`MarkKind::NonSpacing`] mark is zero-width, and a [`MarkKind::NotAMark`] mark
+    /// keeps the width [`width`](UnicodeWidthStr::width) would give it. This is meant for
+    /// matching terminals which disagree with Unicode about whether some marks advance the
+    /// cursor.
+    ///
+    /// ```rust
+    /// use unicode_width::{MarkKind, UnicodeWidthStr};
+    ///
+    /// // A terminal which draws U+0301 COMBINING ACUTE ACCENT in its own cell
+    /// let f = |c| if c == '\u{301}' { MarkKind::Spacing } else { MarkKind::NotAMark };
+    /// assert_eq!("e\u{301}".width_with_mark_classifier(&f), 2);
+    /// ```
+    #[inline]
+    fn width_with_mark_classifier(&self, classify: &dyn Fn(char) -> MarkKind) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        self.as_ref()
+            .chars()
+            .map(|c| {
+                let kind = if cw::is_mark(c) { classify(c) } else { MarkKind::NotAMark };
+                match kind {
+                    MarkKind::Spacing => 1,
+                    MarkKind::NonSpacing => 0,
+                    MarkKind::NotAMark => cw::width(c, false).unwrap_or(0),
+                }
+            })
+            .sum()
+    }
+
+    /// Returns the displayed width of the string in columns, with each combining mark (general
+    /// category `Mn`, `Mc`, or `Me`) for which `is_trailing` returns `true` drawn in an extra
+    /// cell after its base.
+    ///
+    /// This is a compatibility model for fonts and terminals which can't overlay some marks on
+    /// their base, and instead put them in a cell of their own. Those marks are 1 column wide,
+    /// while other marks keep the width [`width`](UnicodeWidthStr::width) would give them
+    /// (usually 0). `is_trailing` is only called for combining marks. This is a shorthand for
+    /// [`width_with_mark_classifier`](UnicodeWidthStr::width_with_mark_classifier) with
+    /// [`MarkKind::Spacing`] for the trailing marks.
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// // A font which can't stack U+0302 COMBINING CIRCUMFLEX ACCENT
+    /// let is_trailing = |c| c == '\u{302}';
+    /// assert_eq!("e\u{302}".width_with_trailing_marks(&is_trailing), 2);
+    /// assert_eq!("e\u{301}".width_with_trailing_marks(&is_trailing), 1);
+    /// ```
+    #[inline]
+    fn width_with_trailing_marks(&self, is_trailing: &dyn Fn(char) -> bool) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        self.width_with_mark_classifier(&|c| {
+            if is_trailing(c) { MarkKind::Spacing } else { MarkKind::NotAMark }
+        })
+    }
+
+    /// Returns the displayed width of the string in columns, using `base_rules` to decide how
+    /// wide each combining mark (general category `Mn`, `Mc`, or `Me`) is given the character
+    /// it's attached to.
+    ///
+    /// `base_rules(base, mark)` is called for each mark, with the last character before it
+    /// which isn't a mark, and returns the number of columns the mark adds. This is an escape
+    /// hatch for script-specific shaping rules which a per-character table can't express, such
+    /// as a spacing mark whose advance depends on its base. Other characters, and marks at the
+    /// start of the string, keep the width [`width`](UnicodeWidthStr::width) would give them.
+    /// A `base_rules` which returns the mark's own width reproduces `width`.
+    ///
+    /// This makes a dynamic call for every mark, so it's slower than `width` on text with many
+    /// marks. Text without marks costs about the same as `width`.
+    ///
+    /// ```rust
+    /// use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+    ///
+    /// // U+0BBE TAMIL VOWEL SIGN AA only advances the cursor after U+0B95 TAMIL LETTER KA
+    /// let rule = |base, mark: char| match (base, mark) {
+    ///     ('\u{B95}', '\u{BBE}') => 1,
+    ///     (_, '\u{BBE}') => 0,
+    ///     _ => mark.width().unwrap_or(0) as u8,
+    /// };
+    /// assert_eq!("\u{B95}\u{BBE}".width_complex(&rule), 2);
+    /// assert_eq!("\u{B9A}\u{BBE}".width_complex(&rule), 1);
+    /// ```
+    #[inline]
+    fn width_complex(&self, base_rules: &dyn Fn(char, char) -> u8) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        let mut base = None;
+        self.as_ref()
+            .chars()
+            .map(|c| match base {
+                Some(b) if cw::is_mark(c) => base_rules(b, c) as usize,
+                _ => {
+                    if !cw::is_mark(c) {
+                        base = Some(c);
+                    }
+                    cw::width(c, false).unwrap_or(0)
+                }
+            })
+            .sum()
+    }
+
+    /// Returns the displayed width in columns of the string without its trailing whitespace.
+    ///
+    /// Whitespace is as defined by [`char::is_whitespace`], so this also trims U+3000
+    /// IDEOGRAPHIC SPACE, which is 2 columns wide. Leading whitespace is still measured.
+    #[inline]
+    fn trimmed_width(&self) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        self.as_ref().trim_end().width()
+    }
+
+    /// Returns the displayed width in columns of the string without its trailing whitespace,
+    /// with Ambiguous characters treated as 2 columns wide.
+    #[inline]
+    fn trimmed_width_cjk(&self) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        self.as_ref().trim_end().width_cjk()
+    }
+
+    /// Returns the displayed width in columns of the widest line in the string, as for the
+    /// bounding box of a block of text.
+    ///
+    /// Lines are split on `'\n'`, and a `'\r'` before it is zero-width like any other control
+    /// character, so `"\r\n"` line endings measure the same. Each line is measured as by
+    /// [`width`](UnicodeWidthStr::width), and a trailing newline doesn't start another line
+    /// wider than 0 columns.
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// assert_eq!("ab\r\nｃｄｅ\nf\n".max_line_width(), 6);
+    /// assert_eq!("".max_line_width(), 0);
+    /// ```
+    #[inline]
+    fn max_line_width(&self) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        max_line_width(self.as_ref(), false)
+    }
+
+    /// Returns the displayed width in columns of the widest line in the string, with Ambiguous
+    /// characters treated as 2 columns wide.
+    #[inline]
+    fn max_line_width_cjk(&self) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        max_line_width(self.as_ref(), true)
+    }
+
+    /// Returns the displayed width of the string in columns with tab stops every `tab_size`
+    /// columns.
+    ///
+    /// Each tab advances to the next multiple of `tab_size` columns from the start of its line,
+    /// the way a terminal expands it, rather than being measured as a zero-width control
+    /// character. A `tab_size` of `0` treats tabs as zero-width. Other characters are measured
+    /// as by [`width`](UnicodeWidthStr::width). This is the same as
+    /// [`width_with_tabs_from`](UnicodeWidthStr::width_with_tabs_from) starting at column 0.
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// assert_eq!("a\tbc".width_with_tabs(4), 6);
+    /// assert_eq!("abcd\t".width_with_tabs(4), 8);
+    /// ```
+    #[inline]
+    fn width_with_tabs(&self, tab_size: usize) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        self.width_with_tabs_from(tab_size, 0)
+    }
+
+    /// Returns the displayed width of the string in columns when it starts at the 0-based
+    /// column `start_col`, with tab stops every `tab_width` columns.
+    ///
+    /// Each tab advances to the next multiple of `tab_width` columns from the start of the line,
+    /// so the first tab only adds the columns between `start_col` (plus the width of whatever
+    /// precedes the tab) and the next tab stop. This is for measuring a fragment of a line which
+    /// doesn't start at column 0, like text after indentation or a wrapped continuation. After a
+    /// `'\n'` or `'\r'`, columns count from 0 again rather than from `start_col`. A `tab_width`
+    /// of `0` measures tabs like other control characters. The result is the number of columns
+    /// the string adds, not the column it ends at; other characters are measured as by
+    /// [`width`](UnicodeWidthStr::width). See [`WidthCalculator::measure_from`] for more options.
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// assert_eq!("\t".width_with_tabs_from(4, 0), 4);
+    /// assert_eq!("\t".width_with_tabs_from(4, 2), 2);
+    /// ```
+    #[inline]
+    fn width_with_tabs_from(&self, tab_width: usize, start_col: usize) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        WidthCalculator::new().tab_width(tab_width).measure_from(self.as_ref(), start_col)
+    }
+
+    /// Returns the column just past the last character of the string which isn't whitespace,
+    /// or `None` if the string is empty or entirely whitespace.
+    ///
+    /// This is where an editor's "end of line content" motion puts the cursor. Whitespace is as
+    /// defined by [`char::is_whitespace`], so U+3000 IDEOGRAPHIC SPACE is skipped too. Columns
+    /// are 0-based, and measured as by [`width`](UnicodeWidthStr::width), so a result of
+    /// `Some(n)` means the content occupies the first `n` columns.
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// assert_eq!("ab  ".last_ink_column(), Some(2));
+    /// assert_eq!("日本\u{3000}".last_ink_column(), Some(4));
+    /// assert_eq!(" \t".last_ink_column(), None);
+    /// ```
+    #[inline]
+    fn last_ink_column(&self) -> Option<usize>
+    where
+        Self: AsRef<str>,
+    {
+        let content = self.as_ref().trim_end();
+        if content.is_empty() { None } else { Some(content.width()) }
+    }
+
+    /// Returns the column just past the last character of the string which isn't whitespace
+    /// like [`last_ink_column`](UnicodeWidthStr::last_ink_column), with Ambiguous characters
+    /// treated as 2 columns wide.
+    #[inline]
+    fn last_ink_column_cjk(&self) -> Option<usize>
+    where
+        Self: AsRef<str>,
+    {
+        let content = self.as_ref().trim_end();
+        if content.is_empty() { None } else { Some(content.width_cjk()) }
+    }
+
+    /// Returns the displayed width of the string in columns after collapsing each run of
+    /// whitespace to a single space, as HTML does by default.
+    ///
+    /// The collapsed whitespace is HTML's ASCII whitespace: `' '`, `'\t'`, `'\n'`, `'\x0C'` (form
+    /// feed) and `'\r'`. Any run of these, including a run at the start or end of the string,
+    /// counts as 1 column; use [`str::trim`] first to drop leading and trailing runs entirely.
+    /// Other whitespace, such as U+00A0 NO-BREAK SPACE and U+3000 IDEOGRAPHIC SPACE, isn't
+    /// collapsed, and everything else is measured as by [`width`](UnicodeWidthStr::width).
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// assert_eq!("a   b".collapsed_width(), 3);
+    /// assert_eq!(" a\n\t b ".collapsed_width(), 5);
+    /// assert_eq!("a\u{A0}\u{A0}b".collapsed_width(), 4);
+    /// ```
+    #[inline]
+    fn collapsed_width(&self) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        collapsed_width(self.as_ref(), false)
+    }
+
+    /// Returns the displayed width of the string in columns after collapsing each run of
+    /// whitespace like [`collapsed_width`](UnicodeWidthStr::collapsed_width), with Ambiguous
+    /// characters treated as 2 columns wide.
+    #[inline]
+    fn collapsed_width_cjk(&self) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        collapsed_width(self.as_ref(), true)
+    }
+
+    /// Returns the displayed width of the string in columns, measuring each emoji ZWJ sequence
+    /// as a single glyph.
+    ///
+    /// Emoji joined by U+200D ZERO WIDTH JOINER, such as the family 👨‍👩‍👧‍👦, are drawn as one
+    /// glyph by terminals which support them, so each such sequence is measured as its widest
+    /// emoji rather than the sum of them. The emoji skin tone modifiers U+1F3FB..U+1F3FF are
+    /// likewise drawn as part of the emoji they follow. A joiner which doesn't follow an emoji,
+    /// or which isn't followed by one, joins nothing, and is zero-width like any other joiner.
+    /// Everything else is measured as by [`width`](UnicodeWidthStr::width).
+    ///
+    /// Unlike `width_graphemes` (with the `segmentation` feature), this doesn't segment the
+    /// string into grapheme clusters, so it's available without dependencies.
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// // MAN, ZWJ, WOMAN, ZWJ, GIRL, ZWJ, BOY
+    /// let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}";
+    /// assert_eq!(family.width(), 8);
+    /// assert_eq!(family.width_clusters(), 2);
+    /// assert_eq!("a\u{200D}b".width_clusters(), 2);
+    /// ```
+    #[inline]
+    fn width_clusters(&self) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        zwj_sequence_width(self.as_ref(), false)
+    }
+
+    /// Returns the displayed width of the string in columns, measuring each emoji ZWJ sequence
+    /// as a single glyph like [`width_clusters`](UnicodeWidthStr::width_clusters), with
+    /// Ambiguous characters treated as 2 columns wide.
+    #[inline]
+    fn width_clusters_cjk(&self) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        zwj_sequence_width(self.as_ref(), true)
+    }
+
+    /// Splits the string into two parts at a column boundary, so that the first part is at
+    /// most `column` columns wide.
+    ///
+    /// A double-width character which would straddle the boundary goes entirely to the
+    /// second part, even though that leaves the first part narrower than `column`. Zero-width
+    /// characters, such as combining marks, stay with the character they follow.
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// assert_eq!("a\u{FF22}c".split_at_width(2), ("a", "\u{FF22}c"));
+    /// assert_eq!("a\u{FF22}c".split_at_width(3), ("a\u{FF22}", "c"));
+    /// ```
+    #[inline]
+    fn split_at_width(&self, column: usize) -> (&str, &str)
+    where
+        Self: AsRef<str>,
+    {
+        let s = self.as_ref();
+        s.split_at(exceeding_index(s, column, false).unwrap_or(s.len()))
+    }
+
+    /// Splits the string into two parts at a column boundary like
+    /// [`split_at_width`](UnicodeWidthStr::split_at_width), with Ambiguous characters treated
+    /// as 2 columns wide.
+    #[inline]
+    fn split_at_width_cjk(&self, column: usize) -> (&str, &str)
+    where
+        Self: AsRef<str>,
+    {
+        let s = self.as_ref();
+        s.split_at(exceeding_index(s, column, true).unwrap_or(s.len()))
+    }
+
+    /// Returns the longest prefix of the string which is at most `max` columns wide.
+    ///
+    /// This is the first part of [`split_at_width`](UnicodeWidthStr::split_at_width), so a
+    /// double-width character which would straddle the limit is left out entirely, and
+    /// zero-width characters stay with the character they follow. It's a subslice of the
+    /// string, so nothing is copied.
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// assert_eq!("a\u{FF22}c".truncate_to_width(2), "a");
+    /// assert_eq!("a\u{FF22}c".truncate_to_width(3), "a\u{FF22}");
+    /// assert_eq!("abc".truncate_to_width(10), "abc");
+    /// ```
+    #[inline]
+    fn truncate_to_width(&self, max: usize) -> &str
+    where
+        Self: AsRef<str>,
+    {
+        self.split_at_width(max).0
+    }
+
+    /// Returns the longest prefix of the string which is at most `max` columns wide like
+    /// [`truncate_to_width`](UnicodeWidthStr::truncate_to_width), with Ambiguous characters
+    /// treated as 2 columns wide.
+    #[inline]
+    fn truncate_to_width_cjk(&self, max: usize) -> &str
+    where
+        Self: AsRef<str>,
+    {
+        self.split_at_width_cjk(max).0
+    }
+
+    /// Hard-wraps the string into lines at most `max` columns wide, returning them as subslices
+    /// of the string.
+    ///
+    /// Lines are broken between characters, as late as possible, so there are as few as
+    /// possible. A double-width character which doesn't fit at the end of a line starts the next
+    /// one instead of being split, and zero-width characters stay on the line of the character
+    /// before them. A character wider than `max` gets a line to itself. Each `'\n'` also ends a
+    /// line, and stays at the end of it, so concatenating the lines gives back the string. An
+    /// empty string has no lines. Characters are measured as by
+    /// [`width`](UnicodeWidthStr::width).
+    ///
+    /// Lines never break across U+200D ZERO WIDTH JOINER, like in
+    /// [`grid_cells`], so that emoji ZWJ sequences stay intact: characters
+    /// joined by ZWJs are placed as a unit, which starts the next line if it doesn't fit, and
+    /// overflows its line if it's wider than `max`.
+    ///
+    /// U+00AD SOFT HYPHEN and U+200B ZERO WIDTH SPACE are break opportunities: a line which
+    /// doesn't fit is broken after the last one on it, if there is one, rather than in the middle
+    /// of a word. A soft hyphen is drawn as a hyphen when a line is broken after it, so it only
+    /// counts as a column then, and it's only used as a break opportunity if the hyphen fits; a
+    /// zero width space never takes up a column. Lines are measured as by
+    /// [`wrapped_line_width`].
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// assert_eq!("abc日本".wrap_to_width(4), ["abc", "日本"]);
+    /// // Shown as "foo-" and "bar"
+    /// assert_eq!("foo\u{AD}bar".wrap_to_width(4), ["foo\u{AD}", "bar"]);
+    /// assert_eq!("foo\u{200B}barbaz".wrap_to_width(6), ["foo\u{200B}", "barbaz"]);
+    /// assert_eq!("ab\ncdef".wrap_to_width(3), ["ab\n", "cde", "f"]);
+    /// // MAN, ZWJ, WOMAN, ZWJ, GIRL
+    /// let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    /// assert_eq!(family.wrap_to_width(4), [family]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn wrap_to_width(&self, max: usize) -> alloc::vec::Vec<&str>
+    where
+        Self: AsRef<str>,
+    {
+        wrap_to_width(self.as_ref(), max, false)
+    }
+
+    /// Hard-wraps the string into lines at most `max` columns wide like
+    /// [`wrap_to_width`](UnicodeWidthStr::wrap_to_width), with Ambiguous characters treated as 2
+    /// columns wide.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn wrap_to_width_cjk(&self, max: usize) -> alloc::vec::Vec<&str>
+    where
+        Self: AsRef<str>,
+    {
+        wrap_to_width(self.as_ref(), max, true)
+    }
+
+    /// Returns the number of unassigned codepoints in the string.
+    ///
+    /// Unassigned codepoints (general category `Cn`, which includes noncharacters) have no
+    /// glyph, so they're usually rendered as a "tofu" box whose width depends on the font and
+    /// terminal. Private-use characters are considered assigned.
+    #[inline]
+    fn count_unassigned(&self) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        self.as_ref().chars().filter(|&c| !cw::is_assigned(c)).count()
+    }
+
+    /// Returns `true` if the string contains any characters with the `Bidi_Control` property,
+    /// such as U+202E RIGHT-TO-LEFT OVERRIDE or U+2066 LEFT-TO-RIGHT ISOLATE.
+    ///
+    /// Bidi controls are zero-width, but they can reorder the text around them, so source
+    /// code or identifiers containing them may display differently from how they're parsed
+    /// (see [CVE-2021-42574](https://trojansource.codes/)).
+    #[inline]
+    fn contains_bidi_controls(&self) -> bool
+    where
+        Self: AsRef<str>,
+    {
+        self.as_ref().chars().any(|c| {
+            matches!(
+                c,
+                '\u{61C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+            )
+        })
+    }
+
+    /// Returns the string's displayed width in columns, along with the number of stray
+    /// combining marks it contains, assuming it has already been composed (for instance, by
+    /// normalizing to NFC).
+    ///
+    /// Every character with the `Grapheme_Extend` property, which includes all nonspacing and
+    /// enclosing marks, is counted as a stray mark and treated as zero-width. Composed text can
+    /// still legitimately contain such marks when no precomposed character exists, so a nonzero
+    /// count is a hint that the input wasn't normalized rather than proof.
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// assert_eq!("caf\u{e9}".width_assume_composed(), (4, 0));
+    /// assert_eq!("cafe\u{301}".width_assume_composed(), (4, 1));
+    /// ```
+    #[inline]
+    fn width_assume_composed(&self) -> (usize, usize)
+    where
+        Self: AsRef<str>,
+    {
+        self.as_ref().chars().fold((0, 0), |(width, stray), c| {
+            if cw::is_grapheme_extend(c) {
+                (width, stray + 1)
+            } else {
+                (width + cw::width(c, false).unwrap_or(0), stray)
+            }
+        })
+    }
+
+    /// Returns the string's displayed width in columns, if every character occupies at least
+    /// `floor` columns.
+    ///
+    /// Each character contributes the larger of its own width and `floor`. The floor is applied
+    /// uniformly, so zero-width characters such as combining marks *and* control characters
+    /// (which [`width`](UnicodeWidthStr::width) treats as zero-width) each contribute `floor`
+    /// columns. This is useful for debugging views that render every codepoint in its own cell.
+    ///
+    /// This function treats characters in the Ambiguous category as 1 column wide.
+    #[inline]
+    fn width_with_min_cell(&self, floor: usize) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        self.as_ref()
+            .chars()
+            .map(|c| cmp::max(cw::width(c, false).unwrap_or(0), floor))
+            .sum()
+    }
+
+    /// Returns the number of cells the string occupies in a degraded terminal which forces every
+    /// codepoint into a single cell, clipping wide glyphs.
+    ///
+    /// Every character other than a control character (including `'\x00'`) counts as exactly 1
+    /// column, so this is meant for previewing text in such environments rather than as a
+    /// display width. East Asian Width is ignored, and combining marks and other zero-width
+    /// characters aren't zeroed.
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// assert_eq!("日本".width_force_mono(), 2);
+    /// assert_eq!("e\u{301}".width_force_mono(), 2);
+    /// ```
+    #[inline]
+    fn width_force_mono(&self) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        self.as_ref().chars().filter(|c| !c.is_control()).count()
+    }
+
+    /// Returns the string's displayed width in columns on a terminal which draws every
+    /// character at or above `threshold` as 2 columns wide.
+    ///
+    /// This is a compatibility shim for emulating old terminals which decide width by codepoint
+    /// rather than by [Unicode Standard Annex #11](http://www.unicode.org/reports/tr11/), and
+    /// shouldn't be used otherwise. Characters at or above `threshold` are 2 columns wide, even
+    /// if they're zero-width (like combining marks) or narrow. Characters below it are measured
+    /// as by [`width`](UnicodeWidthStr::width).
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// assert_eq!("a\u{E9}\u{2192}".width_with_wide_threshold('\u{1100}'), 4);
+    /// ```
+    #[inline]
+    fn width_with_wide_threshold(&self, threshold: char) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        self.as_ref()
+            .chars()
+            .map(|c| if c >= threshold { 2 } else { cw::width(c, false).unwrap_or(0) })
+            .sum()
+    }
+
+    /// Returns the smallest and largest displayed width the string can have in columns, for
+    /// when it's unknown whether it will be displayed in a CJK context.
+    ///
+    /// This is equivalent to `(self.width(), self.width_cjk())`, computed in a single pass: the
+    /// two only differ on characters in the Ambiguous category, so each character is only looked
+    /// up once.
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// assert_eq!("abc".width_range(), (3, 3));
+    /// assert_eq!("\u{3B1}\u{3B2}c".width_range(), (3, 5));
+    /// ```
+    #[inline]
+    fn width_range(&self) -> (usize, usize)
+    where
+        Self: AsRef<str>,
+    {
+        let start = (0, 0, WidthState::new());
+        let (min, max, _) = self.as_ref().chars().fold(start, |(min, max, state), c| {
+            if matches!(c, '\u{FE0E}' | '\u{FE0F}') || is_regional_indicator(c) {
+                (state.add_width(min, c, false), state.add_width(max, c, true), state.after(c))
+            } else {
+                let (narrow, wide) = cw::width_pair(c).unwrap_or((0, 0));
+                (min + narrow, max + wide, state.after(c))
+            }
+        });
+        (min, max)
+    }
+
+    /// Returns the number of cursor stops in the string, for editors which move the cursor one
+    /// character at a time regardless of its displayed width.
+    ///
+    /// Every character is a cursor stop, except for combining marks (general category `Mn`,
+    /// `Mc`, or `Me`), which the cursor skips over along with the character they're attached
+    /// to. So this is at most the number of `char`s, and a double-width character is a single
+    /// stop even though it's 2 columns wide. It's independent of the displayed width: the
+    /// cursor's column after `n` stops has to be measured separately. To treat each grapheme
+    /// cluster (such as an emoji ZWJ sequence) as a single stop, use `cursor_stops_graphemes`
+    /// (with the `segmentation` feature) instead.
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// assert_eq!("日本語".cursor_stops(), 3);
+    /// assert_eq!("e\u{301}".cursor_stops(), 1);
+    /// ```
+    #[inline]
+    fn cursor_stops(&self) -> usize
+    where
+        Self: AsRef<str>,
+    {
+        self.as_ref().chars().filter(|&c| !cw::is_mark(c)).count()
+    }
+
+    /// Returns an iterator over the extended grapheme clusters of the string, as determined by
+    /// [UAX #29](https://www.unicode.org/reports/tr29/), yielding the byte range and width in
+    /// columns of each one.
+    ///
+    /// Clusters are measured by [`cluster_width`] with Ambiguous characters treated as 1 column
+    /// wide, so an emoji sequence is a single 2-column cell. Zero-width clusters are still
+    /// yielded, with a width of 0. The ranges cover the whole string, which makes this suitable
+    /// for cursor movement and hit-testing as well as rendering.
+    ///
+    /// ```rust
+    /// use unicode_width::UnicodeWidthStr;
+    ///
+    /// let cells: Vec<_> = "a\u{FF22}e\u{301}".grapheme_cells().collect();
+    /// assert_eq!(cells, [(0..1, 1), (1..4, 2), (4..7, 1)]);
+    /// ```
+    #[cfg(feature = "segmentation")]
+    #[inline]
+    fn grapheme_cells(&self) -> GraphemeCells<'_>
+    where
+        Self: AsRef<str>,
+    {
+        GraphemeCells::new(self.as_ref())
+    }
 }
 
 impl UnicodeWidthStr for str {
     #[inline]
-    fn width(&self) -> usize {
-        self.chars().map(|c| cw::width(c, false).unwrap_or(0)).fold(0, Add::add)
+    fn width(&self) -> usize { str_width(self, false) }
+
+    #[inline]
+    fn width_cjk(&self) -> usize { str_width(self, true) }
+}
+
+/// Returns the displayed width in columns of the characters yielded by `chars`, for text which
+/// isn't stored as a contiguous `str`, such as a rope or a gap buffer.
+///
+/// Characters are measured as by [`UnicodeWidthStr::width`], including the variation selectors
+/// which adjust the width of the emoji before them and the regional indicators which pair up
+/// into flags. Control characters are treated as having
+/// zero width. If `is_cjk == true`, characters in the Ambiguous category are treated as 2
+/// columns wide; otherwise, they're treated as 1 column wide.
+///
+/// ```rust
+/// use unicode_width::{width_of_chars, UnicodeWidthStr};
+///
+/// let gap_buffer = (['Ｈ', 'ｅ'], ['ｌ', 'ｌ', 'ｏ']);
+/// let chars = gap_buffer.0.iter().chain(&gap_buffer.1).cloned();
+/// assert_eq!(width_of_chars(chars, false), "Ｈｅｌｌｏ".width());
+/// ```
+#[inline]
+pub fn width_of_chars<I: IntoIterator<Item = char>>(chars: I, is_cjk: bool) -> usize {
+    let (total, _) = chars.into_iter().fold((0, WidthState::new()), |(total, state), c| {
+        (state.add_width(total, c, is_cjk), state.after(c))
+    });
+    total
+}
+
+/// Adds the widths of the characters in `s` to `start` one at a time with `add`, which returns
+/// `None` on overflow, for [`UnicodeWidthStr::checked_width`] and
+/// [`UnicodeWidthStr::saturating_width`].
+fn accumulate_width(
+    s: &str,
+    start: usize,
+    add: fn(usize, usize) -> Option<usize>,
+) -> Option<usize> {
+    CharWidthIndices::new(s, false).try_fold(start, |total, (_, _, w)| add(total, w))
+}
+
+/// Sums the widths of the characters in `s`, returning early for empty and single-char strings
+/// so that measuring many tiny strings (like table cells) skips the iterator setup. Variation
+/// selectors adjust the width of the emoji before them, and regional indicators pair up into
+/// flags, as described by [`UnicodeWidthStr::width`].
+#[inline]
+fn str_width(s: &str, is_cjk: bool) -> usize {
+    match s.len() {
+        0 => return 0,
+        // A one-byte string is a single ASCII character: only controls are zero-width
+        1 => return is_printable_ascii(s.as_bytes()[0]) as usize,
+        // Up to four bytes may be a single non-ASCII character, which has nothing to combine with
+        2..=4 => {
+            let mut chars = s.chars();
+            if let (Some(c), None) = (chars.next(), chars.next()) {
+                return WidthState::new().add_width(0, c, is_cjk);
+            }
+        }
+        _ => {}
+    }
+    let bytes = s.as_bytes();
+    let (ascii_len, ascii_width) = ascii_prefix_width(bytes);
+    if ascii_len == s.len() {
+        return ascii_width;
+    }
+    // The last ASCII character is measured with the rest of the string, since it could be an
+    // emoji (like the `#` of a keycap) which a variation selector after it widens
+    let split = ascii_len.saturating_sub(1);
+    let last_width = (ascii_len > 0 && is_printable_ascii(bytes[split])) as usize;
+    ascii_width - last_width + chars_width(&s[split..], is_cjk)
+}
+
+/// Returns `true` if `b` is printable ASCII (U+0020..=U+007E), which is 1 column wide. The rest
+/// of ASCII is control characters, which are zero-width.
+#[inline]
+fn is_printable_ascii(b: u8) -> bool {
+    matches!(b, 0x20..=0x7E)
+}
+
+/// Returns the length in bytes of the ASCII prefix of `bytes` and its width in columns.
+///
+/// The prefix is scanned 16 bytes at a time, counting printable bytes without decoding any
+/// UTF-8, so that predominantly-ASCII text like logs doesn't pay for a table lookup per
+/// character. Only the chunk containing the first non-ASCII byte is scanned byte by byte.
+#[inline]
+fn ascii_prefix_width(bytes: &[u8]) -> (usize, usize) {
+    const CHUNK: usize = 16;
+    let (mut len, mut width) = (0, 0);
+    for chunk in bytes.chunks_exact(CHUNK) {
+        if chunk.iter().fold(0, |acc, &b| acc | b) >= 0x80 {
+            break;
+        }
+        width += chunk.iter().filter(|&&b| is_printable_ascii(b)).count();
+        len += CHUNK;
+    }
+    for &b in &bytes[len..] {
+        if b >= 0x80 {
+            break;
+        }
+        width += is_printable_ascii(b) as usize;
+        len += 1;
+    }
+    (len, width)
+}
+
+/// Sums the widths of the characters in `s` one `char` at a time, without [`str_width`]'s
+/// shortcuts for ASCII.
+#[inline]
+fn chars_width(s: &str, is_cjk: bool) -> usize {
+    // Most strings have no VS15, VS16 or regional indicators, so they're summed without
+    // tracking the previous character, and only measured again if one turns up
+    let mut has_sequence = false;
+    let total = s
+        .chars()
+        .map(|c| {
+            has_sequence |= matches!(c, '\u{FE0E}' | '\u{FE0F}' | '\u{1F1E6}'..='\u{1F1FF}');
+            cw::width(c, is_cjk).unwrap_or(0)
+        })
+        .sum();
+    if has_sequence {
+        width_with_sequences(s, is_cjk)
+    } else {
+        total
+    }
+}
+
+/// Sums the widths of the characters in `s` like [`str_width`], adjusting for variation
+/// selectors and regional indicators.
+#[cold]
+fn width_with_sequences(s: &str, is_cjk: bool) -> usize {
+    width_of_chars(s.chars(), is_cjk)
+}
+
+/// What the width of a character depends on in the characters before it, for measuring a
+/// string one character at a time like [`str_width`].
+#[derive(Clone, Copy, Debug, Default)]
+struct WidthState {
+    /// The previous character, which a variation selector may widen or narrow. `None` at the
+    /// start of a string.
+    prev: Option<char>,
+    /// Whether the previous character is a regional indicator which starts a flag, so that the
+    /// next regional indicator completes it.
+    flag_open: bool,
+}
+
+impl WidthState {
+    /// Returns the state at the start of a string.
+    #[inline]
+    const fn new() -> WidthState {
+        WidthState {
+            prev: None,
+            flag_open: false,
+        }
+    }
+
+    /// Returns the state after `c`, which follows the characters this state describes.
+    #[inline]
+    fn after(self, c: char) -> WidthState {
+        WidthState {
+            prev: Some(c),
+            flag_open: is_regional_indicator(c) && !self.flag_open,
+        }
+    }
+
+    /// Adds the width of `c`, which follows the characters this state describes, to the running
+    /// width `total`.
+    ///
+    /// A variation selector adjusts the width of the emoji before it. The first regional
+    /// indicator of a flag counts for the whole flag's 2 columns and the second is zero-width,
+    /// so an unpaired regional indicator is 2 columns wide too.
+    #[inline]
+    fn add_width(self, total: usize, c: char, is_cjk: bool) -> usize {
+        match c {
+            '\u{FE0F}' => total + vs16_widens(self.prev, is_cjk) as usize,
+            '\u{FE0E}' => total - vs15_narrows(self.prev) as usize,
+            _ if is_regional_indicator(c) => total + self.flag_width(),
+            _ => total + cw::width(c, is_cjk).unwrap_or(0),
+        }
     }
 
+    /// Returns the width of `c` when it's followed by `next`, which is the width a variation
+    /// selector gives it if `next` is one. Attributing the adjustment to `c` rather than to the
+    /// selector means the widths of a string's characters still add up to [`str_width`].
     #[inline]
-    fn width_cjk(&self) -> usize {
-        self.chars().map(|c| cw::width(c, true).unwrap_or(0)).fold(0, Add::add)
+    fn width_before(self, c: char, next: Option<char>, is_cjk: bool) -> usize {
+        match next {
+            _ if is_regional_indicator(c) => self.flag_width(),
+            Some('\u{FE0F}') if vs16_widens(Some(c), is_cjk) => 2,
+            Some('\u{FE0E}') if vs15_narrows(Some(c)) => 1,
+            _ => cw::width(c, is_cjk).unwrap_or(0),
+        }
     }
+
+    /// Returns the width of a regional indicator which follows the characters this state
+    /// describes: 2 if it starts a flag, or 0 if it completes one.
+    #[inline]
+    fn flag_width(self) -> usize {
+        if self.flag_open {
+            0
+        } else {
+            2
+        }
+    }
+}
+
+/// Returns `true` if `c` is one of the regional indicator symbols, U+1F1E6..U+1F1FF, which pair
+/// up into flags.
+#[inline]
+fn is_regional_indicator(c: char) -> bool {
+    matches!(c, '\u{1F1E6}'..='\u{1F1FF}')
+}
+
+/// Returns `true` if U+FE0F VARIATION SELECTOR-16 widens `prev` from 1 column to 2, because
+/// `prev` is a narrow emoji and VS16 requests emoji presentation. Regional indicators are already
+/// measured as flags, so VS16 doesn't widen them.
+#[inline]
+fn vs16_widens(prev: Option<char>, is_cjk: bool) -> bool {
+    prev.is_some_and(|c| {
+        cw::width(c, is_cjk) == Some(1) && cw::is_emoji(c) && !is_regional_indicator(c)
+    })
+}
+
+/// Returns `true` if U+FE0E VARIATION SELECTOR-15 narrows `prev` from 2 columns to 1, because
+/// `prev` is a wide emoji and VS15 requests text presentation. Ambiguous emoji, which are only
+/// wide in CJK contexts, stay wide.
+#[inline]
+fn vs15_narrows(prev: Option<char>) -> bool {
+    prev.is_some_and(|c| cw::width(c, false) == Some(2) && cw::is_emoji(c))
+}
+
+/// Sums the widths of the characters in `s`, counting each run of ASCII whitespace as a single
+/// column, for [`UnicodeWidthStr::collapsed_width`].
+fn collapsed_width(s: &str, is_cjk: bool) -> usize {
+    let (mut total, mut in_run, mut state) = (0, false, WidthState::new());
+    for c in s.chars() {
+        if matches!(c, ' ' | '\t' | '\n' | '\x0C' | '\r') {
+            total += !in_run as usize;
+            in_run = true;
+        } else {
+            total = state.add_width(total, c, is_cjk);
+            in_run = false;
+        }
+        state = state.after(c);
+    }
+    total
+}
+
+/// Returns the width of the widest `'\n'`-separated line of `s`, in a single pass.
+fn max_line_width(s: &str, is_cjk: bool) -> usize {
+    let (mut max, mut line, mut state) = (0, 0, WidthState::new());
+    for c in s.chars() {
+        if c == '\n' {
+            // A line's width is only final at its end, since VS15 can narrow the last character
+            max = max.max(line);
+            line = 0;
+        } else {
+            line = state.add_width(line, c, is_cjk);
+        }
+        state = state.after(c);
+    }
+    max.max(line)
+}
+
+/// Sums the widths of the characters in `s`, measuring each run of emoji joined by ZWJs (and
+/// skin tone modifiers) as its widest emoji, for [`UnicodeWidthStr::width_clusters`].
+fn zwj_sequence_width(s: &str, is_cjk: bool) -> usize {
+    let mut total = 0;
+    // The width of the widest emoji in the sequence being measured, if the last character with
+    // a nonzero width was an emoji
+    let mut sequence: Option<usize> = None;
+    // Whether a ZWJ has joined the sequence to whatever comes next
+    let mut joined = false;
+    for (_, c, width) in CharWidthIndices::new(s, is_cjk) {
+        if c == '\u{200D}' {
+            joined = sequence.is_some();
+            continue;
+        }
+        if width == 0 {
+            // Variation selectors and other zero-width characters don't interrupt a sequence
+            continue;
+        }
+        let extends = joined || matches!(c, '\u{1F3FB}'..='\u{1F3FF}');
+        match sequence {
+            Some(widest) if extends && cw::is_emoji(c) => sequence = Some(widest.max(width)),
+            _ => {
+                total += sequence.take().unwrap_or(0);
+                if cw::is_emoji(c) {
+                    sequence = Some(width);
+                } else {
+                    total += width;
+                }
+            }
+        }
+        joined = false;
+    }
+    total + sequence.unwrap_or(0)
+}
+
+/// Returns `true` if `c` ends a line for [`UnicodeWidthStr::first_line_width`].
+#[inline]
+fn is_line_terminator(c: char) -> bool {
+    matches!(c, '\n' | '\u{2028}' | '\u{2029}')
+}
+
+/// Returns the first line of `s`, without its terminator, for
+/// [`UnicodeWidthStr::first_line_width`].
+#[inline]
+fn first_line(s: &str) -> &str {
+    s.find(is_line_terminator).map_or(s, |end| &s[..end])
+}
+
+/// Returns the byte index of the first character in `s` which would end past `column`, or
+/// `None` if `s` fits in `column` columns.
+///
+/// An emoji followed by a variation selector is measured with the width the selector gives it,
+/// as in [`str_width`], so the two are never separated. Likewise, a flag's width is counted at
+/// its first regional indicator.
+#[inline]
+fn exceeding_index(s: &str, column: usize, is_cjk: bool) -> Option<usize> {
+    let (mut used, mut state) = (0, WidthState::new());
+    let mut chars = s.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        used += state.width_before(c, chars.peek().map(|&(_, next)| next), is_cjk);
+        if used > column {
+            return Some(i);
+        }
+        state = state.after(c);
+    }
+    None
+}
+
+/// Splits `s` into lines at most `max` columns wide, for [`UnicodeWidthStr::wrap_to_width`].
+#[cfg(feature = "alloc")]
+fn wrap_to_width(s: &str, max: usize, is_cjk: bool) -> alloc::vec::Vec<&str> {
+    let mut lines = LineWrapper {
+        s,
+        max,
+        lines: alloc::vec::Vec::new(),
+        start: 0,
+        used: 0,
+        brk: None,
+        soft_hyphen: None,
+    };
+    // The run of characters joined by ZWJs being measured, which can't be broken, as its byte
+    // offset and width
+    let (mut unit_start, mut unit_width) = (0, 0);
+    let mut joined = false;
+    for (i, c, width) in CharWidthIndices::new(s, is_cjk) {
+        if c == '\n' {
+            lines.place(unit_start, unit_width);
+            lines.end_line(i + 1);
+            unit_width = 0;
+            joined = false;
+        } else if c == '\u{AD}' || c == '\u{200B}' {
+            lines.place(unit_start, unit_width);
+            lines.break_opportunity(i, c);
+            unit_width = 0;
+            joined = false;
+        } else if c == '\u{200D}' {
+            joined = true;
+        } else if width > 0 {
+            if joined && unit_width > 0 {
+                unit_width += width;
+            } else {
+                lines.place(unit_start, unit_width);
+                unit_start = i;
+                unit_width = width;
+            }
+            joined = false;
+        }
+    }
+    lines.place(unit_start, unit_width);
+    if lines.start < s.len() {
+        lines.end_line(s.len());
+    }
+    lines.lines
+}
+
+/// The lines of a string being hard-wrapped by [`wrap_to_width`].
+#[cfg(feature = "alloc")]
+struct LineWrapper<'a> {
+    s: &'a str,
+    max: usize,
+    lines: alloc::vec::Vec<&'a str>,
+    /// The byte offset at which the current line starts.
+    start: usize,
+    /// The width of the current line so far.
+    used: usize,
+    /// The last soft hyphen or zero width space on the current line which the line could be
+    /// broken after, as the byte offset just past it and the width of the line after it so far.
+    brk: Option<(usize, usize)>,
+    /// The byte offset of a soft hyphen with nothing visible after it on the current line, whose
+    /// hyphen wouldn't fit if the line were broken after it.
+    soft_hyphen: Option<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> LineWrapper<'a> {
+    /// Adds the `width` columns starting at byte offset `at` to the current line, first ending
+    /// the line if they don't fit on it: at its last break opportunity if it has one, or else
+    /// just before `at`.
+    fn place(&mut self, at: usize, width: usize) {
+        if width == 0 {
+            return;
+        }
+        if let Some((end, after)) = self.brk.filter(|_| self.used + width > self.max) {
+            let soft_hyphen = self.soft_hyphen;
+            self.end_line(end);
+            self.used = after;
+            self.soft_hyphen = soft_hyphen;
+        }
+        if self.used > 0 && self.used + width > self.max {
+            // A soft hyphen right before the break would be drawn, so it moves to the next line
+            self.end_line(self.soft_hyphen.unwrap_or(at));
+        }
+        self.used += width;
+        if let Some((_, after)) = &mut self.brk {
+            *after += width;
+        }
+        self.soft_hyphen = None;
+    }
+
+    /// Records that the line may be broken after `c` at byte offset `at`, which is a soft
+    /// hyphen or a zero width space. Breaking after a soft hyphen draws it as a 1-column hyphen,
+    /// so it's only a break opportunity if the hyphen fits; otherwise, it's invisible.
+    fn break_opportunity(&mut self, at: usize, c: char) {
+        let drawn = (c == '\u{AD}') as usize;
+        if self.used == 0 {
+            // Breaking here would leave the line empty
+        } else if self.used + drawn <= self.max {
+            self.brk = Some((at + c.len_utf8(), 0));
+        } else {
+            self.soft_hyphen = Some(at);
+        }
+    }
+
+    /// Ends the current line just before byte offset `end`.
+    fn end_line(&mut self, end: usize) {
+        self.lines.push(&self.s[self.start..end]);
+        self.start = end;
+        self.used = 0;
+        self.brk = None;
+        self.soft_hyphen = None;
+    }
+}
+
+#[cfg(feature = "alloc")]
+macro_rules! impl_width_str_for_smart_pointers {
+    ($($ty:ty),*) => {$(
+        impl UnicodeWidthStr for $ty {
+            #[inline]
+            fn width(&self) -> usize { (**self).width() }
+
+            #[inline]
+            fn width_cjk(&self) -> usize { (**self).width_cjk() }
+        }
+    )*};
+}
+
+#[cfg(feature = "alloc")]
+impl_width_str_for_smart_pointers!(
+    alloc::boxed::Box<str>,
+    alloc::rc::Rc<str>,
+    alloc::sync::Arc<str>
+);
+
+/// Returns the displayed width of `c` in columns following the conventions of POSIX
+/// `wcwidth()`: `-1` if `c` is a control character other than `'\x00'`, and otherwise the width
+/// returned by [`UnicodeWidthChar::width`].
+///
+/// This is intended for callers porting code that uses `wcwidth()`, such as the Python `wcwidth`
+/// package. It doesn't replicate all of their quirks: format characters (like U+FEFF ZERO WIDTH
+/// NO-BREAK SPACE) are always zero-width here. See `conformance/wcwidth.txt` for details.
+#[inline]
+pub fn width_wcwidth(c: char) -> isize {
+    cw::width(c, false).map_or(-1, |w| w as isize)
+}
+
+/// Returns the displayed width in columns of the codepoint `cp`, or `None` if it isn't a
+/// Unicode scalar value (it's a surrogate or above U+10FFFF) or is a control character other
+/// than U+0000.
+///
+/// This is meant for codepoints decoded from a wire format, which haven't been validated as a
+/// `char` yet. If `is_cjk == true`, characters in the Ambiguous category are treated as 2
+/// columns wide; otherwise, they're treated as 1 column wide. Valid codepoints are measured as
+/// by [`UnicodeWidthChar::width`] and [`UnicodeWidthChar::width_cjk`].
+///
+/// ```rust
+/// use unicode_width::width_of_scalar;
+///
+/// assert_eq!(width_of_scalar(0x41, false), Some(1));
+/// assert_eq!(width_of_scalar(0x3042, false), Some(2));
+/// assert_eq!(width_of_scalar(0xD800, false), None);
+/// assert_eq!(width_of_scalar(0x110000, false), None);
+/// ```
+#[inline]
+pub fn width_of_scalar(cp: u32, is_cjk: bool) -> Option<usize> {
+    core::char::from_u32(cp).and_then(|c| cw::width(c, is_cjk))
+}
+
+/// Returns the East Asian Width category of `c`, like [`UnicodeWidthChar::east_asian_width`].
+///
+/// Unlike the width functions, this keeps Ambiguous characters distinct from narrow and wide
+/// ones, for callers like terminals which negotiate how Ambiguous characters are displayed.
+///
+/// ```rust
+/// use unicode_width::{east_asian_width, EastAsianWidth};
+///
+/// assert_eq!(east_asian_width('\u{FF21}'), EastAsianWidth::FullWidth);
+/// assert_eq!(east_asian_width('\u{3042}'), EastAsianWidth::Wide);
+/// assert_eq!(east_asian_width('\u{D7}'), EastAsianWidth::Ambiguous);
+/// ```
+#[inline]
+pub fn east_asian_width(c: char) -> EastAsianWidth {
+    cw::east_asian_width(c)
+}
+
+/// Returns the displayed width in columns of `c`, or `None` if it's a control character other
+/// than `'\0'`, like [`UnicodeWidthChar::width`], but usable in `const` contexts.
+///
+/// This is for computing widths at compile time, such as when building static layout tables.
+/// Trait methods can't be `const fn`, so prefer [`UnicodeWidthChar::width`] elsewhere.
+///
+/// ```rust
+/// use unicode_width::const_width;
+///
+/// const FULLWIDTH_A: Option<usize> = const_width('\u{FF21}');
+/// assert_eq!(FULLWIDTH_A, Some(2));
+/// ```
+#[inline]
+pub const fn const_width(c: char) -> Option<usize> {
+    cw::width(c, false)
+}
+
+/// Returns the width in columns that the width tables give `c`, without the special-casing of
+/// control characters that [`UnicodeWidthChar::width`] applies.
+///
+/// This is a lower-level entry point for building a presentation-aware width layer with its own
+/// control policy on top of this crate's tables. Control characters are zero-width in the
+/// tables, and every other character has the same width as from `width` (or `width_cjk`, if
+/// `is_cjk == true`).
+///
+/// ```rust
+/// use unicode_width::table_width;
+///
+/// assert_eq!(table_width('\u{FF21}', false), 2);
+/// assert_eq!(table_width('\u{B1}', true), 2);
+/// assert_eq!(table_width('\x1B', false), 0);
+/// ```
+#[inline]
+pub const fn table_width(c: char, is_cjk: bool) -> usize {
+    cw::table_width(c, is_cjk)
+}
+
+/// Returns the displayed width of the C string `s` in columns, as by [`UnicodeWidthStr::width`].
+///
+/// `s` is measured up to (and excluding) its nul terminator, and is interpreted as UTF-8. Invalid
+/// sequences are measured as if replaced by U+FFFD REPLACEMENT CHARACTER, like
+/// [`String::from_utf8_lossy`](https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf8_lossy)
+/// would, but without allocating.
+///
+/// Requires Rust 1.64 or later, which added `core::ffi::CStr`.
+///
+/// ```rust
+/// use std::ffi::CStr;
+/// use unicode_width::cstr_width;
+///
+/// let s = CStr::from_bytes_with_nul(b"caf\xC3\xA9 \xE6\x97\xA5\0").unwrap();
+/// assert_eq!(cstr_width(s), 7);
+/// // The truncated UTF-8 sequence is one replacement character
+/// let s = CStr::from_bytes_with_nul(b"ab\xE6\x97\0").unwrap();
+/// assert_eq!(cstr_width(s), 3);
+/// ```
+#[cfg(feature = "capi")]
+pub fn cstr_width(s: &core::ffi::CStr) -> usize {
+    let mut bytes = s.to_bytes();
+    let mut chars = "".chars();
+    let mut replacement = false;
+    // Decode lossily into a single stream, so that the measurement is exactly that of the
+    // replaced string
+    let lossy = core::iter::from_fn(move || loop {
+        if let Some(c) = chars.next() {
+            return Some(c);
+        }
+        if replacement {
+            replacement = false;
+            return Some('\u{FFFD}');
+        }
+        if bytes.is_empty() {
+            return None;
+        }
+        let (valid, rest) = match core::str::from_utf8(bytes) {
+            Ok(valid) => (valid, &[][..]),
+            Err(e) => {
+                let (valid, rest) = bytes.split_at(e.valid_up_to());
+                let invalid = e.error_len().unwrap_or(rest.len());
+                replacement = true;
+                (core::str::from_utf8(valid).unwrap_or(""), &rest[invalid..])
+            }
+        };
+        chars = valid.chars();
+        bytes = rest;
+    });
+    width_of_chars(lossy, false)
+}
+
+/// Writes the displayed width of each character in `chars` into the corresponding slot of `out`.
+///
+/// Control characters are treated as having zero width. If `is_cjk == true`, characters in the
+/// Ambiguous category are treated as 2 columns wide; otherwise, they're treated as 1 column wide.
+///
+/// This doesn't allocate, so `out` can be reused as a scratch buffer across calls.
+///
+/// # Panics
+///
+/// Panics if `chars` and `out` have different lengths.
+pub fn fill_widths(chars: &[char], out: &mut [u8], is_cjk: bool) {
+    assert_eq!(chars.len(), out.len(), "`chars` and `out` must have the same length");
+    for (&c, slot) in chars.iter().zip(out.iter_mut()) {
+        *slot = cw::width(c, is_cjk).unwrap_or(0) as u8;
+    }
+}
+
+/// Returns the displayed width of the characters at indices `range` of `chars`, without
+/// collecting them into a string.
+///
+/// Control characters are treated as having zero width. If `is_cjk == true`, characters in the
+/// Ambiguous category are treated as 2 columns wide; otherwise, they're treated as 1 column wide.
+///
+/// # Panics
+///
+/// Panics if `range.start > range.end`, or if `range.end > chars.len()`.
+///
+/// ```rust
+/// use unicode_width::char_range_width;
+///
+/// let line: Vec<char> = "a日本b".chars().collect();
+/// assert_eq!(char_range_width(&line, 1..3, false), 4);
+/// ```
+pub fn char_range_width(chars: &[char], range: Range<usize>, is_cjk: bool) -> usize {
+    chars[range]
+        .iter()
+        .map(|&c| cw::width(c, is_cjk).unwrap_or(0))
+        .sum()
+}
+
+/// Returns the displayed width `s` would have if the bytes at `range` were replaced by
+/// `replacement`, without building the resulting string.
+///
+/// This is the width of the text before `range`, plus the width of `replacement`, plus the width
+/// of the text after `range`, each measured as by [`UnicodeWidthStr::width`]. It's meant for
+/// previewing an edit, like a find-and-replace, before committing to it.
+///
+/// # Panics
+///
+/// Panics if `range.start > range.end`, if `range.end > s.len()`, or if either end of `range`
+/// isn't on a `char` boundary of `s`.
+///
+/// ```rust
+/// use unicode_width::width_with_replacement;
+///
+/// assert_eq!(width_with_replacement("hello world", 6..11, "世界"), 10);
+/// ```
+pub fn width_with_replacement(s: &str, range: Range<usize>, replacement: &str) -> usize {
+    assert!(
+        s.is_char_boundary(range.start) && s.is_char_boundary(range.end),
+        "`range` must start and end on char boundaries of `s`"
+    );
+    s[..range.start].width() + replacement.width() + s[range.end..].width()
+}
+
+/// Writes the displayed width of each character in `s` into `buf`, in order, and returns the
+/// number of widths written.
+///
+/// Control characters are treated as having zero width, and characters in the Ambiguous
+/// category are treated as 1 column wide.
+///
+/// This doesn't allocate. If `s` has more than `N` characters, only the widths of the first
+/// `N` characters are written (and `N` is returned); the rest of the string is ignored. If `s`
+/// has fewer than `N` characters, the remainder of `buf` is left untouched.
+pub fn widths_into<const N: usize>(s: &str, buf: &mut [u8; N]) -> usize {
+    let mut written = 0;
+    for (c, slot) in s.chars().zip(buf.iter_mut()) {
+        *slot = cw::width(c, false).unwrap_or(0) as u8;
+        written += 1;
+    }
+    written
+}
+
+/// Returns `Some(w)` if every character in `range` is `w` columns wide, or `None` if the range
+/// is empty, contains characters of differing widths, or contains a control character other
+/// than `'\x00'`.
+///
+/// Widths are determined as by [`UnicodeWidthChar::width`], so characters in the Ambiguous
+/// category are treated as 1 column wide. If text is known to lie entirely within a range with
+/// a constant width, its width can be computed by multiplying that width by its character count.
+///
+/// This scans every character in `range`, so it's best called once and cached.
+pub fn range_constant_width(range: RangeInclusive<char>) -> Option<u8> {
+    let mut chars = range.map(|c| cw::width(c, false));
+    let first = chars.next()??;
+    if chars.all(|w| w == Some(first)) {
+        Some(first as u8)
+    } else {
+        None
+    }
+}
+
+/// Compares `a` and `b` by their displayed width in columns, as computed by
+/// [`UnicodeWidthStr::width`].
+///
+/// Each comparison measures both strings, so it's O(n) in their combined length. When sorting
+/// many strings, consider `sort_by_cached_key` with [`width_key`] instead.
+#[inline]
+pub fn cmp_by_width(a: &str, b: &str) -> Ordering {
+    a.width().cmp(&b.width())
+}
+
+/// Compares `a` and `b` by their displayed width in columns, as computed by
+/// [`UnicodeWidthStr::width_cjk`].
+///
+/// Each comparison measures both strings, so it's O(n) in their combined length.
+#[inline]
+pub fn cmp_by_width_cjk(a: &str, b: &str) -> Ordering {
+    a.width_cjk().cmp(&b.width_cjk())
+}
+
+/// Returns the displayed width of `s` in columns, for use as a key with `sort_by_key` and
+/// similar functions. This is equivalent to [`UnicodeWidthStr::width`].
+#[inline]
+pub fn width_key(s: &str) -> usize {
+    s.width()
+}
+
+/// Advances the running column `state` past `s`, by adding the displayed width of `s` to it.
+///
+/// This is meant for rendering loops which append cells to a row one at a time, so that the
+/// row's width doesn't have to be measured again after each cell. Widths are computed as by
+/// [`UnicodeWidthStr::width`]. See [`push_cell_padded`] to align cells to fixed columns.
+///
+/// ```rust
+/// use unicode_width::push_cell;
+///
+/// let mut column = 0;
+/// push_cell(&mut column, "名前");
+/// push_cell(&mut column, ": ");
+/// assert_eq!(column, 6);
+/// ```
+#[inline]
+pub fn push_cell(state: &mut usize, s: &str) {
+    *state += s.width();
+}
+
+/// Advances the running column `state` past `s` and then up to the column `target`, returning
+/// the number of columns of padding needed after `s` to get there.
+///
+/// If `s` ends at or past `target`, no padding is needed and `state` is just advanced past `s`,
+/// as by [`push_cell`]. Otherwise `state` ends up at `target`. Widths are computed as by
+/// [`UnicodeWidthStr::width`].
+///
+/// ```rust
+/// use unicode_width::push_cell_padded;
+///
+/// let mut row = String::new();
+/// let mut column = 0;
+/// for &(cell, target) in &[("名前", 6), ("age", 10)] {
+///     row.push_str(cell);
+///     let padding = push_cell_padded(&mut column, cell, target);
+///     row.push_str(&" ".repeat(padding));
+/// }
+/// assert_eq!(row, "名前  age ");
+/// assert_eq!(column, 10);
+/// ```
+pub fn push_cell_padded(state: &mut usize, s: &str, target: usize) -> usize {
+    push_cell(state, s);
+    let padding = target.saturating_sub(*state);
+    *state += padding;
+    padding
+}
+
+/// Returns `true` if `s` fits in `cols` columns, that is, if its displayed width is at most
+/// `cols`. Widths are computed as by [`UnicodeWidthStr::width`].
+///
+/// A string which exactly fills the line fits, but leaves no room for a cursor after it; use
+/// [`fits_with_cursor`] when the cursor needs a column of its own.
+#[inline]
+pub fn fits(s: &str, cols: usize) -> bool {
+    s.width() <= cols
+}
+
+/// Returns `true` if `s` fits in `cols` columns with a column to spare for the cursor after it,
+/// that is, if its displayed width is strictly less than `cols`. Widths are computed as by
+/// [`UnicodeWidthStr::width`].
+///
+/// ```rust
+/// use unicode_width::{fits, fits_with_cursor};
+///
+/// assert!(fits("日本", 4));
+/// assert!(!fits_with_cursor("日本", 4));
+/// assert!(fits_with_cursor("日本", 5));
+/// ```
+#[inline]
+pub fn fits_with_cursor(s: &str, cols: usize) -> bool {
+    s.width() < cols
+}
+
+/// Returns the number of cells `s` occupies in a grid `cols` columns wide, if it's wrapped onto
+/// as many rows as needed. This is the number of rows times `cols`, so a partially filled last
+/// row counts in full.
+///
+/// A double-width character which doesn't fit in the last column of a row is moved to the
+/// start of the next row, leaving that column empty. A character wider than the whole grid
+/// gets a row to itself. Zero-width characters (including control characters, such as `'\n'`)
+/// don't advance, so an empty or invisible string occupies no cells.
+///
+/// Rows never break across U+200D ZERO WIDTH JOINER, so that emoji ZWJ sequences stay intact:
+/// characters joined by ZWJs are placed as a unit, which moves to the next row if it doesn't
+/// fit, and overflows its row if it's wider than the whole grid.
+///
+/// # Panics
+///
+/// Panics if `cols` is zero.
+///
+/// ```rust
+/// use unicode_width::grid_cells;
+///
+/// assert_eq!(grid_cells("abc", 4), 4);
+/// // "aＢ" fills the first row, and "ｃ" doesn't fit in the second column
+/// assert_eq!(grid_cells("aＢｃ", 3), 6);
+/// ```
+pub fn grid_cells(s: &str, cols: usize) -> usize {
+    assert!(cols > 0, "grid must have at least one column");
+    wrapped_rows(s, cols).0 * cols
+}
+
+/// Returns the number of cells left empty at the end of rows when `s` is wrapped at `cols`
+/// columns, because the character after them didn't fit.
+///
+/// `s` is wrapped following the rules documented on [`grid_cells`], so the empty cells come from
+/// double-width characters (and sequences joined by U+200D ZERO WIDTH JOINER) which are moved to
+/// the next row instead of being split at the margin. This is meant for layout diagnostics, like
+/// picking a column count which wastes less space. Cells after the last character of `s` aren't
+/// counted.
+///
+/// # Panics
+///
+/// Panics if `cols` is zero.
+///
+/// ```rust
+/// use unicode_width::wrap_waste;
+///
+/// // Each row of 3 columns holds one wide character, leaving one cell empty
+/// assert_eq!(wrap_waste("日本語", 3), 2);
+/// assert_eq!(wrap_waste("日本語", 4), 0);
+/// ```
+pub fn wrap_waste(s: &str, cols: usize) -> usize {
+    assert!(cols > 0, "grid must have at least one column");
+    wrapped_rows(s, cols).1
+}
+
+/// Returns the number of rows `s` occupies when wrapped at `cols` columns, following the rules
+/// documented on [`grid_cells`], along with the number of cells left empty at the ends of rows
+/// which something didn't fit in. There are zero rows if `s` has no visible characters.
+fn wrapped_rows(s: &str, cols: usize) -> (usize, usize) {
+    let mut rows = 0;
+    let mut used = 0;
+    let mut waste = 0;
+    let mut place = |width: usize| {
+        if width == 0 {
+            return;
+        }
+        if rows == 0 || (used > 0 && used + width > cols) {
+            if rows > 0 {
+                waste += cols.saturating_sub(used);
+            }
+            rows += 1;
+            used = 0;
+        }
+        used += width;
+    };
+    // The width of the current run of characters joined by ZWJs, which can't be broken
+    let mut unit = 0;
+    let mut joined = false;
+    for (_, c, width) in CharWidthIndices::new(s, false) {
+        if c == '\u{200D}' {
+            joined = true;
+            continue;
+        }
+        if width == 0 {
+            continue;
+        }
+        if joined && unit > 0 {
+            unit += width;
+        } else {
+            place(unit);
+            unit = width;
+        }
+        joined = false;
+    }
+    place(unit);
+    (rows, waste)
+}
+
+/// Returns the number of terminal rows `s` occupies when each of its lines is wrapped at `cols`
+/// columns.
+///
+/// `s` is split into logical lines at each `'\n'` (as by [`str::split`], so a trailing newline
+/// starts an empty last line), and each logical line takes at least one row, even if it's empty.
+/// Lines wrap as in [`grid_cells`]: a double-width character which doesn't fit in the last
+/// column of a row is moved to the start of the next row, leaving that column empty, a
+/// character wider than `cols` gets a row to itself, and characters joined by U+200D ZERO WIDTH
+/// JOINER are never split across rows.
+///
+/// # Panics
+///
+/// Panics if `cols` is zero.
+///
+/// ```rust
+/// use unicode_width::wrapped_line_count;
+///
+/// assert_eq!(wrapped_line_count("hello\nworld", 3), 4);
+/// assert_eq!(wrapped_line_count("\n", 3), 2);
+/// ```
+pub fn wrapped_line_count(s: &str, cols: usize) -> usize {
+    assert!(cols > 0, "lines must have at least one column");
+    s.split('\n')
+        .map(|line| cmp::max(wrapped_rows(line, cols).0, 1))
+        .sum()
+}
+
+/// Returns the displayed width of `line`, one line of a paragraph which was wrapped at its
+/// break opportunities (as by `UnicodeWidthStr::wrap_to_width`), as it's drawn.
+///
+/// U+00AD SOFT HYPHEN and U+200B ZERO WIDTH SPACE both mark places where a line may be broken,
+/// but they're drawn differently when it is: breaking at a soft hyphen shows a hyphen at the end
+/// of the line, while breaking at a zero width space shows nothing. So a soft hyphen at the very
+/// end of `line` is 1 column wide, while soft hyphens anywhere else, which weren't used as break
+/// points, are invisible. Zero width spaces are always zero-width. Other characters are measured
+/// as by [`UnicodeWidthStr::width`], which always counts soft hyphens as 1 column wide since it
+/// doesn't know where lines are broken.
+///
+/// ```rust
+/// use unicode_width::wrapped_line_width;
+///
+/// // "hyphen-ation" broken after "hyphen"
+/// assert_eq!(wrapped_line_width("hyphen\u{AD}"), 7);
+/// assert_eq!(wrapped_line_width("ation"), 5);
+/// // "hyphenation" on a single line
+/// assert_eq!(wrapped_line_width("hyphen\u{AD}ation"), 11);
+/// // A break at a zero width space adds nothing
+/// assert_eq!(wrapped_line_width("word\u{200B}"), 4);
+/// ```
+pub fn wrapped_line_width(line: &str) -> usize {
+    let (body, hyphen) = match line.strip_suffix('\u{AD}') {
+        Some(body) => (body, 1),
+        None => (line, 0),
+    };
+    let soft_hyphens = body.matches('\u{AD}').count();
+    body.width() - soft_hyphens + hyphen
+}
+
+/// Returns the byte offset of the first character in `s` whose inclusion pushes its displayed
+/// width above `budget` columns, or `None` if all of `s` fits in `budget` columns.
+///
+/// Widths are computed as by [`UnicodeWidthStr::width`], and measuring stops at the first
+/// overflow. The offset is always on a char boundary, so `&s[..offset]` is the longest prefix
+/// of `s` which fits.
+///
+/// ```rust
+/// use unicode_width::offset_exceeding_width;
+///
+/// assert_eq!(offset_exceeding_width("ab\u{FF23}d", 3), Some(2));
+/// assert_eq!(offset_exceeding_width("ab\u{FF23}d", 5), None);
+/// ```
+#[inline]
+pub fn offset_exceeding_width(s: &str, budget: usize) -> Option<usize> {
+    exceeding_index(s, budget, false)
+}
+
+/// Returns the advance of `s` along the line in vertical CJK layout, in cells.
+///
+/// In vertical text, each cell is square and holds one character regardless of its East Asian
+/// Width, so this measures the height of `s` in a vertical-mode terminal rather than its width.
+/// Every character advances by 1, except zero-width characters (such as control and format
+/// characters) and combining marks (including spacing marks), which don't advance.
+///
+/// ```rust
+/// use unicode_width::{vertical_advance, UnicodeWidthStr};
+///
+/// assert_eq!(vertical_advance("縦書き"), 3);
+/// assert_eq!("縦書き".width(), 6);
+/// ```
+pub fn vertical_advance(s: &str) -> usize {
+    s.chars()
+        .filter(|&c| !cw::is_mark(c) && cw::width(c, false).unwrap_or(0) > 0)
+        .count()
+}
+
+/// Returns the displayed width of `s` in columns, with every `Pattern_White_Space` character
+/// other than U+0020 SPACE treated as zero-width.
+///
+/// `Pattern_White_Space` is the stable set of characters that syntaxes like programming
+/// languages treat as whitespace. Besides the space, it contains the ASCII tab and line-break
+/// controls, U+0085 NEXT LINE, U+200E LEFT-TO-RIGHT MARK, U+200F RIGHT-TO-LEFT MARK, U+2028 LINE
+/// SEPARATOR, and U+2029 PARAGRAPH SEPARATOR. These are usually rendered as line breaks or not
+/// at all, but [`UnicodeWidthStr::width`] makes U+2028 and U+2029 1 column wide. The space keeps
+/// its width, so that indentation is still measured. All other characters are measured as by
+/// [`UnicodeWidthStr::width`].
+///
+/// ```rust
+/// use unicode_width::width_pattern_ws_zero;
+///
+/// assert_eq!(width_pattern_ws_zero("a b\u{2028}c"), 4);
+/// ```
+pub fn width_pattern_ws_zero(s: &str) -> usize {
+    CharWidthIndices::new(s, false)
+        .map(|(_, c, width)| {
+            if c != ' ' && cw::is_pattern_white_space(c) {
+                0
+            } else {
+                width
+            }
+        })
+        .sum()
+}
+
+/// Truncates `s` to at most `max` columns, marking the truncation with `ellipsis`.
+///
+/// If `s` fits in `max` columns, it's returned unchanged. Otherwise, this returns the longest
+/// prefix of `s` which fits in `max` columns alongside `ellipsis`, followed by `ellipsis`. Both
+/// are measured as by [`UnicodeWidthStr::width`], so `"…"` takes 1 column (or 2 with the
+/// `cjk-punctuation-wide` feature) and `"..."` takes 3.
+///
+/// If `ellipsis` is itself wider than `max`, none of `s` fits, and this returns the longest
+/// prefix of `ellipsis` which fits instead (which may be empty).
+///
+/// ```rust
+/// use unicode_width::ellipsize;
+///
+/// assert_eq!(ellipsize("日本語", 6, "…"), "日本語");
+/// assert_eq!(ellipsize("日本語", 4, "…"), "日…");
+/// assert_eq!(ellipsize("日本語", 2, "..."), "..");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn ellipsize(s: &str, max: usize, ellipsis: &str) -> alloc::string::String {
+    truncate_with_marker(s, max, ellipsis).into_owned()
+}
+
+/// Truncates `s` to at most `max` columns like [`ellipsize`], appending `marker` if (and only
+/// if) anything was cut off, but without allocating when `s` already fits.
+///
+/// If `s` is at most `max` columns wide, it's returned as is, as a [`Cow::Borrowed`]. Otherwise
+/// the result is a [`Cow::Owned`] holding the longest prefix of `s` which fits in `max` columns
+/// along with `marker`, or just the prefix of `marker` which fits if `marker` itself is wider
+/// than `max`.
+///
+/// [`Cow::Borrowed`]: alloc::borrow::Cow::Borrowed
+/// [`Cow::Owned`]: alloc::borrow::Cow::Owned
+///
+/// ```rust
+/// use std::borrow::Cow;
+/// use unicode_width::truncate_with_marker;
+///
+/// assert_eq!(truncate_with_marker("日本語", 6, "…"), Cow::Borrowed("日本語"));
+/// assert_eq!(truncate_with_marker("日本語", 5, " >"), Cow::<str>::Owned("日 >".into()));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn truncate_with_marker<'a>(
+    s: &'a str,
+    max: usize,
+    marker: &str,
+) -> alloc::borrow::Cow<'a, str> {
+    use alloc::borrow::{Cow, ToOwned};
+
+    if exceeding_index(s, max, false).is_none() {
+        return Cow::Borrowed(s);
+    }
+    let reserved = marker.width();
+    if reserved > max {
+        return Cow::Owned(marker.split_at_width(max).0.to_owned());
+    }
+    let (prefix, _) = s.split_at_width(max - reserved);
+    let mut result = alloc::string::String::with_capacity(prefix.len() + marker.len());
+    result.push_str(prefix);
+    result.push_str(marker);
+    Cow::Owned(result)
+}
+
+/// Joins as many leading `segments` as fit in `max` columns, separated by `sep`.
+///
+/// Returns the joined string along with the number of trailing segments which were dropped
+/// because they didn't fit. Segments are never truncated: as soon as one segment (plus the
+/// separator before it) would overflow, it and every segment after it are dropped. If even the
+/// first segment doesn't fit, the result is empty. Widths are measured as by
+/// [`UnicodeWidthStr::width`].
+///
+/// ```rust
+/// use unicode_width::join_within_width;
+///
+/// let segments = ["main", "utf-8", "12:00"];
+/// assert_eq!(join_within_width(&segments, " | ", 20), ("main | utf-8 | 12:00".to_string(), 0));
+/// assert_eq!(join_within_width(&segments, " | ", 12), ("main | utf-8".to_string(), 1));
+/// assert_eq!(join_within_width(&segments, " | ", 3), ("".to_string(), 3));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn join_within_width(
+    segments: &[&str],
+    sep: &str,
+    max: usize,
+) -> (alloc::string::String, usize) {
+    let sep_width = sep.width();
+    let mut result = alloc::string::String::new();
+    let mut used = 0;
+    for (i, segment) in segments.iter().enumerate() {
+        let needed = if i == 0 { 0 } else { sep_width } + segment.width();
+        if used + needed > max {
+            return (result, segments.len() - i);
+        }
+        if i > 0 {
+            result.push_str(sep);
+        }
+        result.push_str(segment);
+        used += needed;
+    }
+    (result, 0)
+}
+
+/// Returns the number of characters in `s` in each East Asian Width category.
+///
+/// The counts are indexed by `category as usize`, in the order the [`EastAsianWidth`] variants
+/// are declared: `Neutral`, `Narrow`, `Wide`, `FullWidth`, `HalfWidth`, then `Ambiguous`.
+///
+/// ```rust
+/// use unicode_width::{eaw_histogram, EastAsianWidth};
+///
+/// let counts = eaw_histogram("ab日本");
+/// assert_eq!(counts[EastAsianWidth::Narrow as usize], 2);
+/// assert_eq!(counts[EastAsianWidth::Wide as usize], 2);
+/// ```
+pub fn eaw_histogram(s: &str) -> [usize; 6] {
+    let mut counts = [0; 6];
+    for c in s.chars() {
+        counts[cw::east_asian_width(c) as usize] += 1;
+    }
+    counts
+}
+
+/// Compares two versions of a line, returning the column at which they first differ and how
+/// much wider `new` is than `old`, in columns.
+///
+/// This is meant for incremental redraws: everything before the returned column is unchanged,
+/// so only the rest of the line needs to be rendered again. The strings are compared character
+/// by character, and the shared prefix is only measured once. Widths are computed as by
+/// [`UnicodeWidthStr::width`], so a shared emoji whose width changes because a variation
+/// selector was added or removed after it counts as the first difference. If the first
+/// difference is a combining mark, the column is just past the character it combines with, so
+/// callers that redraw whole grapheme clusters should step back to the start of the cluster.
+///
+/// ```rust
+/// use unicode_width::redraw_info;
+///
+/// assert_eq!(redraw_info("ls -l", "ls -la"), (5, 1));
+/// assert_eq!(redraw_info("日本語", "日本"), (4, -2));
+/// assert_eq!(redraw_info("same", "same"), (4, 0));
+/// ```
+pub fn redraw_info(old: &str, new: &str) -> (usize, isize) {
+    let mut column = 0;
+    let mut old_chars = CharWidthIndices::new(old, false);
+    let mut new_chars = CharWidthIndices::new(new, false);
+    loop {
+        match (old_chars.next(), new_chars.next()) {
+            (Some((_, o, width)), Some((_, n, new_width))) if o == n && width == new_width => {
+                column += width
+            }
+            (old_first, new_first) => {
+                // The tails are summed from the iterators rather than measured afresh, since
+                // their first characters may complete a flag started in the shared prefix
+                let tail_width = |first: Option<(usize, char, usize)>, rest: CharWidthIndices| {
+                    first.map_or(0, |(_, _, w)| w) + rest.map(|(_, _, w)| w).sum::<usize>()
+                };
+                let new_width = tail_width(new_first, new_chars) as isize;
+                return (column, new_width - tail_width(old_first, old_chars) as isize);
+            }
+        }
+    }
+}
+
+/// Returns the width of `s` if every character in it is exactly 1 column wide, or the byte
+/// offset of the first character which isn't, along with that character.
+///
+/// This is meant for fixed-width formats which assume one column per character. The disallowed
+/// characters are the control characters, including `'\0'`, and every character whose width in
+/// `s` (as measured by [`UnicodeWidthStr::char_width_indices`]) isn't 1: zero-width characters
+/// such as combining marks, variation selectors and U+200B ZERO WIDTH SPACE, and double-width
+/// characters, including narrow emoji which U+FE0F VARIATION SELECTOR-16 widens and regional
+/// indicators. Ambiguous characters are treated as 1 column wide, so they're allowed. On
+/// success, the width is the number of characters in `s`.
+///
+/// ```rust
+/// use unicode_width::strict_width;
+///
+/// assert_eq!(strict_width("ABC-123"), Ok(7));
+/// assert_eq!(strict_width("ID: 日本"), Err((4, '日')));
+/// assert_eq!(strict_width("a\tb"), Err((1, '\t')));
+/// ```
+pub fn strict_width(s: &str) -> Result<usize, (usize, char)> {
+    let mut width = 0;
+    for (i, c, char_width) in CharWidthIndices::new(s, false) {
+        if char_width != 1 {
+            return Err((i, c));
+        }
+        width += 1;
+    }
+    Ok(width)
+}
+
+/// A source of text which can be measured with [`measure_width`] without being contiguous, such
+/// as a rope or a list of chunks.
+///
+/// Implementors only need to yield their characters in order. Because the width is computed
+/// from one continuous stream of characters, a combining mark, a variation selector or the second
+/// half of a flag at the start of a chunk is handled the same way as if the text weren't split.
+///
+/// Like `IntoIterator`, the trait takes `self` by value, so it's usually implemented for a
+/// reference to the text, which lets [`Chars`](WidthSource::Chars) borrow from it.
+pub trait WidthSource {
+    /// The iterator over the characters of the text.
+    type Chars: Iterator<Item = char>;
+
+    /// Returns an iterator over the characters of the text, in order.
+    fn chars(self) -> Self::Chars;
+}
+
+impl<'a> WidthSource for &'a str {
+    type Chars = core::str::Chars<'a>;
+
+    #[inline]
+    fn chars(self) -> Self::Chars { str::chars(self) }
+}
+
+/// Returns the displayed width of `src` in columns.
+///
+/// Control characters are treated as having zero width. If `is_cjk == true`, characters in the
+/// Ambiguous category are treated as 2 columns wide; otherwise, they're treated as 1 column wide.
+///
+/// ```rust
+/// use std::iter::Chain;
+/// use std::str::Chars;
+/// use unicode_width::{measure_width, WidthSource};
+///
+/// struct GapBuffer<'a> {
+///     before: &'a str,
+///     after: &'a str,
+/// }
+///
+/// impl<'a> WidthSource for &GapBuffer<'a> {
+///     type Chars = Chain<Chars<'a>, Chars<'a>>;
+///
+///     fn chars(self) -> Self::Chars {
+///         self.before.chars().chain(self.after.chars())
+///     }
+/// }
+///
+/// assert_eq!(measure_width(&GapBuffer { before: "日本", after: "語" }, false), 6);
+/// ```
+pub fn measure_width<T: WidthSource>(src: T, is_cjk: bool) -> usize {
+    width_of_chars(src.chars(), is_cjk)
+}
+
+/// Returns the displayed width of `s` in columns, along with whether it contains any strong
+/// right-to-left characters, in a single pass.
+///
+/// A character is strong right-to-left if its bidi class is `R` (as for Hebrew letters) or `AL`
+/// (as for Arabic letters). Bidi-aware renderers only need to reorder text which contains such
+/// a character. Weak and neutral characters, like Arabic-Indic digits and combining marks, don't
+/// count, and neither do unassigned codepoints. Widths are computed as by
+/// [`UnicodeWidthStr::width`].
+///
+/// ```rust
+/// use unicode_width::width_and_rtl;
+///
+/// assert_eq!(width_and_rtl("hello"), (5, false));
+/// assert_eq!(width_and_rtl("\u{5E9}\u{5DC}\u{5D5}\u{5DD}"), (4, true));
+/// ```
+pub fn width_and_rtl(s: &str) -> (usize, bool) {
+    CharWidthIndices::new(s, false).fold((0, false), |(width, rtl), (_, c, char_width)| {
+        (width + char_width, rtl || cw::is_strong_rtl(c))
+    })
+}
+
+/// Returns the displayed width of `s` in columns, broken down by the script of each character.
+///
+/// Each character's width is attributed to its script, as listed in `Scripts.txt`. Characters
+/// shared between scripts, like spaces and digits, are attributed to [`Script::Common`], and
+/// combining marks are usually attributed to [`Script::Inherited`] rather than to the script of
+/// the character they combine with. An emoji's width, as adjusted by a variation selector after
+/// it, is attributed to the emoji's script. Scripts which don't occur in `s` are left out, so the
+/// widths always sum to [`UnicodeWidthStr::width`].
+///
+/// ```rust
+/// use unicode_width::{width_by_script, Script};
+///
+/// let widths = width_by_script("Rust 日本語");
+/// assert_eq!(widths[&Script::Latin], 4);
+/// assert_eq!(widths[&Script::Common], 1);
+/// assert_eq!(widths[&Script::Han], 6);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn width_by_script(s: &str) -> alloc::collections::BTreeMap<Script, usize> {
+    let mut widths = alloc::collections::BTreeMap::new();
+    for (_, c, width) in CharWidthIndices::new(s, false) {
+        *widths.entry(cw::script(c)).or_insert(0) += width;
+    }
+    widths
+}
+
+/// Returns the displayed width of `s` in columns, along with the set of scripts its characters
+/// belong to, in a single pass.
+///
+/// This is meant for routing text to the fonts or shapers which can handle it. Scripts are as
+/// listed in `Scripts.txt`, so the set includes [`Script::Common`] if `s` has spaces, digits or
+/// punctuation, and [`Script::Inherited`] if it has most kinds of combining marks. Widths are
+/// computed as by [`UnicodeWidthStr::width`].
+///
+/// ```rust
+/// use unicode_width::{width_and_scripts, Script};
+///
+/// let (width, scripts) = width_and_scripts("abc\u{627}\u{628}");
+/// assert_eq!(width, 5);
+/// assert!(scripts.contains(Script::Latin) && scripts.contains(Script::Arabic));
+/// assert_eq!(scripts.len(), 2);
+/// ```
+pub fn width_and_scripts(s: &str) -> (usize, ScriptSet) {
+    let mut scripts = ScriptSet::new();
+    let width = CharWidthIndices::new(s, false)
+        .map(|(_, c, width)| {
+            scripts.insert(cw::script(c));
+            width
+        })
+        .sum();
+    (width, scripts)
+}
+
+/// Returns the displayed width of `s` in columns, as drawn by a renderer which stacks at most
+/// `max_marks` combining marks on a single character.
+///
+/// This is a compatibility mode for analyzing how text looks on such renderers, some of which
+/// give up on deep stacks of combining marks (general category `Mn`, `Mc`, or `Me`) and draw
+/// each mark beyond the limit in a cell of its own. Those marks are counted as 1 column wide,
+/// while the first `max_marks` marks after each base character, and all other characters, are
+/// measured as by [`UnicodeWidthStr::width`].
+///
+/// ```rust
+/// use unicode_width::width_with_mark_limit;
+///
+/// let stacked = "a\u{301}\u{302}\u{303}";
+/// assert_eq!(width_with_mark_limit(stacked, 3), 1);
+/// assert_eq!(width_with_mark_limit(stacked, 1), 3);
+/// ```
+pub fn width_with_mark_limit(s: &str, max_marks: usize) -> usize {
+    let mut marks = 0;
+    CharWidthIndices::new(s, false)
+        .map(|(_, c, width)| {
+            if !cw::is_mark(c) {
+                marks = 0;
+            } else if marks < max_marks {
+                marks += 1;
+            } else {
+                return 1;
+            }
+            width
+        })
+        .sum()
+}
+
+/// Returns the displayed width of `s` in columns after composing it to Normalization Form C
+/// (NFC), which replaces each base character and combining marks with a precomposed character
+/// wherever one exists.
+///
+/// Usually this is the same as [`UnicodeWidthStr::width`], since most combining marks are
+/// zero-width and precomposed characters are as wide as their base. They differ when a
+/// precomposed character is narrower than its parts. This happens with the two-part vowel signs
+/// of some Brahmic scripts, such as Bengali, Tamil and Balinese: the decomposed form is two
+/// spacing marks (general category `Mc`) of 1 column each, while the precomposed sign is a
+/// single 1-column mark. Widths are otherwise computed as by [`UnicodeWidthStr::width`]. This
+/// composes `s` on the fly, so it's slower than `width`, but it doesn't allocate.
+///
+/// ```rust
+/// use unicode_width::{composed_width, UnicodeWidthStr};
+///
+/// assert_eq!(composed_width("cafe\u{301}"), 4);
+/// assert_eq!(composed_width("cafe\u{301}"), "caf\u{e9}".width());
+/// // TAMIL LETTER KA, TAMIL VOWEL SIGN E, TAMIL VOWEL SIGN AA, which compose to TAMIL VOWEL
+/// // SIGN O
+/// assert_eq!("\u{B95}\u{BC6}\u{BBE}".width(), 3);
+/// assert_eq!(composed_width("\u{B95}\u{BC6}\u{BBE}"), 2);
+/// ```
+#[cfg(feature = "normalization")]
+pub fn composed_width(s: &str) -> usize {
+    use unicode_normalization::UnicodeNormalization;
+
+    width_of_chars(s.nfc(), false)
 }
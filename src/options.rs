@@ -0,0 +1,540 @@
+// Copyright 2012-2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::ops::RangeInclusive;
+
+use is_regional_indicator;
+use tables::charwidth as cw;
+use UnicodeWidthChar;
+
+/// How characters in the East Asian Width `Ambiguous` category are measured.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AmbiguousPolicy {
+    /// Ambiguous characters are 1 column wide, as in non-CJK contexts.
+    Narrow,
+    /// Ambiguous characters are 2 columns wide, as recommended for CJK contexts.
+    Wide,
+    /// Ambiguous characters in the given ranges are 2 columns wide, and all other ambiguous
+    /// characters are 1 column wide. Characters in the ranges which aren't ambiguous keep
+    /// their usual width.
+    ///
+    /// ```rust
+    /// use unicode_width::{AmbiguousPolicy, UnicodeWidthStr, WidthOptions};
+    ///
+    /// // Wide quotation marks and ellipses, but narrow Greek
+    /// let punctuation = AmbiguousPolicy::Custom(&['\u{2010}'..='\u{2027}']);
+    /// let opts = WidthOptions::new().ambiguous(punctuation);
+    /// assert_eq!("\u{201C}\u{3B1}\u{2026}\u{201D}".width_with(opts), 7);
+    /// ```
+    Custom(&'static [RangeInclusive<char>]),
+}
+
+/// How control characters other than `'\x00'` are measured.
+///
+/// Two [`ControlPolicy::Custom`] policies compare equal if their functions have the same
+/// address, which the compiler doesn't guarantee even for the same function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(unpredictable_function_pointer_comparisons)]
+pub enum ControlPolicy {
+    /// Control characters have no width: `width_with` returns `None` for them, and they
+    /// contribute nothing to the width of a string.
+    Ignore,
+    /// Every control character is the given number of columns wide.
+    Fixed(usize),
+    /// Control characters are drawn in caret notation, as a `'^'` followed by one character, so
+    /// each one is 2 columns wide. This covers the C0 controls (`"\x03"` is drawn as `^C` and
+    /// `"\x1B"` as `^[`), DEL (drawn as `^?`), and the C1 controls U+0080..U+009F, which are
+    /// drawn like the C0 controls they correspond to.
+    ///
+    /// ```rust
+    /// use unicode_width::{ControlPolicy, UnicodeWidthStr, WidthOptions};
+    ///
+    /// let opts = WidthOptions::new().control(ControlPolicy::Caret);
+    /// assert_eq!("\x03abc".width_with(opts), 5);
+    /// assert_eq!("\x7F\u{9B}".width_with(opts), 4);
+    /// ```
+    Caret,
+    /// Each control character is as wide as the given function returns, for other notations.
+    ///
+    /// ```rust
+    /// use unicode_width::{ControlPolicy, UnicodeWidthStr, WidthOptions};
+    ///
+    /// // Escapes like `\x1b` and `\u{85}`
+    /// fn escaped(c: char) -> usize {
+    ///     if c < '\u{80}' { 4 } else { 6 }
+    /// }
+    /// let opts = WidthOptions::new().control(ControlPolicy::Custom(escaped));
+    /// assert_eq!("a\x1B\u{85}".width_with(opts), 11);
+    /// ```
+    Custom(fn(char) -> usize),
+}
+
+/// Configuration for [`UnicodeWidthChar::width_with`](crate::UnicodeWidthChar::width_with) and
+/// [`UnicodeWidthStr::width_with`](crate::UnicodeWidthStr::width_with).
+///
+/// The default options measure width exactly like
+/// [`UnicodeWidthChar::width`](crate::UnicodeWidthChar::width) and
+/// [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+///
+/// ```rust
+/// use unicode_width::{UnicodeWidthStr, WidthOptions};
+///
+/// let opts = WidthOptions::new().ambiguous_wide(true).default_ignorable_width(1);
+/// assert_eq!("\u{a1}\u{200B}".width_with(opts), 3);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WidthOptions {
+    ambiguous: AmbiguousPolicy,
+    control: ControlPolicy,
+    default_ignorable_width: usize,
+    noncharacter_width: Option<usize>,
+    bom_width: usize,
+    force_emoji_wide: bool,
+    max_char_width: usize,
+}
+
+impl Default for WidthOptions {
+    fn default() -> WidthOptions {
+        WidthOptions::new()
+    }
+}
+
+impl WidthOptions {
+    /// Returns the default options.
+    pub const fn new() -> WidthOptions {
+        WidthOptions {
+            ambiguous: AmbiguousPolicy::Narrow,
+            control: ControlPolicy::Ignore,
+            default_ignorable_width: 0,
+            noncharacter_width: Some(1),
+            bom_width: 0,
+            force_emoji_wide: false,
+            max_char_width: usize::MAX,
+        }
+    }
+
+    /// Sets whether characters in the Ambiguous category are treated as 2 columns wide, as
+    /// recommended for CJK contexts, rather than 1 column wide. Defaults to `false`.
+    pub const fn ambiguous_wide(self, ambiguous_wide: bool) -> WidthOptions {
+        self.ambiguous(if ambiguous_wide {
+            AmbiguousPolicy::Wide
+        } else {
+            AmbiguousPolicy::Narrow
+        })
+    }
+
+    /// Sets how characters in the Ambiguous category are measured. Defaults to
+    /// [`AmbiguousPolicy::Narrow`].
+    pub const fn ambiguous(self, ambiguous: AmbiguousPolicy) -> WidthOptions {
+        WidthOptions { ambiguous, ..self }
+    }
+
+    /// Sets how control characters other than `'\x00'` are measured. Defaults to
+    /// [`ControlPolicy::Ignore`].
+    pub const fn control(self, control: ControlPolicy) -> WidthOptions {
+        WidthOptions { control, ..self }
+    }
+
+    /// Sets the width of control characters other than `'\x00'`: `None` ignores them, as with
+    /// [`ControlPolicy::Ignore`], and `Some(width)` makes each one `width` columns wide, as with
+    /// [`ControlPolicy::Fixed`]. Defaults to `None`.
+    ///
+    /// ```rust
+    /// use unicode_width::{UnicodeWidthChar, WidthOptions};
+    ///
+    /// assert_eq!('\x1B'.width_with(WidthOptions::new()), None);
+    /// assert_eq!('\x1B'.width_with(WidthOptions::new().control_width(Some(0))), Some(0));
+    /// ```
+    pub const fn control_width(self, width: Option<usize>) -> WidthOptions {
+        self.control(match width {
+            None => ControlPolicy::Ignore,
+            Some(width) => ControlPolicy::Fixed(width),
+        })
+    }
+
+    /// Sets the width of zero-width characters with the `Default_Ignorable_Code_Point`
+    /// property, such as U+200B ZERO WIDTH SPACE and U+2060 WORD JOINER. Defaults to `0`.
+    ///
+    /// This can be used to make otherwise invisible characters visible. It doesn't affect
+    /// combining marks, control characters, or the `Prepended_Concatenation_Mark` format
+    /// characters (such as U+0600 ARABIC NUMBER SIGN), none of which are default-ignorable.
+    /// Default-ignorable characters which already have a nonzero width, such as U+00AD SOFT
+    /// HYPHEN, keep their width.
+    pub const fn default_ignorable_width(self, width: usize) -> WidthOptions {
+        WidthOptions {
+            default_ignorable_width: width,
+            ..self
+        }
+    }
+
+    /// Sets the width of the 66 noncharacters: U+FDD0..U+FDEF, and the last two codepoints of
+    /// every plane, such as U+FFFE and U+FFFF. Defaults to `Some(1)`, since they're usually
+    /// rendered as a narrow "tofu" box like any other unassigned codepoint.
+    ///
+    /// Noncharacters are reserved for internal use and shouldn't appear in interchanged text.
+    /// Validators can pass `None` to make `width_with` return `None` for them, as it does for
+    /// control characters; they then contribute nothing to the width of a string.
+    pub const fn noncharacter_width(self, width: Option<usize>) -> WidthOptions {
+        WidthOptions {
+            noncharacter_width: width,
+            ..self
+        }
+    }
+
+    /// Sets the width of U+FEFF ZERO WIDTH NO-BREAK SPACE, which is also the byte order mark.
+    /// Defaults to `0`.
+    ///
+    /// A byte order mark at the start of a file is often decoded into the text by accident,
+    /// where it's invisible but can still break alignment. Setting this to `1` makes a stray
+    /// BOM take up a column, so it shows up in the layout. Since U+FEFF is default-ignorable,
+    /// it's as wide as the larger of this and
+    /// [`default_ignorable_width`](WidthOptions::default_ignorable_width).
+    ///
+    /// ```rust
+    /// use unicode_width::{UnicodeWidthStr, WidthOptions};
+    ///
+    /// assert_eq!("\u{FEFF}abc".width_with(WidthOptions::new()), 3);
+    /// assert_eq!("\u{FEFF}abc".width_with(WidthOptions::new().bom_width(1)), 4);
+    /// ```
+    pub const fn bom_width(self, width: usize) -> WidthOptions {
+        WidthOptions {
+            bom_width: width,
+            ..self
+        }
+    }
+
+    /// Sets whether every character with the `Emoji` property is 2 columns wide, even if it
+    /// defaults to text presentation. Defaults to `false`.
+    ///
+    /// Some terminals draw every emoji-capable character as double-width, such as U+2600 BLACK
+    /// SUN WITH RAYS, which [Unicode Standard Annex #11](http://www.unicode.org/reports/tr11/)
+    /// makes 1 column wide unless it's followed by U+FE0F VARIATION SELECTOR-16. This matches
+    /// them, so it diverges from the usual widths on purpose. The ASCII characters with the
+    /// `Emoji` property (the digits, `'#'`, and `'*'`) keep their width, since they only
+    /// render as emoji in keycap sequences.
+    ///
+    /// ```rust
+    /// use unicode_width::{UnicodeWidthStr, WidthOptions};
+    ///
+    /// assert_eq!("\u{2600}".width_with(WidthOptions::new()), 1);
+    /// assert_eq!("\u{2600}".width_with(WidthOptions::new().force_emoji_wide(true)), 2);
+    /// ```
+    pub const fn force_emoji_wide(self, force_emoji_wide: bool) -> WidthOptions {
+        WidthOptions {
+            force_emoji_wide,
+            ..self
+        }
+    }
+
+    /// Sets the most columns any single character can be measured as. Wider characters are
+    /// clamped to this width. Defaults to `usize::MAX`, which doesn't clamp anything.
+    ///
+    /// No character is wider than 2 columns by default, but the other options can make them
+    /// wider: [`ControlPolicy::Fixed`] and
+    /// [`default_ignorable_width`](WidthOptions::default_ignorable_width), for instance. This
+    /// caps the result of every other option, so it can bound them for a renderer which never
+    /// uses more than a fixed number of cells per character.
+    ///
+    /// ```rust
+    /// use unicode_width::{UnicodeWidthStr, WidthOptions};
+    ///
+    /// let opts = WidthOptions::new().force_emoji_wide(true).max_char_width(1);
+    /// assert_eq!("\u{2600}\u{4E00}".width_with(opts), 2);
+    /// ```
+    pub const fn max_char_width(self, max_char_width: usize) -> WidthOptions {
+        WidthOptions {
+            max_char_width,
+            ..self
+        }
+    }
+
+    /// Returns the width of `c` according to these options, or `None` if `c` is a control
+    /// character other than `'\x00'` and controls are ignored.
+    #[inline]
+    pub(crate) fn char_width(&self, c: char) -> Option<usize> {
+        self.unclamped_width(c).map(|width| width.min(self.max_char_width))
+    }
+
+    /// Returns the width of `c` according to every option but `max_char_width`.
+    #[inline]
+    fn unclamped_width(&self, c: char) -> Option<usize> {
+        if c.is_noncharacter() {
+            return self.noncharacter_width;
+        }
+        if self.force_emoji_wide && !c.is_ascii() && cw::is_emoji(c) {
+            return Some(2);
+        }
+        let ambiguous_wide = match self.ambiguous {
+            AmbiguousPolicy::Narrow => false,
+            AmbiguousPolicy::Wide => true,
+            AmbiguousPolicy::Custom(ranges) => ranges.iter().any(|range| range.contains(&c)),
+        };
+        match cw::width(c, ambiguous_wide) {
+            Some(0) if c == '\u{FEFF}' => Some(self.bom_width.max(self.default_ignorable_width)),
+            Some(0) if cw::is_default_ignorable(c) => Some(self.default_ignorable_width),
+            None => match self.control {
+                ControlPolicy::Ignore => None,
+                ControlPolicy::Fixed(width) => Some(width),
+                ControlPolicy::Caret => Some(2),
+                ControlPolicy::Custom(width) => Some(width(c)),
+            },
+            width => width,
+        }
+    }
+}
+
+/// A builder which composes every width option into a single string measurer.
+///
+/// The default calculator measures strings exactly like
+/// [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width). Each builder method returns the
+/// modified calculator, and [`measure`](WidthCalculator::measure) computes the width.
+///
+/// ```rust
+/// use unicode_width::WidthCalculator;
+///
+/// let calc = WidthCalculator::new().cjk(true).tab_width(4);
+/// assert_eq!(calc.measure("\u{a1}\t\u{2600}\u{FE0F}"), 6);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WidthCalculator<'a> {
+    options: WidthOptions,
+    tab_width: usize,
+    emoji_vs16: bool,
+    nel_line_break: bool,
+    overrides: &'a [(RangeInclusive<char>, usize)],
+}
+
+impl<'a> Default for WidthCalculator<'a> {
+    fn default() -> WidthCalculator<'a> {
+        WidthCalculator::new()
+    }
+}
+
+impl<'a> WidthCalculator<'a> {
+    /// Returns a calculator which measures like [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width).
+    pub const fn new() -> WidthCalculator<'a> {
+        WidthCalculator {
+            options: WidthOptions::new(),
+            tab_width: 0,
+            emoji_vs16: true,
+            nel_line_break: false,
+            overrides: &[],
+        }
+    }
+
+    /// Replaces the per-character options wholesale.
+    pub const fn options(self, options: WidthOptions) -> WidthCalculator<'a> {
+        WidthCalculator { options, ..self }
+    }
+
+    /// Sets whether Ambiguous characters are 2 columns wide, as in
+    /// [`UnicodeWidthStr::width_cjk`](crate::UnicodeWidthStr::width_cjk). Defaults to `false`.
+    pub const fn cjk(self, cjk: bool) -> WidthCalculator<'a> {
+        self.options(self.options.ambiguous_wide(cjk))
+    }
+
+    /// Sets how Ambiguous characters are measured. Defaults to [`AmbiguousPolicy::Narrow`].
+    pub const fn ambiguous(self, ambiguous: AmbiguousPolicy) -> WidthCalculator<'a> {
+        self.options(self.options.ambiguous(ambiguous))
+    }
+
+    /// Sets how control characters are measured. Defaults to [`ControlPolicy::Ignore`].
+    pub const fn control(self, control: ControlPolicy) -> WidthCalculator<'a> {
+        self.options(self.options.control(control))
+    }
+
+    /// Sets the width of default-ignorable characters; see
+    /// [`WidthOptions::default_ignorable_width`]. Defaults to `0`.
+    pub const fn default_ignorable_width(self, width: usize) -> WidthCalculator<'a> {
+        self.options(self.options.default_ignorable_width(width))
+    }
+
+    /// Sets the width of noncharacters; see [`WidthOptions::noncharacter_width`]. Defaults to
+    /// `Some(1)`.
+    pub const fn noncharacter_width(self, width: Option<usize>) -> WidthCalculator<'a> {
+        self.options(self.options.noncharacter_width(width))
+    }
+
+    /// Sets the width of U+FEFF, the byte order mark; see [`WidthOptions::bom_width`].
+    /// Defaults to `0`.
+    pub const fn bom_width(self, width: usize) -> WidthCalculator<'a> {
+        self.options(self.options.bom_width(width))
+    }
+
+    /// Sets whether every character with the `Emoji` property is 2 columns wide; see
+    /// [`WidthOptions::force_emoji_wide`]. Defaults to `false`.
+    pub const fn force_emoji_wide(self, force_emoji_wide: bool) -> WidthCalculator<'a> {
+        self.options(self.options.force_emoji_wide(force_emoji_wide))
+    }
+
+    /// Sets the distance between tab stops. A tab advances to the next multiple of
+    /// `tab_width` columns from the start of its line. Defaults to `0`, which measures tabs
+    /// like any other control character.
+    ///
+    /// Both `'\n'` and `'\r'` return the cursor to the start of the line, so a tab after
+    /// either one advances a full `tab_width` columns, even if the `'\r'` is in the middle of
+    /// a line (as in progress bars which redraw themselves).
+    ///
+    /// ```rust
+    /// use unicode_width::WidthCalculator;
+    ///
+    /// assert_eq!(WidthCalculator::new().tab_width(4).measure("ab\r\tc"), 7);
+    /// ```
+    pub const fn tab_width(self, tab_width: usize) -> WidthCalculator<'a> {
+        WidthCalculator { tab_width, ..self }
+    }
+
+    /// Sets the most columns any single character can be measured as; see
+    /// [`WidthOptions::max_char_width`]. Defaults to `usize::MAX`. This doesn't clamp tabs or
+    /// [`overrides`](WidthCalculator::overrides), which are measured as requested.
+    pub const fn max_char_width(self, max_char_width: usize) -> WidthCalculator<'a> {
+        self.options(self.options.max_char_width(max_char_width))
+    }
+
+    /// Sets whether variation selectors change the width of the emoji before them, as in
+    /// [`UnicodeWidthStr::width`](crate::UnicodeWidthStr::width): a narrow character with the
+    /// `Emoji` property is 2 columns wide when it's followed by U+FE0F VARIATION SELECTOR-16,
+    /// which requests emoji presentation, and a wide one is 1 column wide when it's followed by
+    /// U+FE0E VARIATION SELECTOR-15, which requests text presentation. Defaults to `true`.
+    pub const fn emoji_vs16(self, emoji_vs16: bool) -> WidthCalculator<'a> {
+        WidthCalculator { emoji_vs16, ..self }
+    }
+
+    /// Sets whether U+0085 NEXT LINE (NEL) ends a line, as it does in text converted from EBCDIC.
+    /// Defaults to `false`, in which case NEL is an ordinary C1 control character.
+    ///
+    /// When set, NEL splits lines in [`lines_widths`](WidthCalculator::lines_widths), and
+    /// returns the cursor to the start of the line for tab stops like `'\n'` does. It's still
+    /// measured like any other control character.
+    ///
+    /// ```rust
+    /// use unicode_width::WidthCalculator;
+    ///
+    /// let calc = WidthCalculator::new().tab_width(4);
+    /// assert_eq!(calc.measure("ab\u{85}\tc"), 2 + 2 + 1);
+    /// assert_eq!(calc.nel_line_break(true).measure("ab\u{85}\tc"), 2 + 4 + 1);
+    /// ```
+    pub const fn nel_line_break(self, nel_line_break: bool) -> WidthCalculator<'a> {
+        WidthCalculator {
+            nel_line_break,
+            ..self
+        }
+    }
+
+    /// Sets explicit widths for ranges of characters, which take precedence over every other
+    /// option. If ranges overlap, the first one wins. Defaults to no overrides.
+    pub const fn overrides(
+        self,
+        overrides: &'a [(RangeInclusive<char>, usize)],
+    ) -> WidthCalculator<'a> {
+        WidthCalculator { overrides, ..self }
+    }
+
+    /// Returns the width of `s` in columns. For multi-line strings, this is the sum of every
+    /// line's width.
+    pub fn measure(&self, s: &str) -> usize {
+        self.measure_from(s, 0)
+    }
+
+    /// Returns the width of `s` in columns, when it's displayed starting at the 0-based column
+    /// `start_col` rather than at the start of a line.
+    ///
+    /// This only makes a difference to tabs, which advance to the next tab stop counting from
+    /// the start of the line, so a tab before the first line break is narrower when `start_col`
+    /// is partway to a tab stop. Lines after the first start at column 0.
+    ///
+    /// ```rust
+    /// use unicode_width::WidthCalculator;
+    ///
+    /// let calc = WidthCalculator::new().tab_width(4);
+    /// assert_eq!(calc.measure_from("\tx", 0), 5);
+    /// assert_eq!(calc.measure_from("\tx", 3), 2);
+    /// ```
+    pub fn measure_from(&self, s: &str, start_col: usize) -> usize {
+        let mut total = 0;
+        let mut column = start_col;
+        let mut flag_open = false;
+        let mut chars = s.chars().peekable();
+        while let Some(c) = chars.next() {
+            let width = if let Some(&(_, width)) =
+                self.overrides.iter().find(|(range, _)| range.contains(&c))
+            {
+                width
+            } else if c == '\t' && self.tab_width > 0 {
+                self.tab_width - column % self.tab_width
+            } else if is_regional_indicator(c) {
+                // A flag's width is counted at its first regional indicator
+                if flag_open {
+                    0
+                } else {
+                    self.options.max_char_width.min(2)
+                }
+            } else {
+                match self.options.char_width(c).unwrap_or(0) {
+                    1 if self.emoji_vs16
+                        && chars.peek() == Some(&'\u{FE0F}')
+                        && cw::is_emoji(c) =>
+                    {
+                        self.options.max_char_width.min(2)
+                    }
+                    2 if self.emoji_vs16
+                        && chars.peek() == Some(&'\u{FE0E}')
+                        && cw::width(c, false) == Some(2)
+                        && cw::is_emoji(c) =>
+                    {
+                        self.options.max_char_width.min(1)
+                    }
+                    width => width,
+                }
+            };
+            total += width;
+            flag_open = is_regional_indicator(c) && !flag_open;
+            column = if c == '\n' || c == '\r' || (self.nel_line_break && c == '\u{85}') {
+                0
+            } else {
+                column + width
+            };
+        }
+        total
+    }
+
+    /// Returns an iterator over the widths of the lines in `s`, each measured as by
+    /// [`measure`](WidthCalculator::measure).
+    ///
+    /// Lines end at `'\n'`, U+2028 LINE SEPARATOR, U+2029 PARAGRAPH SEPARATOR, and U+0085 NEXT
+    /// LINE if [`nel_line_break`](WidthCalculator::nel_line_break) is set. A `'\r'` at the end of
+    /// a line isn't part of it, so `"\r\n"` ends a line too. As with [`str::lines`], a
+    /// terminator at the end of `s` doesn't start an extra empty line.
+    ///
+    /// ```rust
+    /// use unicode_width::WidthCalculator;
+    ///
+    /// let calc = WidthCalculator::new();
+    /// let widths: Vec<_> = calc.lines_widths("ab\r\nｃｄ\n").collect();
+    /// assert_eq!(widths, [2, 4]);
+    /// let widths: Vec<_> = calc.nel_line_break(true).lines_widths("ab\u{85}ｃｄ").collect();
+    /// assert_eq!(widths, [2, 4]);
+    /// ```
+    pub fn lines_widths<'s>(&self, s: &'s str) -> impl Iterator<Item = usize> + 's
+    where
+        'a: 's,
+    {
+        let calc = *self;
+        s.split_terminator(move |c| calc.is_line_break(c)).map(move |line| {
+            calc.measure(line.strip_suffix('\r').unwrap_or(line))
+        })
+    }
+
+    /// Returns `true` if `c` ends a line, for [`lines_widths`](WidthCalculator::lines_widths).
+    #[inline]
+    fn is_line_break(&self, c: char) -> bool {
+        matches!(c, '\n' | '\u{2028}' | '\u{2029}') || (self.nel_line_break && c == '\u{85}')
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright 2012-2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::io::{self, BufRead};
+use std::string::String;
+use std::vec::Vec;
+
+use UnicodeWidthStr;
+
+/// The displayed widths of each line read by [`measure_reader`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LineWidths {
+    /// The width of each line, in order. Line terminators aren't included in the width.
+    pub widths: Vec<usize>,
+    /// The width of the widest line, or `0` if there were no lines.
+    pub max: usize,
+}
+
+/// Measures the displayed width of each line read from `r`, without reading the whole input
+/// into memory.
+///
+/// Lines are split on `'\n'`, and a trailing `"\r\n"` or `'\n'` isn't counted. A final line
+/// without a terminator is still measured, but a trailing terminator doesn't create an extra
+/// empty line. Invalid UTF-8 is decoded lossily, so each invalid sequence is measured as one
+/// U+FFFD REPLACEMENT CHARACTER.
+///
+/// Widths are measured as by [`UnicodeWidthStr::width`].
+///
+/// ```rust
+/// use std::io::Cursor;
+/// use unicode_width::measure_reader;
+///
+/// let widths = measure_reader(Cursor::new("ab\nｃｄ\n")).unwrap();
+/// assert_eq!(widths.widths, vec![2, 4]);
+/// assert_eq!(widths.max, 4);
+/// ```
+pub fn measure_reader<R: BufRead>(mut r: R) -> io::Result<LineWidths> {
+    let mut result = LineWidths::default();
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+        if r.read_until(b'\n', &mut buf)? == 0 {
+            return Ok(result);
+        }
+        let mut line = &buf[..];
+        if line.ends_with(b"\n") {
+            line = &line[..line.len() - 1];
+            if line.ends_with(b"\r") {
+                line = &line[..line.len() - 1];
+            }
+        }
+        let width = String::from_utf8_lossy(line).width();
+        result.max = result.max.max(width);
+        result.widths.push(width);
+    }
+}
@@ -0,0 +1,185 @@
+// Copyright 2012-2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::iter::Peekable;
+use core::str::CharIndices;
+
+use WidthState;
+
+/// An iterator over the runs of consecutive equal-width characters in a string, returned by
+/// [`UnicodeWidthStr::width_runs`](crate::UnicodeWidthStr::width_runs) and
+/// [`UnicodeWidthStr::width_runs_cjk`](crate::UnicodeWidthStr::width_runs_cjk).
+///
+/// Each item is a `(width, count)` pair: `count` consecutive characters which are each `width`
+/// columns wide. Widths are as given by [`CharWidthIndices`], so control characters are
+/// treated as zero-width, an emoji has the width a variation selector after it gives it, and
+/// a flag is counted at its first regional indicator.
+#[derive(Clone, Debug)]
+pub struct WidthRuns<'a> {
+    chars: Peekable<CharWidthIndices<'a>>,
+}
+
+impl<'a> WidthRuns<'a> {
+    pub(crate) fn new(s: &'a str, is_cjk: bool) -> WidthRuns<'a> {
+        WidthRuns {
+            chars: CharWidthIndices::new(s, is_cjk).peekable(),
+        }
+    }
+}
+
+impl<'a> Iterator for WidthRuns<'a> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        let (_, _, width) = self.chars.next()?;
+        let mut count = 1;
+        while self.chars.next_if(|&(_, _, next)| next == width).is_some() {
+            count += 1;
+        }
+        Some((width, count))
+    }
+}
+
+/// An iterator over the starting columns of the double-width characters in a string, returned
+/// by [`UnicodeWidthStr::wide_char_columns`](crate::UnicodeWidthStr::wide_char_columns) and
+/// [`UnicodeWidthStr::wide_char_columns_cjk`](crate::UnicodeWidthStr::wide_char_columns_cjk).
+///
+/// Columns are 0-based, and widths are as given by [`CharWidthIndices`], so a narrow emoji
+/// which U+FE0F VARIATION SELECTOR-16 widens and a flag (at its first regional indicator) are
+/// double-width.
+#[derive(Clone, Debug)]
+pub struct WideCharColumns<'a> {
+    chars: CharWidthIndices<'a>,
+    column: usize,
+}
+
+impl<'a> WideCharColumns<'a> {
+    pub(crate) fn new(s: &'a str, is_cjk: bool) -> WideCharColumns<'a> {
+        WideCharColumns {
+            chars: CharWidthIndices::new(s, is_cjk),
+            column: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for WideCharColumns<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        for (_, _, width) in &mut self.chars {
+            let start = self.column;
+            self.column += width;
+            if width == 2 {
+                return Some(start);
+            }
+        }
+        None
+    }
+}
+
+/// The contents of one cell of a terminal grid, as yielded by [`CellKinds`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CellKind {
+    /// A single-width character, which fills its column.
+    Narrow(char),
+    /// The left half of a double-width character, which is the column the character is drawn
+    /// from.
+    WideLeft(char),
+    /// The right half of the double-width character in the previous column. A renderer which
+    /// clips the character at this column draws a placeholder instead.
+    WideRight,
+    /// A zero-width character (including a control character), which occupies no column of its
+    /// own.
+    ZeroWidth(char),
+}
+
+/// An iterator over the cells which the characters of a string occupy, returned by
+/// [`UnicodeWidthStr::cell_kinds`](crate::UnicodeWidthStr::cell_kinds) and
+/// [`UnicodeWidthStr::cell_kinds_cjk`](crate::UnicodeWidthStr::cell_kinds_cjk).
+///
+/// Each [`CellKind`] other than [`CellKind::ZeroWidth`] is one column, so the number of
+/// non-`ZeroWidth` items equals the string's width. Widths are as given by
+/// [`CharWidthIndices`]: an emoji occupies the cells a variation selector after it requests,
+/// the selector itself is `ZeroWidth`, and a flag's two cells belong to its first regional
+/// indicator, with the second one `ZeroWidth`.
+#[derive(Clone, Debug)]
+pub struct CellKinds<'a> {
+    chars: CharWidthIndices<'a>,
+    // Whether the right half of a double-width character is due next
+    right_half: bool,
+}
+
+impl<'a> CellKinds<'a> {
+    pub(crate) fn new(s: &'a str, is_cjk: bool) -> CellKinds<'a> {
+        CellKinds {
+            chars: CharWidthIndices::new(s, is_cjk),
+            right_half: false,
+        }
+    }
+}
+
+impl<'a> Iterator for CellKinds<'a> {
+    type Item = CellKind;
+
+    fn next(&mut self) -> Option<CellKind> {
+        if self.right_half {
+            self.right_half = false;
+            return Some(CellKind::WideRight);
+        }
+        let (_, c, width) = self.chars.next()?;
+        Some(match width {
+            0 => CellKind::ZeroWidth(c),
+            1 => CellKind::Narrow(c),
+            _ => {
+                self.right_half = true;
+                CellKind::WideLeft(c)
+            }
+        })
+    }
+}
+
+/// An iterator over the characters of a string with their byte offsets and widths, returned by
+/// [`UnicodeWidthStr::char_width_indices`](crate::UnicodeWidthStr::char_width_indices) and
+/// [`UnicodeWidthStr::char_width_indices_cjk`](crate::UnicodeWidthStr::char_width_indices_cjk).
+///
+/// Each item is an `(offset, char, width)` triple. Control characters are treated as
+/// zero-width.
+#[derive(Clone, Debug)]
+pub struct CharWidthIndices<'a> {
+    chars: Peekable<CharIndices<'a>>,
+    is_cjk: bool,
+    state: WidthState,
+}
+
+impl<'a> CharWidthIndices<'a> {
+    pub(crate) fn new(s: &'a str, is_cjk: bool) -> CharWidthIndices<'a> {
+        CharWidthIndices {
+            chars: s.char_indices().peekable(),
+            is_cjk,
+            state: WidthState::new(),
+        }
+    }
+}
+
+impl<'a> Iterator for CharWidthIndices<'a> {
+    type Item = (usize, char, usize);
+
+    fn next(&mut self) -> Option<(usize, char, usize)> {
+        let (i, c) = self.chars.next()?;
+        let next = self.chars.peek().map(|&(_, next)| next);
+        let width = self.state.width_before(c, next, self.is_cjk);
+        self.state = self.state.after(c);
+        Some((i, c, width))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chars.size_hint()
+    }
+}
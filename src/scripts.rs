@@ -0,0 +1,83 @@
+// Copyright 2012-2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use Script;
+
+/// The number of `u64` words needed for one bit per [`Script`] variant.
+const WORDS: usize = Script::ALL.len().div_ceil(64);
+
+/// A set of [`Script`]s, stored as a bitset with one bit per script, as returned by
+/// [`width_and_scripts`](crate::width_and_scripts).
+///
+/// ```rust
+/// use unicode_width::{Script, ScriptSet};
+///
+/// let mut set = ScriptSet::new();
+/// assert!(set.insert(Script::Latin));
+/// assert!(!set.insert(Script::Latin));
+/// assert!(set.contains(Script::Latin) && !set.contains(Script::Han));
+/// assert_eq!(set.len(), 1);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ScriptSet {
+    bits: [u64; WORDS],
+}
+
+impl ScriptSet {
+    /// Returns an empty set.
+    pub const fn new() -> ScriptSet {
+        ScriptSet { bits: [0; WORDS] }
+    }
+
+    /// Adds `script` to the set, returning `true` if it wasn't already present.
+    #[inline]
+    pub fn insert(&mut self, script: Script) -> bool {
+        let (word, bit) = position(script);
+        let absent = self.bits[word] & bit == 0;
+        self.bits[word] |= bit;
+        absent
+    }
+
+    /// Returns `true` if `script` is in the set.
+    #[inline]
+    pub fn contains(&self, script: Script) -> bool {
+        let (word, bit) = position(script);
+        self.bits[word] & bit != 0
+    }
+
+    /// Returns the number of scripts in the set.
+    pub fn len(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns `true` if the set has no scripts.
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&word| word == 0)
+    }
+
+    /// Returns an iterator over the scripts in the set, in the order the [`Script`] variants are
+    /// declared.
+    pub fn iter(&self) -> impl Iterator<Item = Script> + '_ {
+        Script::ALL
+            .iter()
+            .cloned()
+            .filter(move |&script| self.contains(script))
+    }
+}
+
+/// Returns the index of the word holding `script`'s bit, along with a mask for the bit.
+#[inline]
+fn position(script: Script) -> (usize, u64) {
+    let i = script as usize;
+    (i / 64, 1 << (i % 64))
+}
@@ -0,0 +1,4436 @@
+// Copyright 2012-2022 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// NOTE: The following code was generated by "scripts/unicode.py", do not edit directly
+
+use core::ops::RangeInclusive;
+
+/// The version of [Unicode](http://www.unicode.org/)
+/// that this version of unicode-width is based on.
+pub const UNICODE_VERSION: (u8, u8, u8) = (15, 1, 0);
+
+/// A 64-bit [FNV-1a](http://www.isthe.com/chongo/tech/comp/fnv/) hash of the generated lookup
+/// tables used by this version of unicode-width, computed by `unicode.py`.
+/// Builds generated from the same Unicode data by the same generator have the same hash.
+pub const TABLE_HASH: u64 = 0xF2EF102B84B01E8B;
+
+/// The script of a character, as listed in `Scripts.txt` and reported by `width_by_script` and
+/// `width_and_scripts`. Variants are named after the long names of the scripts, without
+/// underscores.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Script {
+    /// The `Adlam` script.
+    Adlam,
+    /// The `Ahom` script.
+    Ahom,
+    /// The `Anatolian_Hieroglyphs` script.
+    AnatolianHieroglyphs,
+    /// The `Arabic` script.
+    Arabic,
+    /// The `Armenian` script.
+    Armenian,
+    /// The `Avestan` script.
+    Avestan,
+    /// The `Balinese` script.
+    Balinese,
+    /// The `Bamum` script.
+    Bamum,
+    /// The `Bassa_Vah` script.
+    BassaVah,
+    /// The `Batak` script.
+    Batak,
+    /// The `Bengali` script.
+    Bengali,
+    /// The `Bhaiksuki` script.
+    Bhaiksuki,
+    /// The `Bopomofo` script.
+    Bopomofo,
+    /// The `Brahmi` script.
+    Brahmi,
+    /// The `Braille` script.
+    Braille,
+    /// The `Buginese` script.
+    Buginese,
+    /// The `Buhid` script.
+    Buhid,
+    /// The `Canadian_Aboriginal` script.
+    CanadianAboriginal,
+    /// The `Carian` script.
+    Carian,
+    /// The `Caucasian_Albanian` script.
+    CaucasianAlbanian,
+    /// The `Chakma` script.
+    Chakma,
+    /// The `Cham` script.
+    Cham,
+    /// The `Cherokee` script.
+    Cherokee,
+    /// The `Chorasmian` script.
+    Chorasmian,
+    /// Characters which are used by several scripts (`Common`), such as digits.
+    Common,
+    /// The `Coptic` script.
+    Coptic,
+    /// The `Cuneiform` script.
+    Cuneiform,
+    /// The `Cypriot` script.
+    Cypriot,
+    /// The `Cypro_Minoan` script.
+    CyproMinoan,
+    /// The `Cyrillic` script.
+    Cyrillic,
+    /// The `Deseret` script.
+    Deseret,
+    /// The `Devanagari` script.
+    Devanagari,
+    /// The `Dives_Akuru` script.
+    DivesAkuru,
+    /// The `Dogra` script.
+    Dogra,
+    /// The `Duployan` script.
+    Duployan,
+    /// The `Egyptian_Hieroglyphs` script.
+    EgyptianHieroglyphs,
+    /// The `Elbasan` script.
+    Elbasan,
+    /// The `Elymaic` script.
+    Elymaic,
+    /// The `Ethiopic` script.
+    Ethiopic,
+    /// The `Georgian` script.
+    Georgian,
+    /// The `Glagolitic` script.
+    Glagolitic,
+    /// The `Gothic` script.
+    Gothic,
+    /// The `Grantha` script.
+    Grantha,
+    /// The `Greek` script.
+    Greek,
+    /// The `Gujarati` script.
+    Gujarati,
+    /// The `Gunjala_Gondi` script.
+    GunjalaGondi,
+    /// The `Gurmukhi` script.
+    Gurmukhi,
+    /// The `Han` script.
+    Han,
+    /// The `Hangul` script.
+    Hangul,
+    /// The `Hanifi_Rohingya` script.
+    HanifiRohingya,
+    /// The `Hanunoo` script.
+    Hanunoo,
+    /// The `Hatran` script.
+    Hatran,
+    /// The `Hebrew` script.
+    Hebrew,
+    /// The `Hiragana` script.
+    Hiragana,
+    /// The `Imperial_Aramaic` script.
+    ImperialAramaic,
+    /// Characters which take on the script of the previous character (`Inherited`).
+    Inherited,
+    /// The `Inscriptional_Pahlavi` script.
+    InscriptionalPahlavi,
+    /// The `Inscriptional_Parthian` script.
+    InscriptionalParthian,
+    /// The `Javanese` script.
+    Javanese,
+    /// The `Kaithi` script.
+    Kaithi,
+    /// The `Kannada` script.
+    Kannada,
+    /// The `Katakana` script.
+    Katakana,
+    /// The `Kawi` script.
+    Kawi,
+    /// The `Kayah_Li` script.
+    KayahLi,
+    /// The `Kharoshthi` script.
+    Kharoshthi,
+    /// The `Khitan_Small_Script` script.
+    KhitanSmallScript,
+    /// The `Khmer` script.
+    Khmer,
+    /// The `Khojki` script.
+    Khojki,
+    /// The `Khudawadi` script.
+    Khudawadi,
+    /// The `Lao` script.
+    Lao,
+    /// The `Latin` script.
+    Latin,
+    /// The `Lepcha` script.
+    Lepcha,
+    /// The `Limbu` script.
+    Limbu,
+    /// The `Linear_A` script.
+    LinearA,
+    /// The `Linear_B` script.
+    LinearB,
+    /// The `Lisu` script.
+    Lisu,
+    /// The `Lycian` script.
+    Lycian,
+    /// The `Lydian` script.
+    Lydian,
+    /// The `Mahajani` script.
+    Mahajani,
+    /// The `Makasar` script.
+    Makasar,
+    /// The `Malayalam` script.
+    Malayalam,
+    /// The `Mandaic` script.
+    Mandaic,
+    /// The `Manichaean` script.
+    Manichaean,
+    /// The `Marchen` script.
+    Marchen,
+    /// The `Masaram_Gondi` script.
+    MasaramGondi,
+    /// The `Medefaidrin` script.
+    Medefaidrin,
+    /// The `Meetei_Mayek` script.
+    MeeteiMayek,
+    /// The `Mende_Kikakui` script.
+    MendeKikakui,
+    /// The `Meroitic_Cursive` script.
+    MeroiticCursive,
+    /// The `Meroitic_Hieroglyphs` script.
+    MeroiticHieroglyphs,
+    /// The `Miao` script.
+    Miao,
+    /// The `Modi` script.
+    Modi,
+    /// The `Mongolian` script.
+    Mongolian,
+    /// The `Mro` script.
+    Mro,
+    /// The `Multani` script.
+    Multani,
+    /// The `Myanmar` script.
+    Myanmar,
+    /// The `Nabataean` script.
+    Nabataean,
+    /// The `Nag_Mundari` script.
+    NagMundari,
+    /// The `Nandinagari` script.
+    Nandinagari,
+    /// The `New_Tai_Lue` script.
+    NewTaiLue,
+    /// The `Newa` script.
+    Newa,
+    /// The `Nko` script.
+    Nko,
+    /// The `Nushu` script.
+    Nushu,
+    /// The `Nyiakeng_Puachue_Hmong` script.
+    NyiakengPuachueHmong,
+    /// The `Ogham` script.
+    Ogham,
+    /// The `Ol_Chiki` script.
+    OlChiki,
+    /// The `Old_Hungarian` script.
+    OldHungarian,
+    /// The `Old_Italic` script.
+    OldItalic,
+    /// The `Old_North_Arabian` script.
+    OldNorthArabian,
+    /// The `Old_Permic` script.
+    OldPermic,
+    /// The `Old_Persian` script.
+    OldPersian,
+    /// The `Old_Sogdian` script.
+    OldSogdian,
+    /// The `Old_South_Arabian` script.
+    OldSouthArabian,
+    /// The `Old_Turkic` script.
+    OldTurkic,
+    /// The `Old_Uyghur` script.
+    OldUyghur,
+    /// The `Oriya` script.
+    Oriya,
+    /// The `Osage` script.
+    Osage,
+    /// The `Osmanya` script.
+    Osmanya,
+    /// The `Pahawh_Hmong` script.
+    PahawhHmong,
+    /// The `Palmyrene` script.
+    Palmyrene,
+    /// The `Pau_Cin_Hau` script.
+    PauCinHau,
+    /// The `Phags_Pa` script.
+    PhagsPa,
+    /// The `Phoenician` script.
+    Phoenician,
+    /// The `Psalter_Pahlavi` script.
+    PsalterPahlavi,
+    /// The `Rejang` script.
+    Rejang,
+    /// The `Runic` script.
+    Runic,
+    /// The `Samaritan` script.
+    Samaritan,
+    /// The `Saurashtra` script.
+    Saurashtra,
+    /// The `Sharada` script.
+    Sharada,
+    /// The `Shavian` script.
+    Shavian,
+    /// The `Siddham` script.
+    Siddham,
+    /// The `SignWriting` script.
+    SignWriting,
+    /// The `Sinhala` script.
+    Sinhala,
+    /// The `Sogdian` script.
+    Sogdian,
+    /// The `Sora_Sompeng` script.
+    SoraSompeng,
+    /// The `Soyombo` script.
+    Soyombo,
+    /// The `Sundanese` script.
+    Sundanese,
+    /// The `Syloti_Nagri` script.
+    SylotiNagri,
+    /// The `Syriac` script.
+    Syriac,
+    /// The `Tagalog` script.
+    Tagalog,
+    /// The `Tagbanwa` script.
+    Tagbanwa,
+    /// The `Tai_Le` script.
+    TaiLe,
+    /// The `Tai_Tham` script.
+    TaiTham,
+    /// The `Tai_Viet` script.
+    TaiViet,
+    /// The `Takri` script.
+    Takri,
+    /// The `Tamil` script.
+    Tamil,
+    /// The `Tangsa` script.
+    Tangsa,
+    /// The `Tangut` script.
+    Tangut,
+    /// The `Telugu` script.
+    Telugu,
+    /// The `Thaana` script.
+    Thaana,
+    /// The `Thai` script.
+    Thai,
+    /// The `Tibetan` script.
+    Tibetan,
+    /// The `Tifinagh` script.
+    Tifinagh,
+    /// The `Tirhuta` script.
+    Tirhuta,
+    /// The `Toto` script.
+    Toto,
+    /// The `Ugaritic` script.
+    Ugaritic,
+    /// Unassigned, private use and surrogate codepoints (`Unknown`).
+    Unknown,
+    /// The `Vai` script.
+    Vai,
+    /// The `Vithkuqi` script.
+    Vithkuqi,
+    /// The `Wancho` script.
+    Wancho,
+    /// The `Warang_Citi` script.
+    WarangCiti,
+    /// The `Yezidi` script.
+    Yezidi,
+    /// The `Yi` script.
+    Yi,
+    /// The `Zanabazar_Square` script.
+    ZanabazarSquare,
+}
+
+impl Script {
+    /// Every script, in the order the variants are declared.
+    pub const ALL: [Script; 164] = [
+        Script::Adlam,
+        Script::Ahom,
+        Script::AnatolianHieroglyphs,
+        Script::Arabic,
+        Script::Armenian,
+        Script::Avestan,
+        Script::Balinese,
+        Script::Bamum,
+        Script::BassaVah,
+        Script::Batak,
+        Script::Bengali,
+        Script::Bhaiksuki,
+        Script::Bopomofo,
+        Script::Brahmi,
+        Script::Braille,
+        Script::Buginese,
+        Script::Buhid,
+        Script::CanadianAboriginal,
+        Script::Carian,
+        Script::CaucasianAlbanian,
+        Script::Chakma,
+        Script::Cham,
+        Script::Cherokee,
+        Script::Chorasmian,
+        Script::Common,
+        Script::Coptic,
+        Script::Cuneiform,
+        Script::Cypriot,
+        Script::CyproMinoan,
+        Script::Cyrillic,
+        Script::Deseret,
+        Script::Devanagari,
+        Script::DivesAkuru,
+        Script::Dogra,
+        Script::Duployan,
+        Script::EgyptianHieroglyphs,
+        Script::Elbasan,
+        Script::Elymaic,
+        Script::Ethiopic,
+        Script::Georgian,
+        Script::Glagolitic,
+        Script::Gothic,
+        Script::Grantha,
+        Script::Greek,
+        Script::Gujarati,
+        Script::GunjalaGondi,
+        Script::Gurmukhi,
+        Script::Han,
+        Script::Hangul,
+        Script::HanifiRohingya,
+        Script::Hanunoo,
+        Script::Hatran,
+        Script::Hebrew,
+        Script::Hiragana,
+        Script::ImperialAramaic,
+        Script::Inherited,
+        Script::InscriptionalPahlavi,
+        Script::InscriptionalParthian,
+        Script::Javanese,
+        Script::Kaithi,
+        Script::Kannada,
+        Script::Katakana,
+        Script::Kawi,
+        Script::KayahLi,
+        Script::Kharoshthi,
+        Script::KhitanSmallScript,
+        Script::Khmer,
+        Script::Khojki,
+        Script::Khudawadi,
+        Script::Lao,
+        Script::Latin,
+        Script::Lepcha,
+        Script::Limbu,
+        Script::LinearA,
+        Script::LinearB,
+        Script::Lisu,
+        Script::Lycian,
+        Script::Lydian,
+        Script::Mahajani,
+        Script::Makasar,
+        Script::Malayalam,
+        Script::Mandaic,
+        Script::Manichaean,
+        Script::Marchen,
+        Script::MasaramGondi,
+        Script::Medefaidrin,
+        Script::MeeteiMayek,
+        Script::MendeKikakui,
+        Script::MeroiticCursive,
+        Script::MeroiticHieroglyphs,
+        Script::Miao,
+        Script::Modi,
+        Script::Mongolian,
+        Script::Mro,
+        Script::Multani,
+        Script::Myanmar,
+        Script::Nabataean,
+        Script::NagMundari,
+        Script::Nandinagari,
+        Script::NewTaiLue,
+        Script::Newa,
+        Script::Nko,
+        Script::Nushu,
+        Script::NyiakengPuachueHmong,
+        Script::Ogham,
+        Script::OlChiki,
+        Script::OldHungarian,
+        Script::OldItalic,
+        Script::OldNorthArabian,
+        Script::OldPermic,
+        Script::OldPersian,
+        Script::OldSogdian,
+        Script::OldSouthArabian,
+        Script::OldTurkic,
+        Script::OldUyghur,
+        Script::Oriya,
+        Script::Osage,
+        Script::Osmanya,
+        Script::PahawhHmong,
+        Script::Palmyrene,
+        Script::PauCinHau,
+        Script::PhagsPa,
+        Script::Phoenician,
+        Script::PsalterPahlavi,
+        Script::Rejang,
+        Script::Runic,
+        Script::Samaritan,
+        Script::Saurashtra,
+        Script::Sharada,
+        Script::Shavian,
+        Script::Siddham,
+        Script::SignWriting,
+        Script::Sinhala,
+        Script::Sogdian,
+        Script::SoraSompeng,
+        Script::Soyombo,
+        Script::Sundanese,
+        Script::SylotiNagri,
+        Script::Syriac,
+        Script::Tagalog,
+        Script::Tagbanwa,
+        Script::TaiLe,
+        Script::TaiTham,
+        Script::TaiViet,
+        Script::Takri,
+        Script::Tamil,
+        Script::Tangsa,
+        Script::Tangut,
+        Script::Telugu,
+        Script::Thaana,
+        Script::Thai,
+        Script::Tibetan,
+        Script::Tifinagh,
+        Script::Tirhuta,
+        Script::Toto,
+        Script::Ugaritic,
+        Script::Unknown,
+        Script::Vai,
+        Script::Vithkuqi,
+        Script::Wancho,
+        Script::WarangCiti,
+        Script::Yezidi,
+        Script::Yi,
+        Script::ZanabazarSquare,
+    ];
+}
+
+/// Autogenerated. The ranges of characters which are 1 column wide, except in CJK contexts
+/// (such as [`width_cjk`](crate::UnicodeWidthChar::width_cjk)), where they're 2 columns wide.
+/// These are the Ambiguous characters which aren't zero-width.
+pub const AMBIGUOUS_RANGES: &[RangeInclusive<char>] = &[
+    '\u{a1}'..='\u{a1}',
+    '\u{a4}'..='\u{a4}',
+    '\u{a7}'..='\u{a8}',
+    '\u{aa}'..='\u{aa}',
+    '\u{ae}'..='\u{ae}',
+    '\u{b0}'..='\u{b4}',
+    '\u{b6}'..='\u{ba}',
+    '\u{bc}'..='\u{bf}',
+    '\u{c6}'..='\u{c6}',
+    '\u{d0}'..='\u{d0}',
+    '\u{d7}'..='\u{d8}',
+    '\u{de}'..='\u{e1}',
+    '\u{e6}'..='\u{e6}',
+    '\u{e8}'..='\u{ea}',
+    '\u{ec}'..='\u{ed}',
+    '\u{f0}'..='\u{f0}',
+    '\u{f2}'..='\u{f3}',
+    '\u{f7}'..='\u{fa}',
+    '\u{fc}'..='\u{fc}',
+    '\u{fe}'..='\u{fe}',
+    '\u{101}'..='\u{101}',
+    '\u{111}'..='\u{111}',
+    '\u{113}'..='\u{113}',
+    '\u{11b}'..='\u{11b}',
+    '\u{126}'..='\u{127}',
+    '\u{12b}'..='\u{12b}',
+    '\u{131}'..='\u{133}',
+    '\u{138}'..='\u{138}',
+    '\u{13f}'..='\u{142}',
+    '\u{144}'..='\u{144}',
+    '\u{148}'..='\u{14b}',
+    '\u{14d}'..='\u{14d}',
+    '\u{152}'..='\u{153}',
+    '\u{166}'..='\u{167}',
+    '\u{16b}'..='\u{16b}',
+    '\u{1ce}'..='\u{1ce}',
+    '\u{1d0}'..='\u{1d0}',
+    '\u{1d2}'..='\u{1d2}',
+    '\u{1d4}'..='\u{1d4}',
+    '\u{1d6}'..='\u{1d6}',
+    '\u{1d8}'..='\u{1d8}',
+    '\u{1da}'..='\u{1da}',
+    '\u{1dc}'..='\u{1dc}',
+    '\u{251}'..='\u{251}',
+    '\u{261}'..='\u{261}',
+    '\u{2c4}'..='\u{2c4}',
+    '\u{2c7}'..='\u{2c7}',
+    '\u{2c9}'..='\u{2cb}',
+    '\u{2cd}'..='\u{2cd}',
+    '\u{2d0}'..='\u{2d0}',
+    '\u{2d8}'..='\u{2db}',
+    '\u{2dd}'..='\u{2dd}',
+    '\u{2df}'..='\u{2df}',
+    '\u{391}'..='\u{3a1}',
+    '\u{3a3}'..='\u{3a9}',
+    '\u{3b1}'..='\u{3c1}',
+    '\u{3c3}'..='\u{3c9}',
+    '\u{401}'..='\u{401}',
+    '\u{410}'..='\u{44f}',
+    '\u{451}'..='\u{451}',
+    '\u{2010}'..='\u{2010}',
+    '\u{2013}'..='\u{2016}',
+    '\u{2018}'..='\u{2019}',
+    '\u{201c}'..='\u{201d}',
+    '\u{2020}'..='\u{2022}',
+    '\u{2024}'..='\u{2027}',
+    '\u{2030}'..='\u{2030}',
+    '\u{2032}'..='\u{2033}',
+    '\u{2035}'..='\u{2035}',
+    '\u{203b}'..='\u{203b}',
+    '\u{203e}'..='\u{203e}',
+    '\u{2074}'..='\u{2074}',
+    '\u{207f}'..='\u{207f}',
+    '\u{2081}'..='\u{2084}',
+    '\u{20ac}'..='\u{20ac}',
+    '\u{2103}'..='\u{2103}',
+    '\u{2105}'..='\u{2105}',
+    '\u{2109}'..='\u{2109}',
+    '\u{2113}'..='\u{2113}',
+    '\u{2116}'..='\u{2116}',
+    '\u{2121}'..='\u{2122}',
+    '\u{2126}'..='\u{2126}',
+    '\u{212b}'..='\u{212b}',
+    '\u{2153}'..='\u{2154}',
+    '\u{215b}'..='\u{215e}',
+    '\u{2160}'..='\u{216b}',
+    '\u{2170}'..='\u{2179}',
+    '\u{2189}'..='\u{2189}',
+    '\u{2190}'..='\u{2199}',
+    '\u{21b8}'..='\u{21b9}',
+    '\u{21d2}'..='\u{21d2}',
+    '\u{21d4}'..='\u{21d4}',
+    '\u{21e7}'..='\u{21e7}',
+    '\u{2200}'..='\u{2200}',
+    '\u{2202}'..='\u{2203}',
+    '\u{2207}'..='\u{2208}',
+    '\u{220b}'..='\u{220b}',
+    '\u{220f}'..='\u{220f}',
+    '\u{2211}'..='\u{2211}',
+    '\u{2215}'..='\u{2215}',
+    '\u{221a}'..='\u{221a}',
+    '\u{221d}'..='\u{2220}',
+    '\u{2223}'..='\u{2223}',
+    '\u{2225}'..='\u{2225}',
+    '\u{2227}'..='\u{222c}',
+    '\u{222e}'..='\u{222e}',
+    '\u{2234}'..='\u{2237}',
+    '\u{223c}'..='\u{223d}',
+    '\u{2248}'..='\u{2248}',
+    '\u{224c}'..='\u{224c}',
+    '\u{2252}'..='\u{2252}',
+    '\u{2260}'..='\u{2261}',
+    '\u{2264}'..='\u{2267}',
+    '\u{226a}'..='\u{226b}',
+    '\u{226e}'..='\u{226f}',
+    '\u{2282}'..='\u{2283}',
+    '\u{2286}'..='\u{2287}',
+    '\u{2295}'..='\u{2295}',
+    '\u{2299}'..='\u{2299}',
+    '\u{22a5}'..='\u{22a5}',
+    '\u{22bf}'..='\u{22bf}',
+    '\u{2312}'..='\u{2312}',
+    '\u{2460}'..='\u{24e9}',
+    '\u{24eb}'..='\u{254b}',
+    '\u{2550}'..='\u{2573}',
+    '\u{2580}'..='\u{258f}',
+    '\u{2592}'..='\u{2595}',
+    '\u{25a0}'..='\u{25a1}',
+    '\u{25a3}'..='\u{25a9}',
+    '\u{25b2}'..='\u{25b3}',
+    '\u{25b6}'..='\u{25b7}',
+    '\u{25bc}'..='\u{25bd}',
+    '\u{25c0}'..='\u{25c1}',
+    '\u{25c6}'..='\u{25c8}',
+    '\u{25cb}'..='\u{25cb}',
+    '\u{25ce}'..='\u{25d1}',
+    '\u{25e2}'..='\u{25e5}',
+    '\u{25ef}'..='\u{25ef}',
+    '\u{2605}'..='\u{2606}',
+    '\u{2609}'..='\u{2609}',
+    '\u{260e}'..='\u{260f}',
+    '\u{261c}'..='\u{261c}',
+    '\u{261e}'..='\u{261e}',
+    '\u{2640}'..='\u{2640}',
+    '\u{2642}'..='\u{2642}',
+    '\u{2660}'..='\u{2661}',
+    '\u{2663}'..='\u{2665}',
+    '\u{2667}'..='\u{266a}',
+    '\u{266c}'..='\u{266d}',
+    '\u{266f}'..='\u{266f}',
+    '\u{269e}'..='\u{269f}',
+    '\u{26bf}'..='\u{26bf}',
+    '\u{26c6}'..='\u{26cd}',
+    '\u{26cf}'..='\u{26d3}',
+    '\u{26d5}'..='\u{26e1}',
+    '\u{26e3}'..='\u{26e3}',
+    '\u{26e8}'..='\u{26e9}',
+    '\u{26eb}'..='\u{26f1}',
+    '\u{26f4}'..='\u{26f4}',
+    '\u{26f6}'..='\u{26f9}',
+    '\u{26fb}'..='\u{26fc}',
+    '\u{26fe}'..='\u{26ff}',
+    '\u{273d}'..='\u{273d}',
+    '\u{2776}'..='\u{277f}',
+    '\u{2b56}'..='\u{2b59}',
+    '\u{3248}'..='\u{324f}',
+    '\u{e000}'..='\u{f8ff}',
+    '\u{fffd}'..='\u{fffd}',
+    '\u{1f100}'..='\u{1f10a}',
+    '\u{1f110}'..='\u{1f12d}',
+    '\u{1f130}'..='\u{1f169}',
+    '\u{1f170}'..='\u{1f18d}',
+    '\u{1f18f}'..='\u{1f190}',
+    '\u{1f19b}'..='\u{1f1ac}',
+    '\u{f0000}'..='\u{ffffd}',
+    '\u{100000}'..='\u{10fffd}',
+];
+
+pub mod charwidth {
+    use core::option::Option::{self, None, Some};
+    use EastAsianWidth;
+    use Script;
+
+    /// Returns the encoded [UAX #11](https://www.unicode.org/reports/tr11/) based width of `c` by
+    /// consulting a multi-level lookup table. Use `resolve_width` to convert it into a column
+    /// count.
+    ///
+    /// # Maintenance
+    /// The tables themselves are autogenerated but this function is hardcoded. You should have
+    /// nothing to worry about if you re-run `unicode.py` (for example, when updating Unicode.)
+    /// However, if you change the *actual structure* of the lookup tables (perhaps by editing the
+    /// `TABLE_CFGS` global in `unicode.py`) you must ensure that this code reflects those changes.
+    #[inline]
+    const fn lookup_width(c: char) -> u8 {
+        let cp = c as usize;
+
+        let t1_offset = TABLES_0[cp >> 13 & 0xFF];
+
+        // Each sub-table in TABLES_1 is 7 bits, and each stored entry is a byte,
+        // so each sub-table is 128 bytes in size.
+        // (Sub-tables are selected using the computed offset from the previous table.)
+        let t2_offset = TABLES_1[128 * t1_offset as usize + (cp >> 6 & 0x7F)];
+
+        // Each sub-table in TABLES_2 is 6 bits, but each stored entry is 2 bits.
+        // This is accomplished by packing four stored entries into one byte.
+        // So each sub-table is 2**(6-2) == 16 bytes in size.
+        // Since this is the last table, each entry represents an encoded width.
+        let packed_widths = TABLES_2[16 * t2_offset as usize + (cp >> 2 & 0xF)];
+
+        // Extract the packed width
+        packed_widths >> (2 * (cp & 0b11)) & 0b11
+    }
+
+    /// Converts an encoded width from one of the lookup tables into a column count.
+    #[inline]
+    const fn resolve_width(width: u8, is_cjk: bool) -> usize {
+        // A width of 3 signifies that the codepoint is ambiguous width.
+        if width == 3 {
+            if is_cjk {
+                2
+            } else {
+                1
+            }
+        } else {
+            width as usize
+        }
+    }
+
+    /// Converts the encoded width of `c` from one of the lookup tables into a column count.
+    /// With the `cjk-punctuation-wide` feature, the ambiguous width punctuation in
+    /// `CJK_PUNCTUATION_TABLE` is double width regardless of `is_cjk`.
+    #[inline]
+    const fn resolve_char_width(c: char, width: u8, is_cjk: bool) -> usize {
+        #[cfg(feature = "cjk-punctuation-wide")]
+        if width == 3 && bsearch_range_table(c, &CJK_PUNCTUATION_TABLE) {
+            return 2;
+        }
+        #[cfg(not(feature = "cjk-punctuation-wide"))]
+        let _ = c;
+        resolve_width(width, is_cjk)
+    }
+
+    /// Returns the [UAX #11](https://www.unicode.org/reports/tr11/) based width of `c`, or
+    /// `None` if `c` is a control character other than `'\x00'`.
+    /// If `is_cjk == true`, ambiguous width characters are treated as double width; otherwise,
+    /// they're treated as single width.
+    #[inline]
+    pub const fn width(c: char, is_cjk: bool) -> Option<usize> {
+        if c < '\u{7F}' {
+            if c >= '\u{20}' {
+                // U+0020 to U+007F (exclusive) are single-width ASCII codepoints
+                Some(1)
+            } else if c == '\0' {
+                // U+0000 *is* a control code, but it's special-cased
+                Some(0)
+            } else {
+                // U+0001 to U+0020 (exclusive) are control codes
+                None
+            }
+        } else if c < '\u{A0}' {
+            // U+007F to U+00A0 (exclusive) are control codes
+            None
+        } else if c < '\u{100}' {
+            // U+00A0 to U+0100 (exclusive) are common enough in European text to get their own
+            // table, which skips the multi-level lookup
+            Some(resolve_width(LATIN1_TABLE[c as usize - 0xA0], is_cjk))
+        } else {
+            // No characters >= U+00A0 are control codes, so we can consult the lookup tables
+            Some(resolve_char_width(c, lookup_width(c), is_cjk))
+        }
+    }
+
+    /// Returns the [UAX #11](https://www.unicode.org/reports/tr11/) based widths of `c` with
+    /// ambiguous width characters treated as single width and as double width, in that order,
+    /// or `None` if `c` is a control character other than `'\0'`.
+    /// This is equivalent to calling `width` twice, but only looks `c` up once.
+    #[inline]
+    pub fn width_pair(c: char) -> Option<(usize, usize)> {
+        if c < '\u{A0}' {
+            // ASCII and the C0 and C1 control codes aren't ambiguous width
+            return width(c, false).map(|w| (w, w));
+        }
+        let encoded = if c < '\u{100}' {
+            LATIN1_TABLE[c as usize - 0xA0]
+        } else {
+            lookup_width(c)
+        };
+        Some((
+            resolve_char_width(c, encoded, false),
+            resolve_char_width(c, encoded, true),
+        ))
+    }
+
+    /// Returns the width of `c` derived from the Unicode data alone, without special-casing
+    /// control characters: like every codepoint in general category `Cc`, they're zero-width.
+    /// If `is_cjk == true`, ambiguous width characters are treated as double width; otherwise,
+    /// they're treated as single width.
+    #[inline]
+    pub const fn table_width(c: char, is_cjk: bool) -> usize {
+        if c < '\u{A0}' {
+            // The multi-level tables store everything below U+0100 as narrow, so ASCII and the
+            // C0 and C1 control codes are resolved here, as in `width`
+            (c >= '\u{20}' && c < '\u{7F}') as usize
+        } else if c < '\u{100}' {
+            resolve_width(LATIN1_TABLE[c as usize - 0xA0], is_cjk)
+        } else {
+            resolve_char_width(c, lookup_width(c), is_cjk)
+        }
+    }
+
+    /// Returns `true` if `c` lies within one of the ranges in `r`, which must be sorted and
+    /// non-overlapping. This is a `while` loop rather than `binary_search_by` so that it can be
+    /// used in `const` evaluation.
+    #[inline]
+    const fn bsearch_range_table(c: char, r: &[(char, char)]) -> bool {
+        let (mut lo, mut hi) = (0, r.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let (low, high) = r[mid];
+            if low > c {
+                hi = mid;
+            } else if high < c {
+                lo = mid + 1;
+            } else {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Returns `true` if `c` has been assigned a general category other than `Cn` (Unassigned).
+    /// Private-use characters are considered assigned; noncharacters are not.
+    #[inline]
+    pub fn is_assigned(c: char) -> bool {
+        bsearch_range_table(c, &ASSIGNED_TABLE)
+    }
+
+    /// Returns `true` if `c` has the `Default_Ignorable_Code_Point` property, meaning that it
+    /// should be invisible when not specially supported. Notably, this excludes the
+    /// `Prepended_Concatenation_Mark` characters, which are visible despite being format
+    /// characters.
+    #[inline]
+    pub fn is_default_ignorable(c: char) -> bool {
+        bsearch_range_table(c, &DEFAULT_IGNORABLE_TABLE)
+    }
+
+    /// Returns `true` if `c` has the `Emoji` property. This includes characters like U+2600
+    /// BLACK SUN WITH RAYS which default to text presentation, but can be presented as emoji
+    /// when followed by U+FE0F VARIATION SELECTOR-16.
+    #[inline]
+    pub fn is_emoji(c: char) -> bool {
+        bsearch_range_table(c, &EMOJI_TABLE)
+    }
+
+    /// Returns `true` if `c` has the `Emoji_Component` property, meaning that it can appear as
+    /// part of an emoji sequence. This includes U+200D ZERO WIDTH JOINER, U+FE0F VARIATION
+    /// SELECTOR-16, the skin tone modifiers, the hair components, the regional indicators, and
+    /// the keycap and tag characters.
+    #[cfg(feature = "segmentation")]
+    #[inline]
+    pub fn is_emoji_component(c: char) -> bool {
+        bsearch_range_table(c, &EMOJI_COMPONENT_TABLE)
+    }
+
+    /// Returns `true` if `c` has the `Grapheme_Extend` property, meaning that it extends the
+    /// grapheme cluster of the preceding character. This includes all nonspacing and enclosing
+    /// marks, as well as a few spacing marks and other characters.
+    #[inline]
+    pub fn is_grapheme_extend(c: char) -> bool {
+        bsearch_range_table(c, &GRAPHEME_EXTEND_TABLE)
+    }
+
+    /// Returns `true` if `c` is a combining mark: a nonspacing (`Mn`), spacing (`Mc`), or
+    /// enclosing (`Me`) mark.
+    #[inline]
+    pub fn is_mark(c: char) -> bool {
+        bsearch_range_table(c, &MARK_TABLE)
+    }
+
+    /// Returns `true` if `c` has the `Pattern_White_Space` property, the stable set of
+    /// characters which programming languages and other syntaxes should treat as whitespace.
+    #[inline]
+    pub fn is_pattern_white_space(c: char) -> bool {
+        bsearch_range_table(c, &PATTERN_WHITE_SPACE_TABLE)
+    }
+
+    /// Returns `true` if `c` is a strong right-to-left character, with the bidi class `R` (such
+    /// as Hebrew letters) or `AL` (such as Arabic letters).
+    #[inline]
+    pub fn is_strong_rtl(c: char) -> bool {
+        bsearch_range_table(c, &RTL_TABLE)
+    }
+
+    /// Returns the East Asian Width category of `c`, as listed in `EastAsianWidth.txt`.
+    #[inline]
+    pub fn east_asian_width(c: char) -> EastAsianWidth {
+        use core::cmp::Ordering::{Equal, Greater, Less};
+        match EAW_TABLE.binary_search_by(|&(low, high, _)| {
+            if low > c {
+                Greater
+            } else if high < c {
+                Less
+            } else {
+                Equal
+            }
+        }) {
+            Ok(i) => EAW_TABLE[i].2,
+            Err(_) => EastAsianWidth::Neutral,
+        }
+    }
+
+    /// Returns the script of `c`, as listed in `Scripts.txt`.
+    #[inline]
+    pub fn script(c: char) -> Script {
+        use core::cmp::Ordering::{Equal, Greater, Less};
+        match SCRIPT_TABLE.binary_search_by(|&(low, high, _)| {
+            if low > c {
+                Greater
+            } else if high < c {
+                Less
+            } else {
+                Equal
+            }
+        }) {
+            Ok(i) => SCRIPT_TABLE[i].2,
+            Err(_) => Script::Unknown,
+        }
+    }
+
+    /// Autogenerated. 1 sub-table(s). Consult [`lookup_width`] for layout info.
+    static TABLES_0: [u8; 256] = [
+        0x00, 0x01, 0x02, 0x03, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+        0x0E, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x0F, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03,
+        0x03, 0x0F, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09,
+        0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09,
+        0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09,
+        0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09,
+        0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09,
+        0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x10, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09, 0x09,
+        0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x12, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11,
+        0x12, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00,
+    ];
+
+    /// Autogenerated. 19 sub-table(s). Consult [`lookup_width`] for layout info.
+    static TABLES_1: [u8; 2432] = [
+        0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x00, 0x03, 0x00, 0x04, 0x00, 0x05, 0x06, 0x07, 0x08,
+        0x09, 0x0A, 0x0B, 0x0C, 0x00, 0x00, 0x00, 0x0D, 0x0E, 0x0F, 0x10, 0x00, 0x11, 0x12, 0x13,
+        0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F, 0x1E, 0x20, 0x21,
+        0x22, 0x23, 0x24, 0x25, 0x26, 0x21, 0x27, 0x28, 0x29, 0x2A, 0x2B, 0x2C, 0x2D, 0x2E, 0x2F,
+        0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x00, 0x37, 0x38, 0x06, 0x06, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x39, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x3A, 0x3B, 0x3C, 0x3D, 0x3E, 0x00, 0x3F, 0x00, 0x40, 0x00, 0x00, 0x00, 0x41,
+        0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x00, 0x00, 0x4A, 0x00, 0x00, 0x00, 0x06,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51,
+        0x52, 0x53, 0x54, 0x55, 0x00, 0x56, 0x00, 0x00, 0x57, 0x00, 0x58, 0x59, 0x5A, 0x59, 0x5B,
+        0x5C, 0x5D, 0x5E, 0x5F, 0x60, 0x61, 0x62, 0x63, 0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x65, 0x66, 0x00, 0x00, 0x00, 0x00, 0x00, 0x67,
+        0x00, 0x68, 0x00, 0x69, 0x00, 0x00, 0x6A, 0x6B, 0x37, 0x37, 0x37, 0x6C, 0x6D, 0x6E, 0x6F,
+        0x37, 0x70, 0x37, 0x71, 0x72, 0x73, 0x74, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x00, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x75, 0x76, 0x00, 0x00, 0x00, 0x00, 0x00, 0x77, 0x78, 0x79,
+        0x00, 0x00, 0x00, 0x00, 0x7A, 0x00, 0x00, 0x7B, 0x7C, 0x7D, 0x7E, 0x7F, 0x80, 0x81, 0x82,
+        0x83, 0x00, 0x00, 0x00, 0x84, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x85,
+        0x86, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x87, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x88, 0x89, 0x00, 0x68, 0x6E, 0x8A, 0x00, 0x8B, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x8C, 0x00, 0x00, 0x00, 0x8D, 0x00, 0x8E, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x8F, 0x00, 0x00, 0x90, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x91, 0x00, 0x00, 0x00, 0x00, 0x00, 0x92, 0x93, 0x00, 0x94, 0x95, 0x00,
+        0x96, 0x97, 0x98, 0x99, 0x9A, 0x9B, 0x9C, 0x9D, 0x9E, 0x2A, 0x00, 0x9F, 0x28, 0xA0, 0x00,
+        0x00, 0xA1, 0xA2, 0xA3, 0xA4, 0x00, 0x00, 0xA5, 0xA6, 0xA7, 0xA8, 0xA9, 0x00, 0xAA, 0x00,
+        0x00, 0x00, 0xAB, 0x00, 0x00, 0x00, 0xAC, 0xAD, 0x00, 0xAE, 0xAF, 0xB0, 0xB1, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0xB2, 0x00, 0xB3, 0x00, 0xB4, 0xB5, 0xB6, 0x00, 0x00, 0x00, 0x00, 0xB7,
+        0xB8, 0xB9, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x43, 0xBA, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0xBB, 0xBC, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBD, 0xBE, 0xBF, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0xC0,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0xC1, 0xC2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC3, 0x37, 0x37, 0x37, 0x37, 0xC4,
+        0xC5, 0x37, 0x37, 0x37, 0x37, 0x37, 0xC6, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xC7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC8,
+        0xC9, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xCA, 0xCB, 0x00, 0x00, 0xCC, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xCD, 0xCE,
+        0xCF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xD0, 0x00, 0xBD, 0x00, 0xBC, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0xD1, 0xD2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xD2, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xD3, 0x00, 0xD4,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xD5, 0x00, 0x00, 0xD6,
+        0xD7, 0xD8, 0xD9, 0x00, 0xDA, 0xDB, 0x00, 0x00, 0xDC, 0xDD, 0xDE, 0xDF, 0xE0, 0xE1, 0x37,
+        0xE2, 0xE3, 0xE4, 0xE5, 0xE6, 0x37, 0xE7, 0x37, 0xE8, 0x00, 0x00, 0x00, 0xE9, 0x00, 0x00,
+        0x00, 0x00, 0xEA, 0xEB, 0x37, 0x37, 0x00, 0xEC, 0xED, 0xEE, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37,
+        0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0x37, 0xE3, 0xEF, 0x06, 0x00, 0x00, 0x06, 0x06, 0x06,
+        0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59,
+        0x59, 0xF0,
+    ];
+
+    /// Autogenerated. 241 sub-table(s). Consult [`lookup_width`] for layout info.
+    static TABLES_2: [u8; 3856] = [
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x5D, 0x55, 0x55, 0x55, 0xDD, 0x55, 0xD5, 0x55, 0x55, 0xF5, 0xD5, 0x55, 0xFD, 0x55,
+        0x57, 0xD5, 0x7F, 0x57, 0xFF, 0x5D, 0xF5, 0x55, 0x55, 0x55, 0x55, 0xF5, 0xD5, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x75, 0x77, 0x77, 0x77, 0x57, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x5D, 0x55, 0x55, 0x55, 0x5D, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xD7, 0xFD, 0x5D, 0x57, 0x55, 0xFF, 0xDD, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xFD, 0xFF, 0xFF,
+        0xFF, 0xDF, 0xFF, 0x5F, 0x55, 0xFD, 0xFF, 0xFF, 0xFF, 0xDF, 0xFF, 0x5F, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x5D, 0x55, 0x55, 0x55, 0xFF,
+        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+        0x5D, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x00, 0x50,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x41,
+        0x10, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x00, 0x50, 0x55, 0x55, 0x00, 0x00, 0x40, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55, 0x54, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x00, 0x10, 0x00, 0x14, 0x04, 0x50, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05,
+        0x00, 0x00, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x15, 0x00, 0x00, 0x55, 0x55, 0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x10, 0x00,
+        0x00, 0x01, 0x01, 0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x01,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x50, 0x55,
+        0x00, 0x00, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x45, 0x54, 0x01, 0x00, 0x54,
+        0x51, 0x01, 0x00, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x51, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0x01,
+        0x54, 0x55, 0x51, 0x55, 0x55, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x45,
+        0x41, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x54, 0x41, 0x15, 0x14, 0x50, 0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x50, 0x51,
+        0x55, 0x55, 0x01, 0x10, 0x54, 0x51, 0x55, 0x55, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x05, 0x00, 0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x14, 0x01, 0x54, 0x55, 0x51, 0x55, 0x41, 0x55, 0x55, 0x05, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x45, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0x55, 0x55, 0x51, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x04, 0x54, 0x05, 0x04, 0x50, 0x55, 0x41, 0x55,
+        0x55, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x45, 0x55, 0x50, 0x55, 0x55,
+        0x55, 0x55, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x50, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x54, 0x01, 0x54, 0x55, 0x51,
+        0x55, 0x55, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x51, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x45, 0x55, 0x05, 0x44, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x51, 0x00, 0x40, 0x55,
+        0x55, 0x15, 0x00, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x51, 0x00,
+        0x00, 0x54, 0x55, 0x55, 0x00, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x50, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x11, 0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x01, 0x00, 0x00, 0x40, 0x00, 0x04, 0x55, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x54, 0x55, 0x45, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x01, 0x04, 0x00, 0x41, 0x41, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x50,
+        0x05, 0x54, 0x55, 0x55, 0x55, 0x01, 0x54, 0x55, 0x55, 0x45, 0x41, 0x55, 0x51, 0x55, 0x55,
+        0x55, 0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0xAA, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x05, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x10, 0x00,
+        0x50, 0x55, 0x45, 0x01, 0x00, 0x00, 0x55, 0x55, 0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x15, 0x00, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x41, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x51, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x40, 0x15, 0x54,
+        0x55, 0x45, 0x55, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x14, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x45, 0x00, 0x40, 0x44,
+        0x01, 0x00, 0x54, 0x15, 0x00, 0x00, 0x14, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x04, 0x40, 0x54, 0x45, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x00, 0x00, 0x55, 0x55, 0x55, 0x50, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x05, 0x50, 0x10, 0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x45, 0x50, 0x11, 0x50, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x05, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x40, 0x00, 0x00, 0x00, 0x04, 0x00, 0x54, 0x51, 0x55, 0x54, 0x50, 0x55,
+        0x55, 0x55, 0x15, 0x00, 0xD7, 0x7F, 0x5F, 0x5F, 0x7F, 0xFF, 0x05, 0x40, 0xF7, 0x5D, 0xD5,
+        0x75, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x04, 0x00, 0x00, 0x55, 0x57,
+        0x55, 0xD5, 0xFD, 0x57, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x57, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x54, 0x55, 0x55, 0x55, 0xD5, 0x5D, 0x5D, 0x55, 0xD5, 0x75, 0x55, 0x55, 0x7D, 0x75, 0xD5,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xD5, 0x57, 0xD5, 0x7F, 0xFF, 0xFF,
+        0xFF, 0x55, 0xFF, 0xFF, 0x5F, 0x55, 0x55, 0x55, 0x5D, 0x55, 0xFF, 0xFF, 0x5F, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x5F, 0x55, 0x55, 0x55, 0x55, 0x55, 0x75, 0x57, 0x55, 0x55,
+        0x55, 0xD5, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xF7, 0xD5, 0xD7, 0xD5, 0x5D, 0x5D, 0x75,
+        0xFD, 0xD7, 0xDD, 0xFF, 0x77, 0x55, 0xFF, 0x55, 0x5F, 0x55, 0x55, 0x57, 0x57, 0x75, 0x55,
+        0x55, 0x55, 0x5F, 0xFF, 0xF5, 0xF5, 0x55, 0x55, 0x55, 0x55, 0xF5, 0xF5, 0x55, 0x55, 0x55,
+        0x5D, 0x5D, 0x55, 0x55, 0x5D, 0x55, 0x55, 0x55, 0x55, 0x55, 0xD5, 0x55, 0x55, 0x55, 0x55,
+        0x75, 0x55, 0xA5, 0x55, 0x55, 0x55, 0x69, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xA9, 0x56, 0x96, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xDF, 0xFF, 0xFF, 0xFF, 0xFF,
+        0xFF, 0xFF, 0xFF, 0xFF, 0x55, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x55,
+        0x55, 0x55, 0xFF, 0xFF, 0xFF, 0xFF, 0xF5, 0x5F, 0x55, 0x55, 0xDF, 0xFF, 0x5F, 0x55, 0xF5,
+        0xF5, 0x55, 0x5F, 0x5F, 0xF5, 0xD7, 0xF5, 0x5F, 0x55, 0x55, 0x55, 0xF5, 0x5F, 0x55, 0xD5,
+        0x55, 0x55, 0x55, 0x69, 0x55, 0x7D, 0x5D, 0xF5, 0x55, 0x5A, 0x55, 0x77, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x77, 0x55, 0xAA, 0xAA, 0xAA, 0x55, 0x55, 0x55, 0xDF, 0xDF,
+        0x7F, 0xDF, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0xF5, 0x59,
+        0x55, 0xA5, 0x55, 0x55, 0x55, 0x55, 0xE9, 0x55, 0xFA, 0xFF, 0xEF, 0xFF, 0xFE, 0xFF, 0xFF,
+        0xDF, 0x55, 0xEF, 0xFF, 0xAF, 0xFB, 0xEF, 0xFB, 0x55, 0x59, 0xA5, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x56, 0x55, 0x55, 0x55, 0x55, 0x5D, 0x55, 0x55, 0x55, 0x66, 0x95, 0x9A,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xF5, 0xFF, 0xFF, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0xA9, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x56, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x95, 0x56, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x56, 0xF9, 0x5F, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x50, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x9A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+        0x55, 0x55, 0x55, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x5A, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x0A,
+        0xA0, 0xAA, 0xAA, 0xAA, 0x6A, 0xA9, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x6A, 0x81, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x55, 0xA9, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xA9, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x6A, 0xAA, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0x55, 0x55, 0x95, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+        0xAA, 0xAA, 0x6A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xFF, 0xFF,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+        0x56, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x6A,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x40, 0x00, 0x00, 0x50,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x50, 0x55,
+        0x55, 0x55, 0x45, 0x45, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x41, 0x55, 0x54, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+        0x50, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x00,
+        0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x00, 0x00, 0x50, 0x55, 0x55, 0x55, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x56, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x15, 0x05, 0x50, 0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x01, 0x40, 0x41, 0x41, 0x55, 0x55, 0x15, 0x55, 0x55, 0x54, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x04, 0x14, 0x54, 0x05, 0x51, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x50, 0x55, 0x45, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x51, 0x54, 0x51, 0x55, 0x55, 0x55, 0x55, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x40, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x45, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x00, 0x00, 0x00, 0x00, 0xAA, 0xAA, 0x5A, 0x55, 0x00, 0x00, 0x00, 0x00, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x6A, 0xAA, 0xAA, 0xAA, 0xAA, 0x6A, 0xAA, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x56, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xAA, 0x6A, 0x55,
+        0x55, 0x55, 0x55, 0x01, 0x5D, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x40, 0x55, 0x01, 0x41, 0x55, 0x00, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x40, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x41, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x00, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x01, 0x55, 0x05,
+        0x00, 0x00, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05,
+        0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00,
+        0x00, 0x00, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x14, 0x54,
+        0x55, 0x15, 0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15,
+        0x40, 0x41, 0x51, 0x45, 0x55, 0x55, 0x51, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x00,
+        0x01, 0x00, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x05, 0x00, 0x40, 0x55, 0x55, 0x01, 0x14, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x15, 0x50, 0x04, 0x55, 0x45, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x15, 0x15, 0x00, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x05, 0x00, 0x54, 0x00, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x05, 0x44, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x45, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x00, 0x44, 0x15, 0x04,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05, 0x50, 0x55,
+        0x10, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15,
+        0x00, 0x40, 0x11, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15,
+        0x51, 0x00, 0x10, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x01, 0x05, 0x10,
+        0x00, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x15, 0x00, 0x00, 0x41, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x44, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00,
+        0x05, 0x55, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x01, 0x00, 0x40, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x00, 0x14, 0x40, 0x55, 0x15, 0x55, 0x55,
+        0x01, 0x40, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x05,
+        0x00, 0x00, 0x40, 0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x40, 0x00, 0x10, 0x55,
+        0x55, 0x55, 0x55, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x04, 0x41, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x01, 0x40, 0x45,
+        0x10, 0x00, 0x10, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x50, 0x11, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x15, 0x54, 0x55, 0x55, 0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x05, 0x40, 0x55, 0x44, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0x15, 0x00, 0x00, 0x00, 0x50, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x00, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x40,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0xAA, 0x54, 0x55, 0x55, 0x5A, 0x55, 0x55, 0x55, 0xAA, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x55, 0x55, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0x5A, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xAA,
+        0xAA, 0x56, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xAA, 0xA9, 0xAA,
+        0x69, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x6A, 0x55, 0x55, 0x55, 0x65, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x6A, 0x59, 0x55, 0x55, 0x55, 0xAA, 0x55, 0x55, 0xAA,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x41, 0x00, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x50, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x01, 0x50, 0x01, 0x00, 0x00, 0x00, 0x00, 0x40, 0x01, 0x00, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x05, 0x50, 0x55, 0x55, 0x55, 0x55, 0x05, 0x54, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x54, 0x55, 0x51, 0x55, 0x55, 0x55, 0x54, 0x55,
+        0x55, 0x55, 0x55, 0x15, 0x00, 0x01, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55, 0x00, 0x40,
+        0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x10, 0x04, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x45, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x00, 0x40, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x56, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0xFF, 0xFF, 0x7F, 0x55, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+        0xFF, 0x5F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+        0xFF, 0x5F, 0x55, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xEF, 0xAB, 0xAA, 0xEA, 0xFF,
+        0xFF, 0xFF, 0xFF, 0x57, 0x55, 0x55, 0x55, 0x55, 0x6A, 0x55, 0x55, 0x55, 0xAA, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x55, 0xAA, 0xAA, 0x56, 0x55, 0x5A, 0x55,
+        0x55, 0x55, 0xAA, 0x5A, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0x56, 0x55, 0x55, 0xA9, 0xAA, 0x9A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xA6, 0xAA, 0xAA, 0xAA,
+        0xAA, 0xAA, 0x55, 0x55, 0x55, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+        0x6A, 0x95, 0xAA, 0x55, 0x55, 0x55, 0xAA, 0xAA, 0xAA, 0xAA, 0x56, 0x56, 0xAA, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x6A,
+        0xA6, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+        0xAA, 0x96, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+        0xAA, 0xAA, 0x5A, 0x55, 0x55, 0x95, 0x6A, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x55, 0x55,
+        0x55, 0x55, 0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x69, 0x55, 0x55, 0x55, 0x56, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x95, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xAA, 0x5A, 0x55, 0x56, 0x6A, 0xA9, 0x55, 0xAA,
+        0x55, 0x55, 0x95, 0x56, 0x55, 0xAA, 0xAA, 0x56, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0xAA, 0xAA, 0xAA, 0x55, 0x56, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xAA, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x6A, 0xAA, 0xAA, 0x9A, 0xAA, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x55, 0x55, 0x55, 0x55,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xAA, 0xAA, 0xAA, 0x56, 0xAA, 0xAA, 0x56,
+        0x55, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0x9A, 0xAA, 0x5A,
+        0x55, 0xA5, 0xAA, 0xAA, 0xAA, 0x55, 0xAA, 0xAA, 0x56, 0x55, 0xAA, 0xAA, 0x56, 0x55, 0x51,
+        0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+        0x5F,
+    ];
+
+    /// Autogenerated. The encoded widths of U+00A0..U+0100, consulted by [`width`].
+    static LATIN1_TABLE: [u8; 96] = [
+        1, 3, 1, 1, 3, 1, 1, 3, 3, 1, 3, 1, 1, 1, 3, 1, 3, 3, 3, 3, 3, 1, 3, 3, 3, 3, 3, 1, 3, 3,
+        3, 3, 1, 1, 1, 1, 1, 1, 3, 1, 1, 1, 1, 1, 1, 1, 1, 1, 3, 1, 1, 1, 1, 1, 1, 3, 3, 1, 1, 1,
+        1, 1, 3, 3, 3, 3, 1, 1, 1, 1, 3, 1, 3, 3, 3, 1, 3, 3, 1, 1, 3, 1, 3, 3, 1, 1, 1, 3, 3, 3,
+        3, 1, 3, 1, 3, 1,
+    ];
+
+    /// Autogenerated. 707 sorted, non-overlapping range(s).
+    static ASSIGNED_TABLE: [(char, char); 707] = [
+        ('\u{0}', '\u{377}'),
+        ('\u{37a}', '\u{37f}'),
+        ('\u{384}', '\u{38a}'),
+        ('\u{38c}', '\u{38c}'),
+        ('\u{38e}', '\u{3a1}'),
+        ('\u{3a3}', '\u{52f}'),
+        ('\u{531}', '\u{556}'),
+        ('\u{559}', '\u{58a}'),
+        ('\u{58d}', '\u{58f}'),
+        ('\u{591}', '\u{5c7}'),
+        ('\u{5d0}', '\u{5ea}'),
+        ('\u{5ef}', '\u{5f4}'),
+        ('\u{600}', '\u{70d}'),
+        ('\u{70f}', '\u{74a}'),
+        ('\u{74d}', '\u{7b1}'),
+        ('\u{7c0}', '\u{7fa}'),
+        ('\u{7fd}', '\u{82d}'),
+        ('\u{830}', '\u{83e}'),
+        ('\u{840}', '\u{85b}'),
+        ('\u{85e}', '\u{85e}'),
+        ('\u{860}', '\u{86a}'),
+        ('\u{870}', '\u{88e}'),
+        ('\u{890}', '\u{891}'),
+        ('\u{898}', '\u{983}'),
+        ('\u{985}', '\u{98c}'),
+        ('\u{98f}', '\u{990}'),
+        ('\u{993}', '\u{9a8}'),
+        ('\u{9aa}', '\u{9b0}'),
+        ('\u{9b2}', '\u{9b2}'),
+        ('\u{9b6}', '\u{9b9}'),
+        ('\u{9bc}', '\u{9c4}'),
+        ('\u{9c7}', '\u{9c8}'),
+        ('\u{9cb}', '\u{9ce}'),
+        ('\u{9d7}', '\u{9d7}'),
+        ('\u{9dc}', '\u{9dd}'),
+        ('\u{9df}', '\u{9e3}'),
+        ('\u{9e6}', '\u{9fe}'),
+        ('\u{a01}', '\u{a03}'),
+        ('\u{a05}', '\u{a0a}'),
+        ('\u{a0f}', '\u{a10}'),
+        ('\u{a13}', '\u{a28}'),
+        ('\u{a2a}', '\u{a30}'),
+        ('\u{a32}', '\u{a33}'),
+        ('\u{a35}', '\u{a36}'),
+        ('\u{a38}', '\u{a39}'),
+        ('\u{a3c}', '\u{a3c}'),
+        ('\u{a3e}', '\u{a42}'),
+        ('\u{a47}', '\u{a48}'),
+        ('\u{a4b}', '\u{a4d}'),
+        ('\u{a51}', '\u{a51}'),
+        ('\u{a59}', '\u{a5c}'),
+        ('\u{a5e}', '\u{a5e}'),
+        ('\u{a66}', '\u{a76}'),
+        ('\u{a81}', '\u{a83}'),
+        ('\u{a85}', '\u{a8d}'),
+        ('\u{a8f}', '\u{a91}'),
+        ('\u{a93}', '\u{aa8}'),
+        ('\u{aaa}', '\u{ab0}'),
+        ('\u{ab2}', '\u{ab3}'),
+        ('\u{ab5}', '\u{ab9}'),
+        ('\u{abc}', '\u{ac5}'),
+        ('\u{ac7}', '\u{ac9}'),
+        ('\u{acb}', '\u{acd}'),
+        ('\u{ad0}', '\u{ad0}'),
+        ('\u{ae0}', '\u{ae3}'),
+        ('\u{ae6}', '\u{af1}'),
+        ('\u{af9}', '\u{aff}'),
+        ('\u{b01}', '\u{b03}'),
+        ('\u{b05}', '\u{b0c}'),
+        ('\u{b0f}', '\u{b10}'),
+        ('\u{b13}', '\u{b28}'),
+        ('\u{b2a}', '\u{b30}'),
+        ('\u{b32}', '\u{b33}'),
+        ('\u{b35}', '\u{b39}'),
+        ('\u{b3c}', '\u{b44}'),
+        ('\u{b47}', '\u{b48}'),
+        ('\u{b4b}', '\u{b4d}'),
+        ('\u{b55}', '\u{b57}'),
+        ('\u{b5c}', '\u{b5d}'),
+        ('\u{b5f}', '\u{b63}'),
+        ('\u{b66}', '\u{b77}'),
+        ('\u{b82}', '\u{b83}'),
+        ('\u{b85}', '\u{b8a}'),
+        ('\u{b8e}', '\u{b90}'),
+        ('\u{b92}', '\u{b95}'),
+        ('\u{b99}', '\u{b9a}'),
+        ('\u{b9c}', '\u{b9c}'),
+        ('\u{b9e}', '\u{b9f}'),
+        ('\u{ba3}', '\u{ba4}'),
+        ('\u{ba8}', '\u{baa}'),
+        ('\u{bae}', '\u{bb9}'),
+        ('\u{bbe}', '\u{bc2}'),
+        ('\u{bc6}', '\u{bc8}'),
+        ('\u{bca}', '\u{bcd}'),
+        ('\u{bd0}', '\u{bd0}'),
+        ('\u{bd7}', '\u{bd7}'),
+        ('\u{be6}', '\u{bfa}'),
+        ('\u{c00}', '\u{c0c}'),
+        ('\u{c0e}', '\u{c10}'),
+        ('\u{c12}', '\u{c28}'),
+        ('\u{c2a}', '\u{c39}'),
+        ('\u{c3c}', '\u{c44}'),
+        ('\u{c46}', '\u{c48}'),
+        ('\u{c4a}', '\u{c4d}'),
+        ('\u{c55}', '\u{c56}'),
+        ('\u{c58}', '\u{c5a}'),
+        ('\u{c5d}', '\u{c5d}'),
+        ('\u{c60}', '\u{c63}'),
+        ('\u{c66}', '\u{c6f}'),
+        ('\u{c77}', '\u{c8c}'),
+        ('\u{c8e}', '\u{c90}'),
+        ('\u{c92}', '\u{ca8}'),
+        ('\u{caa}', '\u{cb3}'),
+        ('\u{cb5}', '\u{cb9}'),
+        ('\u{cbc}', '\u{cc4}'),
+        ('\u{cc6}', '\u{cc8}'),
+        ('\u{cca}', '\u{ccd}'),
+        ('\u{cd5}', '\u{cd6}'),
+        ('\u{cdd}', '\u{cde}'),
+        ('\u{ce0}', '\u{ce3}'),
+        ('\u{ce6}', '\u{cef}'),
+        ('\u{cf1}', '\u{cf3}'),
+        ('\u{d00}', '\u{d0c}'),
+        ('\u{d0e}', '\u{d10}'),
+        ('\u{d12}', '\u{d44}'),
+        ('\u{d46}', '\u{d48}'),
+        ('\u{d4a}', '\u{d4f}'),
+        ('\u{d54}', '\u{d63}'),
+        ('\u{d66}', '\u{d7f}'),
+        ('\u{d81}', '\u{d83}'),
+        ('\u{d85}', '\u{d96}'),
+        ('\u{d9a}', '\u{db1}'),
+        ('\u{db3}', '\u{dbb}'),
+        ('\u{dbd}', '\u{dbd}'),
+        ('\u{dc0}', '\u{dc6}'),
+        ('\u{dca}', '\u{dca}'),
+        ('\u{dcf}', '\u{dd4}'),
+        ('\u{dd6}', '\u{dd6}'),
+        ('\u{dd8}', '\u{ddf}'),
+        ('\u{de6}', '\u{def}'),
+        ('\u{df2}', '\u{df4}'),
+        ('\u{e01}', '\u{e3a}'),
+        ('\u{e3f}', '\u{e5b}'),
+        ('\u{e81}', '\u{e82}'),
+        ('\u{e84}', '\u{e84}'),
+        ('\u{e86}', '\u{e8a}'),
+        ('\u{e8c}', '\u{ea3}'),
+        ('\u{ea5}', '\u{ea5}'),
+        ('\u{ea7}', '\u{ebd}'),
+        ('\u{ec0}', '\u{ec4}'),
+        ('\u{ec6}', '\u{ec6}'),
+        ('\u{ec8}', '\u{ece}'),
+        ('\u{ed0}', '\u{ed9}'),
+        ('\u{edc}', '\u{edf}'),
+        ('\u{f00}', '\u{f47}'),
+        ('\u{f49}', '\u{f6c}'),
+        ('\u{f71}', '\u{f97}'),
+        ('\u{f99}', '\u{fbc}'),
+        ('\u{fbe}', '\u{fcc}'),
+        ('\u{fce}', '\u{fda}'),
+        ('\u{1000}', '\u{10c5}'),
+        ('\u{10c7}', '\u{10c7}'),
+        ('\u{10cd}', '\u{10cd}'),
+        ('\u{10d0}', '\u{1248}'),
+        ('\u{124a}', '\u{124d}'),
+        ('\u{1250}', '\u{1256}'),
+        ('\u{1258}', '\u{1258}'),
+        ('\u{125a}', '\u{125d}'),
+        ('\u{1260}', '\u{1288}'),
+        ('\u{128a}', '\u{128d}'),
+        ('\u{1290}', '\u{12b0}'),
+        ('\u{12b2}', '\u{12b5}'),
+        ('\u{12b8}', '\u{12be}'),
+        ('\u{12c0}', '\u{12c0}'),
+        ('\u{12c2}', '\u{12c5}'),
+        ('\u{12c8}', '\u{12d6}'),
+        ('\u{12d8}', '\u{1310}'),
+        ('\u{1312}', '\u{1315}'),
+        ('\u{1318}', '\u{135a}'),
+        ('\u{135d}', '\u{137c}'),
+        ('\u{1380}', '\u{1399}'),
+        ('\u{13a0}', '\u{13f5}'),
+        ('\u{13f8}', '\u{13fd}'),
+        ('\u{1400}', '\u{169c}'),
+        ('\u{16a0}', '\u{16f8}'),
+        ('\u{1700}', '\u{1715}'),
+        ('\u{171f}', '\u{1736}'),
+        ('\u{1740}', '\u{1753}'),
+        ('\u{1760}', '\u{176c}'),
+        ('\u{176e}', '\u{1770}'),
+        ('\u{1772}', '\u{1773}'),
+        ('\u{1780}', '\u{17dd}'),
+        ('\u{17e0}', '\u{17e9}'),
+        ('\u{17f0}', '\u{17f9}'),
+        ('\u{1800}', '\u{1819}'),
+        ('\u{1820}', '\u{1878}'),
+        ('\u{1880}', '\u{18aa}'),
+        ('\u{18b0}', '\u{18f5}'),
+        ('\u{1900}', '\u{191e}'),
+        ('\u{1920}', '\u{192b}'),
+        ('\u{1930}', '\u{193b}'),
+        ('\u{1940}', '\u{1940}'),
+        ('\u{1944}', '\u{196d}'),
+        ('\u{1970}', '\u{1974}'),
+        ('\u{1980}', '\u{19ab}'),
+        ('\u{19b0}', '\u{19c9}'),
+        ('\u{19d0}', '\u{19da}'),
+        ('\u{19de}', '\u{1a1b}'),
+        ('\u{1a1e}', '\u{1a5e}'),
+        ('\u{1a60}', '\u{1a7c}'),
+        ('\u{1a7f}', '\u{1a89}'),
+        ('\u{1a90}', '\u{1a99}'),
+        ('\u{1aa0}', '\u{1aad}'),
+        ('\u{1ab0}', '\u{1ace}'),
+        ('\u{1b00}', '\u{1b4c}'),
+        ('\u{1b50}', '\u{1b7e}'),
+        ('\u{1b80}', '\u{1bf3}'),
+        ('\u{1bfc}', '\u{1c37}'),
+        ('\u{1c3b}', '\u{1c49}'),
+        ('\u{1c4d}', '\u{1c88}'),
+        ('\u{1c90}', '\u{1cba}'),
+        ('\u{1cbd}', '\u{1cc7}'),
+        ('\u{1cd0}', '\u{1cfa}'),
+        ('\u{1d00}', '\u{1f15}'),
+        ('\u{1f18}', '\u{1f1d}'),
+        ('\u{1f20}', '\u{1f45}'),
+        ('\u{1f48}', '\u{1f4d}'),
+        ('\u{1f50}', '\u{1f57}'),
+        ('\u{1f59}', '\u{1f59}'),
+        ('\u{1f5b}', '\u{1f5b}'),
+        ('\u{1f5d}', '\u{1f5d}'),
+        ('\u{1f5f}', '\u{1f7d}'),
+        ('\u{1f80}', '\u{1fb4}'),
+        ('\u{1fb6}', '\u{1fc4}'),
+        ('\u{1fc6}', '\u{1fd3}'),
+        ('\u{1fd6}', '\u{1fdb}'),
+        ('\u{1fdd}', '\u{1fef}'),
+        ('\u{1ff2}', '\u{1ff4}'),
+        ('\u{1ff6}', '\u{1ffe}'),
+        ('\u{2000}', '\u{2064}'),
+        ('\u{2066}', '\u{2071}'),
+        ('\u{2074}', '\u{208e}'),
+        ('\u{2090}', '\u{209c}'),
+        ('\u{20a0}', '\u{20c0}'),
+        ('\u{20d0}', '\u{20f0}'),
+        ('\u{2100}', '\u{218b}'),
+        ('\u{2190}', '\u{2426}'),
+        ('\u{2440}', '\u{244a}'),
+        ('\u{2460}', '\u{2b73}'),
+        ('\u{2b76}', '\u{2b95}'),
+        ('\u{2b97}', '\u{2cf3}'),
+        ('\u{2cf9}', '\u{2d25}'),
+        ('\u{2d27}', '\u{2d27}'),
+        ('\u{2d2d}', '\u{2d2d}'),
+        ('\u{2d30}', '\u{2d67}'),
+        ('\u{2d6f}', '\u{2d70}'),
+        ('\u{2d7f}', '\u{2d96}'),
+        ('\u{2da0}', '\u{2da6}'),
+        ('\u{2da8}', '\u{2dae}'),
+        ('\u{2db0}', '\u{2db6}'),
+        ('\u{2db8}', '\u{2dbe}'),
+        ('\u{2dc0}', '\u{2dc6}'),
+        ('\u{2dc8}', '\u{2dce}'),
+        ('\u{2dd0}', '\u{2dd6}'),
+        ('\u{2dd8}', '\u{2dde}'),
+        ('\u{2de0}', '\u{2e5d}'),
+        ('\u{2e80}', '\u{2e99}'),
+        ('\u{2e9b}', '\u{2ef3}'),
+        ('\u{2f00}', '\u{2fd5}'),
+        ('\u{2ff0}', '\u{303f}'),
+        ('\u{3041}', '\u{3096}'),
+        ('\u{3099}', '\u{30ff}'),
+        ('\u{3105}', '\u{312f}'),
+        ('\u{3131}', '\u{318e}'),
+        ('\u{3190}', '\u{31e3}'),
+        ('\u{31ef}', '\u{321e}'),
+        ('\u{3220}', '\u{a48c}'),
+        ('\u{a490}', '\u{a4c6}'),
+        ('\u{a4d0}', '\u{a62b}'),
+        ('\u{a640}', '\u{a6f7}'),
+        ('\u{a700}', '\u{a7ca}'),
+        ('\u{a7d0}', '\u{a7d1}'),
+        ('\u{a7d3}', '\u{a7d3}'),
+        ('\u{a7d5}', '\u{a7d9}'),
+        ('\u{a7f2}', '\u{a82c}'),
+        ('\u{a830}', '\u{a839}'),
+        ('\u{a840}', '\u{a877}'),
+        ('\u{a880}', '\u{a8c5}'),
+        ('\u{a8ce}', '\u{a8d9}'),
+        ('\u{a8e0}', '\u{a953}'),
+        ('\u{a95f}', '\u{a97c}'),
+        ('\u{a980}', '\u{a9cd}'),
+        ('\u{a9cf}', '\u{a9d9}'),
+        ('\u{a9de}', '\u{a9fe}'),
+        ('\u{aa00}', '\u{aa36}'),
+        ('\u{aa40}', '\u{aa4d}'),
+        ('\u{aa50}', '\u{aa59}'),
+        ('\u{aa5c}', '\u{aac2}'),
+        ('\u{aadb}', '\u{aaf6}'),
+        ('\u{ab01}', '\u{ab06}'),
+        ('\u{ab09}', '\u{ab0e}'),
+        ('\u{ab11}', '\u{ab16}'),
+        ('\u{ab20}', '\u{ab26}'),
+        ('\u{ab28}', '\u{ab2e}'),
+        ('\u{ab30}', '\u{ab6b}'),
+        ('\u{ab70}', '\u{abed}'),
+        ('\u{abf0}', '\u{abf9}'),
+        ('\u{ac00}', '\u{d7a3}'),
+        ('\u{d7b0}', '\u{d7c6}'),
+        ('\u{d7cb}', '\u{d7fb}'),
+        ('\u{e000}', '\u{fa6d}'),
+        ('\u{fa70}', '\u{fad9}'),
+        ('\u{fb00}', '\u{fb06}'),
+        ('\u{fb13}', '\u{fb17}'),
+        ('\u{fb1d}', '\u{fb36}'),
+        ('\u{fb38}', '\u{fb3c}'),
+        ('\u{fb3e}', '\u{fb3e}'),
+        ('\u{fb40}', '\u{fb41}'),
+        ('\u{fb43}', '\u{fb44}'),
+        ('\u{fb46}', '\u{fbc2}'),
+        ('\u{fbd3}', '\u{fd8f}'),
+        ('\u{fd92}', '\u{fdc7}'),
+        ('\u{fdcf}', '\u{fdcf}'),
+        ('\u{fdf0}', '\u{fe19}'),
+        ('\u{fe20}', '\u{fe52}'),
+        ('\u{fe54}', '\u{fe66}'),
+        ('\u{fe68}', '\u{fe6b}'),
+        ('\u{fe70}', '\u{fe74}'),
+        ('\u{fe76}', '\u{fefc}'),
+        ('\u{feff}', '\u{feff}'),
+        ('\u{ff01}', '\u{ffbe}'),
+        ('\u{ffc2}', '\u{ffc7}'),
+        ('\u{ffca}', '\u{ffcf}'),
+        ('\u{ffd2}', '\u{ffd7}'),
+        ('\u{ffda}', '\u{ffdc}'),
+        ('\u{ffe0}', '\u{ffe6}'),
+        ('\u{ffe8}', '\u{ffee}'),
+        ('\u{fff9}', '\u{fffd}'),
+        ('\u{10000}', '\u{1000b}'),
+        ('\u{1000d}', '\u{10026}'),
+        ('\u{10028}', '\u{1003a}'),
+        ('\u{1003c}', '\u{1003d}'),
+        ('\u{1003f}', '\u{1004d}'),
+        ('\u{10050}', '\u{1005d}'),
+        ('\u{10080}', '\u{100fa}'),
+        ('\u{10100}', '\u{10102}'),
+        ('\u{10107}', '\u{10133}'),
+        ('\u{10137}', '\u{1018e}'),
+        ('\u{10190}', '\u{1019c}'),
+        ('\u{101a0}', '\u{101a0}'),
+        ('\u{101d0}', '\u{101fd}'),
+        ('\u{10280}', '\u{1029c}'),
+        ('\u{102a0}', '\u{102d0}'),
+        ('\u{102e0}', '\u{102fb}'),
+        ('\u{10300}', '\u{10323}'),
+        ('\u{1032d}', '\u{1034a}'),
+        ('\u{10350}', '\u{1037a}'),
+        ('\u{10380}', '\u{1039d}'),
+        ('\u{1039f}', '\u{103c3}'),
+        ('\u{103c8}', '\u{103d5}'),
+        ('\u{10400}', '\u{1049d}'),
+        ('\u{104a0}', '\u{104a9}'),
+        ('\u{104b0}', '\u{104d3}'),
+        ('\u{104d8}', '\u{104fb}'),
+        ('\u{10500}', '\u{10527}'),
+        ('\u{10530}', '\u{10563}'),
+        ('\u{1056f}', '\u{1057a}'),
+        ('\u{1057c}', '\u{1058a}'),
+        ('\u{1058c}', '\u{10592}'),
+        ('\u{10594}', '\u{10595}'),
+        ('\u{10597}', '\u{105a1}'),
+        ('\u{105a3}', '\u{105b1}'),
+        ('\u{105b3}', '\u{105b9}'),
+        ('\u{105bb}', '\u{105bc}'),
+        ('\u{10600}', '\u{10736}'),
+        ('\u{10740}', '\u{10755}'),
+        ('\u{10760}', '\u{10767}'),
+        ('\u{10780}', '\u{10785}'),
+        ('\u{10787}', '\u{107b0}'),
+        ('\u{107b2}', '\u{107ba}'),
+        ('\u{10800}', '\u{10805}'),
+        ('\u{10808}', '\u{10808}'),
+        ('\u{1080a}', '\u{10835}'),
+        ('\u{10837}', '\u{10838}'),
+        ('\u{1083c}', '\u{1083c}'),
+        ('\u{1083f}', '\u{10855}'),
+        ('\u{10857}', '\u{1089e}'),
+        ('\u{108a7}', '\u{108af}'),
+        ('\u{108e0}', '\u{108f2}'),
+        ('\u{108f4}', '\u{108f5}'),
+        ('\u{108fb}', '\u{1091b}'),
+        ('\u{1091f}', '\u{10939}'),
+        ('\u{1093f}', '\u{1093f}'),
+        ('\u{10980}', '\u{109b7}'),
+        ('\u{109bc}', '\u{109cf}'),
+        ('\u{109d2}', '\u{10a03}'),
+        ('\u{10a05}', '\u{10a06}'),
+        ('\u{10a0c}', '\u{10a13}'),
+        ('\u{10a15}', '\u{10a17}'),
+        ('\u{10a19}', '\u{10a35}'),
+        ('\u{10a38}', '\u{10a3a}'),
+        ('\u{10a3f}', '\u{10a48}'),
+        ('\u{10a50}', '\u{10a58}'),
+        ('\u{10a60}', '\u{10a9f}'),
+        ('\u{10ac0}', '\u{10ae6}'),
+        ('\u{10aeb}', '\u{10af6}'),
+        ('\u{10b00}', '\u{10b35}'),
+        ('\u{10b39}', '\u{10b55}'),
+        ('\u{10b58}', '\u{10b72}'),
+        ('\u{10b78}', '\u{10b91}'),
+        ('\u{10b99}', '\u{10b9c}'),
+        ('\u{10ba9}', '\u{10baf}'),
+        ('\u{10c00}', '\u{10c48}'),
+        ('\u{10c80}', '\u{10cb2}'),
+        ('\u{10cc0}', '\u{10cf2}'),
+        ('\u{10cfa}', '\u{10d27}'),
+        ('\u{10d30}', '\u{10d39}'),
+        ('\u{10e60}', '\u{10e7e}'),
+        ('\u{10e80}', '\u{10ea9}'),
+        ('\u{10eab}', '\u{10ead}'),
+        ('\u{10eb0}', '\u{10eb1}'),
+        ('\u{10efd}', '\u{10f27}'),
+        ('\u{10f30}', '\u{10f59}'),
+        ('\u{10f70}', '\u{10f89}'),
+        ('\u{10fb0}', '\u{10fcb}'),
+        ('\u{10fe0}', '\u{10ff6}'),
+        ('\u{11000}', '\u{1104d}'),
+        ('\u{11052}', '\u{11075}'),
+        ('\u{1107f}', '\u{110c2}'),
+        ('\u{110cd}', '\u{110cd}'),
+        ('\u{110d0}', '\u{110e8}'),
+        ('\u{110f0}', '\u{110f9}'),
+        ('\u{11100}', '\u{11134}'),
+        ('\u{11136}', '\u{11147}'),
+        ('\u{11150}', '\u{11176}'),
+        ('\u{11180}', '\u{111df}'),
+        ('\u{111e1}', '\u{111f4}'),
+        ('\u{11200}', '\u{11211}'),
+        ('\u{11213}', '\u{11241}'),
+        ('\u{11280}', '\u{11286}'),
+        ('\u{11288}', '\u{11288}'),
+        ('\u{1128a}', '\u{1128d}'),
+        ('\u{1128f}', '\u{1129d}'),
+        ('\u{1129f}', '\u{112a9}'),
+        ('\u{112b0}', '\u{112ea}'),
+        ('\u{112f0}', '\u{112f9}'),
+        ('\u{11300}', '\u{11303}'),
+        ('\u{11305}', '\u{1130c}'),
+        ('\u{1130f}', '\u{11310}'),
+        ('\u{11313}', '\u{11328}'),
+        ('\u{1132a}', '\u{11330}'),
+        ('\u{11332}', '\u{11333}'),
+        ('\u{11335}', '\u{11339}'),
+        ('\u{1133b}', '\u{11344}'),
+        ('\u{11347}', '\u{11348}'),
+        ('\u{1134b}', '\u{1134d}'),
+        ('\u{11350}', '\u{11350}'),
+        ('\u{11357}', '\u{11357}'),
+        ('\u{1135d}', '\u{11363}'),
+        ('\u{11366}', '\u{1136c}'),
+        ('\u{11370}', '\u{11374}'),
+        ('\u{11400}', '\u{1145b}'),
+        ('\u{1145d}', '\u{11461}'),
+        ('\u{11480}', '\u{114c7}'),
+        ('\u{114d0}', '\u{114d9}'),
+        ('\u{11580}', '\u{115b5}'),
+        ('\u{115b8}', '\u{115dd}'),
+        ('\u{11600}', '\u{11644}'),
+        ('\u{11650}', '\u{11659}'),
+        ('\u{11660}', '\u{1166c}'),
+        ('\u{11680}', '\u{116b9}'),
+        ('\u{116c0}', '\u{116c9}'),
+        ('\u{11700}', '\u{1171a}'),
+        ('\u{1171d}', '\u{1172b}'),
+        ('\u{11730}', '\u{11746}'),
+        ('\u{11800}', '\u{1183b}'),
+        ('\u{118a0}', '\u{118f2}'),
+        ('\u{118ff}', '\u{11906}'),
+        ('\u{11909}', '\u{11909}'),
+        ('\u{1190c}', '\u{11913}'),
+        ('\u{11915}', '\u{11916}'),
+        ('\u{11918}', '\u{11935}'),
+        ('\u{11937}', '\u{11938}'),
+        ('\u{1193b}', '\u{11946}'),
+        ('\u{11950}', '\u{11959}'),
+        ('\u{119a0}', '\u{119a7}'),
+        ('\u{119aa}', '\u{119d7}'),
+        ('\u{119da}', '\u{119e4}'),
+        ('\u{11a00}', '\u{11a47}'),
+        ('\u{11a50}', '\u{11aa2}'),
+        ('\u{11ab0}', '\u{11af8}'),
+        ('\u{11b00}', '\u{11b09}'),
+        ('\u{11c00}', '\u{11c08}'),
+        ('\u{11c0a}', '\u{11c36}'),
+        ('\u{11c38}', '\u{11c45}'),
+        ('\u{11c50}', '\u{11c6c}'),
+        ('\u{11c70}', '\u{11c8f}'),
+        ('\u{11c92}', '\u{11ca7}'),
+        ('\u{11ca9}', '\u{11cb6}'),
+        ('\u{11d00}', '\u{11d06}'),
+        ('\u{11d08}', '\u{11d09}'),
+        ('\u{11d0b}', '\u{11d36}'),
+        ('\u{11d3a}', '\u{11d3a}'),
+        ('\u{11d3c}', '\u{11d3d}'),
+        ('\u{11d3f}', '\u{11d47}'),
+        ('\u{11d50}', '\u{11d59}'),
+        ('\u{11d60}', '\u{11d65}'),
+        ('\u{11d67}', '\u{11d68}'),
+        ('\u{11d6a}', '\u{11d8e}'),
+        ('\u{11d90}', '\u{11d91}'),
+        ('\u{11d93}', '\u{11d98}'),
+        ('\u{11da0}', '\u{11da9}'),
+        ('\u{11ee0}', '\u{11ef8}'),
+        ('\u{11f00}', '\u{11f10}'),
+        ('\u{11f12}', '\u{11f3a}'),
+        ('\u{11f3e}', '\u{11f59}'),
+        ('\u{11fb0}', '\u{11fb0}'),
+        ('\u{11fc0}', '\u{11ff1}'),
+        ('\u{11fff}', '\u{12399}'),
+        ('\u{12400}', '\u{1246e}'),
+        ('\u{12470}', '\u{12474}'),
+        ('\u{12480}', '\u{12543}'),
+        ('\u{12f90}', '\u{12ff2}'),
+        ('\u{13000}', '\u{13455}'),
+        ('\u{14400}', '\u{14646}'),
+        ('\u{16800}', '\u{16a38}'),
+        ('\u{16a40}', '\u{16a5e}'),
+        ('\u{16a60}', '\u{16a69}'),
+        ('\u{16a6e}', '\u{16abe}'),
+        ('\u{16ac0}', '\u{16ac9}'),
+        ('\u{16ad0}', '\u{16aed}'),
+        ('\u{16af0}', '\u{16af5}'),
+        ('\u{16b00}', '\u{16b45}'),
+        ('\u{16b50}', '\u{16b59}'),
+        ('\u{16b5b}', '\u{16b61}'),
+        ('\u{16b63}', '\u{16b77}'),
+        ('\u{16b7d}', '\u{16b8f}'),
+        ('\u{16e40}', '\u{16e9a}'),
+        ('\u{16f00}', '\u{16f4a}'),
+        ('\u{16f4f}', '\u{16f87}'),
+        ('\u{16f8f}', '\u{16f9f}'),
+        ('\u{16fe0}', '\u{16fe4}'),
+        ('\u{16ff0}', '\u{16ff1}'),
+        ('\u{17000}', '\u{187f7}'),
+        ('\u{18800}', '\u{18cd5}'),
+        ('\u{18d00}', '\u{18d08}'),
+        ('\u{1aff0}', '\u{1aff3}'),
+        ('\u{1aff5}', '\u{1affb}'),
+        ('\u{1affd}', '\u{1affe}'),
+        ('\u{1b000}', '\u{1b122}'),
+        ('\u{1b132}', '\u{1b132}'),
+        ('\u{1b150}', '\u{1b152}'),
+        ('\u{1b155}', '\u{1b155}'),
+        ('\u{1b164}', '\u{1b167}'),
+        ('\u{1b170}', '\u{1b2fb}'),
+        ('\u{1bc00}', '\u{1bc6a}'),
+        ('\u{1bc70}', '\u{1bc7c}'),
+        ('\u{1bc80}', '\u{1bc88}'),
+        ('\u{1bc90}', '\u{1bc99}'),
+        ('\u{1bc9c}', '\u{1bca3}'),
+        ('\u{1cf00}', '\u{1cf2d}'),
+        ('\u{1cf30}', '\u{1cf46}'),
+        ('\u{1cf50}', '\u{1cfc3}'),
+        ('\u{1d000}', '\u{1d0f5}'),
+        ('\u{1d100}', '\u{1d126}'),
+        ('\u{1d129}', '\u{1d1ea}'),
+        ('\u{1d200}', '\u{1d245}'),
+        ('\u{1d2c0}', '\u{1d2d3}'),
+        ('\u{1d2e0}', '\u{1d2f3}'),
+        ('\u{1d300}', '\u{1d356}'),
+        ('\u{1d360}', '\u{1d378}'),
+        ('\u{1d400}', '\u{1d454}'),
+        ('\u{1d456}', '\u{1d49c}'),
+        ('\u{1d49e}', '\u{1d49f}'),
+        ('\u{1d4a2}', '\u{1d4a2}'),
+        ('\u{1d4a5}', '\u{1d4a6}'),
+        ('\u{1d4a9}', '\u{1d4ac}'),
+        ('\u{1d4ae}', '\u{1d4b9}'),
+        ('\u{1d4bb}', '\u{1d4bb}'),
+        ('\u{1d4bd}', '\u{1d4c3}'),
+        ('\u{1d4c5}', '\u{1d505}'),
+        ('\u{1d507}', '\u{1d50a}'),
+        ('\u{1d50d}', '\u{1d514}'),
+        ('\u{1d516}', '\u{1d51c}'),
+        ('\u{1d51e}', '\u{1d539}'),
+        ('\u{1d53b}', '\u{1d53e}'),
+        ('\u{1d540}', '\u{1d544}'),
+        ('\u{1d546}', '\u{1d546}'),
+        ('\u{1d54a}', '\u{1d550}'),
+        ('\u{1d552}', '\u{1d6a5}'),
+        ('\u{1d6a8}', '\u{1d7cb}'),
+        ('\u{1d7ce}', '\u{1da8b}'),
+        ('\u{1da9b}', '\u{1da9f}'),
+        ('\u{1daa1}', '\u{1daaf}'),
+        ('\u{1df00}', '\u{1df1e}'),
+        ('\u{1df25}', '\u{1df2a}'),
+        ('\u{1e000}', '\u{1e006}'),
+        ('\u{1e008}', '\u{1e018}'),
+        ('\u{1e01b}', '\u{1e021}'),
+        ('\u{1e023}', '\u{1e024}'),
+        ('\u{1e026}', '\u{1e02a}'),
+        ('\u{1e030}', '\u{1e06d}'),
+        ('\u{1e08f}', '\u{1e08f}'),
+        ('\u{1e100}', '\u{1e12c}'),
+        ('\u{1e130}', '\u{1e13d}'),
+        ('\u{1e140}', '\u{1e149}'),
+        ('\u{1e14e}', '\u{1e14f}'),
+        ('\u{1e290}', '\u{1e2ae}'),
+        ('\u{1e2c0}', '\u{1e2f9}'),
+        ('\u{1e2ff}', '\u{1e2ff}'),
+        ('\u{1e4d0}', '\u{1e4f9}'),
+        ('\u{1e7e0}', '\u{1e7e6}'),
+        ('\u{1e7e8}', '\u{1e7eb}'),
+        ('\u{1e7ed}', '\u{1e7ee}'),
+        ('\u{1e7f0}', '\u{1e7fe}'),
+        ('\u{1e800}', '\u{1e8c4}'),
+        ('\u{1e8c7}', '\u{1e8d6}'),
+        ('\u{1e900}', '\u{1e94b}'),
+        ('\u{1e950}', '\u{1e959}'),
+        ('\u{1e95e}', '\u{1e95f}'),
+        ('\u{1ec71}', '\u{1ecb4}'),
+        ('\u{1ed01}', '\u{1ed3d}'),
+        ('\u{1ee00}', '\u{1ee03}'),
+        ('\u{1ee05}', '\u{1ee1f}'),
+        ('\u{1ee21}', '\u{1ee22}'),
+        ('\u{1ee24}', '\u{1ee24}'),
+        ('\u{1ee27}', '\u{1ee27}'),
+        ('\u{1ee29}', '\u{1ee32}'),
+        ('\u{1ee34}', '\u{1ee37}'),
+        ('\u{1ee39}', '\u{1ee39}'),
+        ('\u{1ee3b}', '\u{1ee3b}'),
+        ('\u{1ee42}', '\u{1ee42}'),
+        ('\u{1ee47}', '\u{1ee47}'),
+        ('\u{1ee49}', '\u{1ee49}'),
+        ('\u{1ee4b}', '\u{1ee4b}'),
+        ('\u{1ee4d}', '\u{1ee4f}'),
+        ('\u{1ee51}', '\u{1ee52}'),
+        ('\u{1ee54}', '\u{1ee54}'),
+        ('\u{1ee57}', '\u{1ee57}'),
+        ('\u{1ee59}', '\u{1ee59}'),
+        ('\u{1ee5b}', '\u{1ee5b}'),
+        ('\u{1ee5d}', '\u{1ee5d}'),
+        ('\u{1ee5f}', '\u{1ee5f}'),
+        ('\u{1ee61}', '\u{1ee62}'),
+        ('\u{1ee64}', '\u{1ee64}'),
+        ('\u{1ee67}', '\u{1ee6a}'),
+        ('\u{1ee6c}', '\u{1ee72}'),
+        ('\u{1ee74}', '\u{1ee77}'),
+        ('\u{1ee79}', '\u{1ee7c}'),
+        ('\u{1ee7e}', '\u{1ee7e}'),
+        ('\u{1ee80}', '\u{1ee89}'),
+        ('\u{1ee8b}', '\u{1ee9b}'),
+        ('\u{1eea1}', '\u{1eea3}'),
+        ('\u{1eea5}', '\u{1eea9}'),
+        ('\u{1eeab}', '\u{1eebb}'),
+        ('\u{1eef0}', '\u{1eef1}'),
+        ('\u{1f000}', '\u{1f02b}'),
+        ('\u{1f030}', '\u{1f093}'),
+        ('\u{1f0a0}', '\u{1f0ae}'),
+        ('\u{1f0b1}', '\u{1f0bf}'),
+        ('\u{1f0c1}', '\u{1f0cf}'),
+        ('\u{1f0d1}', '\u{1f0f5}'),
+        ('\u{1f100}', '\u{1f1ad}'),
+        ('\u{1f1e6}', '\u{1f202}'),
+        ('\u{1f210}', '\u{1f23b}'),
+        ('\u{1f240}', '\u{1f248}'),
+        ('\u{1f250}', '\u{1f251}'),
+        ('\u{1f260}', '\u{1f265}'),
+        ('\u{1f300}', '\u{1f6d7}'),
+        ('\u{1f6dc}', '\u{1f6ec}'),
+        ('\u{1f6f0}', '\u{1f6fc}'),
+        ('\u{1f700}', '\u{1f776}'),
+        ('\u{1f77b}', '\u{1f7d9}'),
+        ('\u{1f7e0}', '\u{1f7eb}'),
+        ('\u{1f7f0}', '\u{1f7f0}'),
+        ('\u{1f800}', '\u{1f80b}'),
+        ('\u{1f810}', '\u{1f847}'),
+        ('\u{1f850}', '\u{1f859}'),
+        ('\u{1f860}', '\u{1f887}'),
+        ('\u{1f890}', '\u{1f8ad}'),
+        ('\u{1f8b0}', '\u{1f8b1}'),
+        ('\u{1f900}', '\u{1fa53}'),
+        ('\u{1fa60}', '\u{1fa6d}'),
+        ('\u{1fa70}', '\u{1fa7c}'),
+        ('\u{1fa80}', '\u{1fa88}'),
+        ('\u{1fa90}', '\u{1fabd}'),
+        ('\u{1fabf}', '\u{1fac5}'),
+        ('\u{1face}', '\u{1fadb}'),
+        ('\u{1fae0}', '\u{1fae8}'),
+        ('\u{1faf0}', '\u{1faf8}'),
+        ('\u{1fb00}', '\u{1fb92}'),
+        ('\u{1fb94}', '\u{1fbca}'),
+        ('\u{1fbf0}', '\u{1fbf9}'),
+        ('\u{20000}', '\u{2a6df}'),
+        ('\u{2a700}', '\u{2b739}'),
+        ('\u{2b740}', '\u{2b81d}'),
+        ('\u{2b820}', '\u{2cea1}'),
+        ('\u{2ceb0}', '\u{2ebe0}'),
+        ('\u{2ebf0}', '\u{2ee5d}'),
+        ('\u{2f800}', '\u{2fa1d}'),
+        ('\u{30000}', '\u{3134a}'),
+        ('\u{31350}', '\u{323af}'),
+        ('\u{e0001}', '\u{e0001}'),
+        ('\u{e0020}', '\u{e007f}'),
+        ('\u{e0100}', '\u{e01ef}'),
+        ('\u{f0000}', '\u{ffffd}'),
+        ('\u{100000}', '\u{10fffd}'),
+    ];
+
+    /// Autogenerated. 17 sorted, non-overlapping range(s).
+    static DEFAULT_IGNORABLE_TABLE: [(char, char); 17] = [
+        ('\u{ad}', '\u{ad}'),
+        ('\u{34f}', '\u{34f}'),
+        ('\u{61c}', '\u{61c}'),
+        ('\u{115f}', '\u{1160}'),
+        ('\u{17b4}', '\u{17b5}'),
+        ('\u{180b}', '\u{180f}'),
+        ('\u{200b}', '\u{200f}'),
+        ('\u{202a}', '\u{202e}'),
+        ('\u{2060}', '\u{206f}'),
+        ('\u{3164}', '\u{3164}'),
+        ('\u{fe00}', '\u{fe0f}'),
+        ('\u{feff}', '\u{feff}'),
+        ('\u{ffa0}', '\u{ffa0}'),
+        ('\u{fff0}', '\u{fff8}'),
+        ('\u{1bca0}', '\u{1bca3}'),
+        ('\u{1d173}', '\u{1d17a}'),
+        ('\u{e0000}', '\u{e0fff}'),
+    ];
+
+    /// Autogenerated. 151 sorted, non-overlapping range(s).
+    static EMOJI_TABLE: [(char, char); 151] = [
+        ('\u{23}', '\u{23}'),
+        ('\u{2a}', '\u{2a}'),
+        ('\u{30}', '\u{39}'),
+        ('\u{a9}', '\u{a9}'),
+        ('\u{ae}', '\u{ae}'),
+        ('\u{203c}', '\u{203c}'),
+        ('\u{2049}', '\u{2049}'),
+        ('\u{2122}', '\u{2122}'),
+        ('\u{2139}', '\u{2139}'),
+        ('\u{2194}', '\u{2199}'),
+        ('\u{21a9}', '\u{21aa}'),
+        ('\u{231a}', '\u{231b}'),
+        ('\u{2328}', '\u{2328}'),
+        ('\u{23cf}', '\u{23cf}'),
+        ('\u{23e9}', '\u{23f3}'),
+        ('\u{23f8}', '\u{23fa}'),
+        ('\u{24c2}', '\u{24c2}'),
+        ('\u{25aa}', '\u{25ab}'),
+        ('\u{25b6}', '\u{25b6}'),
+        ('\u{25c0}', '\u{25c0}'),
+        ('\u{25fb}', '\u{25fe}'),
+        ('\u{2600}', '\u{2604}'),
+        ('\u{260e}', '\u{260e}'),
+        ('\u{2611}', '\u{2611}'),
+        ('\u{2614}', '\u{2615}'),
+        ('\u{2618}', '\u{2618}'),
+        ('\u{261d}', '\u{261d}'),
+        ('\u{2620}', '\u{2620}'),
+        ('\u{2622}', '\u{2623}'),
+        ('\u{2626}', '\u{2626}'),
+        ('\u{262a}', '\u{262a}'),
+        ('\u{262e}', '\u{262f}'),
+        ('\u{2638}', '\u{263a}'),
+        ('\u{2640}', '\u{2640}'),
+        ('\u{2642}', '\u{2642}'),
+        ('\u{2648}', '\u{2653}'),
+        ('\u{265f}', '\u{2660}'),
+        ('\u{2663}', '\u{2663}'),
+        ('\u{2665}', '\u{2666}'),
+        ('\u{2668}', '\u{2668}'),
+        ('\u{267b}', '\u{267b}'),
+        ('\u{267e}', '\u{267f}'),
+        ('\u{2692}', '\u{2697}'),
+        ('\u{2699}', '\u{2699}'),
+        ('\u{269b}', '\u{269c}'),
+        ('\u{26a0}', '\u{26a1}'),
+        ('\u{26a7}', '\u{26a7}'),
+        ('\u{26aa}', '\u{26ab}'),
+        ('\u{26b0}', '\u{26b1}'),
+        ('\u{26bd}', '\u{26be}'),
+        ('\u{26c4}', '\u{26c5}'),
+        ('\u{26c8}', '\u{26c8}'),
+        ('\u{26ce}', '\u{26cf}'),
+        ('\u{26d1}', '\u{26d1}'),
+        ('\u{26d3}', '\u{26d4}'),
+        ('\u{26e9}', '\u{26ea}'),
+        ('\u{26f0}', '\u{26f5}'),
+        ('\u{26f7}', '\u{26fa}'),
+        ('\u{26fd}', '\u{26fd}'),
+        ('\u{2702}', '\u{2702}'),
+        ('\u{2705}', '\u{2705}'),
+        ('\u{2708}', '\u{270d}'),
+        ('\u{270f}', '\u{270f}'),
+        ('\u{2712}', '\u{2712}'),
+        ('\u{2714}', '\u{2714}'),
+        ('\u{2716}', '\u{2716}'),
+        ('\u{271d}', '\u{271d}'),
+        ('\u{2721}', '\u{2721}'),
+        ('\u{2728}', '\u{2728}'),
+        ('\u{2733}', '\u{2734}'),
+        ('\u{2744}', '\u{2744}'),
+        ('\u{2747}', '\u{2747}'),
+        ('\u{274c}', '\u{274c}'),
+        ('\u{274e}', '\u{274e}'),
+        ('\u{2753}', '\u{2755}'),
+        ('\u{2757}', '\u{2757}'),
+        ('\u{2763}', '\u{2764}'),
+        ('\u{2795}', '\u{2797}'),
+        ('\u{27a1}', '\u{27a1}'),
+        ('\u{27b0}', '\u{27b0}'),
+        ('\u{27bf}', '\u{27bf}'),
+        ('\u{2934}', '\u{2935}'),
+        ('\u{2b05}', '\u{2b07}'),
+        ('\u{2b1b}', '\u{2b1c}'),
+        ('\u{2b50}', '\u{2b50}'),
+        ('\u{2b55}', '\u{2b55}'),
+        ('\u{3030}', '\u{3030}'),
+        ('\u{303d}', '\u{303d}'),
+        ('\u{3297}', '\u{3297}'),
+        ('\u{3299}', '\u{3299}'),
+        ('\u{1f004}', '\u{1f004}'),
+        ('\u{1f0cf}', '\u{1f0cf}'),
+        ('\u{1f170}', '\u{1f171}'),
+        ('\u{1f17e}', '\u{1f17f}'),
+        ('\u{1f18e}', '\u{1f18e}'),
+        ('\u{1f191}', '\u{1f19a}'),
+        ('\u{1f1e6}', '\u{1f1ff}'),
+        ('\u{1f201}', '\u{1f202}'),
+        ('\u{1f21a}', '\u{1f21a}'),
+        ('\u{1f22f}', '\u{1f22f}'),
+        ('\u{1f232}', '\u{1f23a}'),
+        ('\u{1f250}', '\u{1f251}'),
+        ('\u{1f300}', '\u{1f321}'),
+        ('\u{1f324}', '\u{1f393}'),
+        ('\u{1f396}', '\u{1f397}'),
+        ('\u{1f399}', '\u{1f39b}'),
+        ('\u{1f39e}', '\u{1f3f0}'),
+        ('\u{1f3f3}', '\u{1f3f5}'),
+        ('\u{1f3f7}', '\u{1f4fd}'),
+        ('\u{1f4ff}', '\u{1f53d}'),
+        ('\u{1f549}', '\u{1f54e}'),
+        ('\u{1f550}', '\u{1f567}'),
+        ('\u{1f56f}', '\u{1f570}'),
+        ('\u{1f573}', '\u{1f57a}'),
+        ('\u{1f587}', '\u{1f587}'),
+        ('\u{1f58a}', '\u{1f58d}'),
+        ('\u{1f590}', '\u{1f590}'),
+        ('\u{1f595}', '\u{1f596}'),
+        ('\u{1f5a4}', '\u{1f5a5}'),
+        ('\u{1f5a8}', '\u{1f5a8}'),
+        ('\u{1f5b1}', '\u{1f5b2}'),
+        ('\u{1f5bc}', '\u{1f5bc}'),
+        ('\u{1f5c2}', '\u{1f5c4}'),
+        ('\u{1f5d1}', '\u{1f5d3}'),
+        ('\u{1f5dc}', '\u{1f5de}'),
+        ('\u{1f5e1}', '\u{1f5e1}'),
+        ('\u{1f5e3}', '\u{1f5e3}'),
+        ('\u{1f5e8}', '\u{1f5e8}'),
+        ('\u{1f5ef}', '\u{1f5ef}'),
+        ('\u{1f5f3}', '\u{1f5f3}'),
+        ('\u{1f5fa}', '\u{1f64f}'),
+        ('\u{1f680}', '\u{1f6c5}'),
+        ('\u{1f6cb}', '\u{1f6d2}'),
+        ('\u{1f6d5}', '\u{1f6d7}'),
+        ('\u{1f6dc}', '\u{1f6e5}'),
+        ('\u{1f6e9}', '\u{1f6e9}'),
+        ('\u{1f6eb}', '\u{1f6ec}'),
+        ('\u{1f6f0}', '\u{1f6f0}'),
+        ('\u{1f6f3}', '\u{1f6fc}'),
+        ('\u{1f7e0}', '\u{1f7eb}'),
+        ('\u{1f7f0}', '\u{1f7f0}'),
+        ('\u{1f90c}', '\u{1f93a}'),
+        ('\u{1f93c}', '\u{1f945}'),
+        ('\u{1f947}', '\u{1f9ff}'),
+        ('\u{1fa70}', '\u{1fa7c}'),
+        ('\u{1fa80}', '\u{1fa88}'),
+        ('\u{1fa90}', '\u{1fabd}'),
+        ('\u{1fabf}', '\u{1fac5}'),
+        ('\u{1face}', '\u{1fadb}'),
+        ('\u{1fae0}', '\u{1fae8}'),
+        ('\u{1faf0}', '\u{1faf8}'),
+    ];
+
+    /// Autogenerated. 10 sorted, non-overlapping range(s).
+    #[cfg(feature = "segmentation")]
+    static EMOJI_COMPONENT_TABLE: [(char, char); 10] = [
+        ('\u{23}', '\u{23}'),
+        ('\u{2a}', '\u{2a}'),
+        ('\u{30}', '\u{39}'),
+        ('\u{200d}', '\u{200d}'),
+        ('\u{20e3}', '\u{20e3}'),
+        ('\u{fe0f}', '\u{fe0f}'),
+        ('\u{1f1e6}', '\u{1f1ff}'),
+        ('\u{1f3fb}', '\u{1f3ff}'),
+        ('\u{1f9b0}', '\u{1f9b3}'),
+        ('\u{e0020}', '\u{e007f}'),
+    ];
+
+    /// Autogenerated. 363 sorted, non-overlapping range(s).
+    static GRAPHEME_EXTEND_TABLE: [(char, char); 363] = [
+        ('\u{300}', '\u{36f}'),
+        ('\u{483}', '\u{489}'),
+        ('\u{591}', '\u{5bd}'),
+        ('\u{5bf}', '\u{5bf}'),
+        ('\u{5c1}', '\u{5c2}'),
+        ('\u{5c4}', '\u{5c5}'),
+        ('\u{5c7}', '\u{5c7}'),
+        ('\u{610}', '\u{61a}'),
+        ('\u{64b}', '\u{65f}'),
+        ('\u{670}', '\u{670}'),
+        ('\u{6d6}', '\u{6dc}'),
+        ('\u{6df}', '\u{6e4}'),
+        ('\u{6e7}', '\u{6e8}'),
+        ('\u{6ea}', '\u{6ed}'),
+        ('\u{711}', '\u{711}'),
+        ('\u{730}', '\u{74a}'),
+        ('\u{7a6}', '\u{7b0}'),
+        ('\u{7eb}', '\u{7f3}'),
+        ('\u{7fd}', '\u{7fd}'),
+        ('\u{816}', '\u{819}'),
+        ('\u{81b}', '\u{823}'),
+        ('\u{825}', '\u{827}'),
+        ('\u{829}', '\u{82d}'),
+        ('\u{859}', '\u{85b}'),
+        ('\u{898}', '\u{89f}'),
+        ('\u{8ca}', '\u{8e1}'),
+        ('\u{8e3}', '\u{902}'),
+        ('\u{93a}', '\u{93a}'),
+        ('\u{93c}', '\u{93c}'),
+        ('\u{941}', '\u{948}'),
+        ('\u{94d}', '\u{94d}'),
+        ('\u{951}', '\u{957}'),
+        ('\u{962}', '\u{963}'),
+        ('\u{981}', '\u{981}'),
+        ('\u{9bc}', '\u{9bc}'),
+        ('\u{9be}', '\u{9be}'),
+        ('\u{9c1}', '\u{9c4}'),
+        ('\u{9cd}', '\u{9cd}'),
+        ('\u{9d7}', '\u{9d7}'),
+        ('\u{9e2}', '\u{9e3}'),
+        ('\u{9fe}', '\u{9fe}'),
+        ('\u{a01}', '\u{a02}'),
+        ('\u{a3c}', '\u{a3c}'),
+        ('\u{a41}', '\u{a42}'),
+        ('\u{a47}', '\u{a48}'),
+        ('\u{a4b}', '\u{a4d}'),
+        ('\u{a51}', '\u{a51}'),
+        ('\u{a70}', '\u{a71}'),
+        ('\u{a75}', '\u{a75}'),
+        ('\u{a81}', '\u{a82}'),
+        ('\u{abc}', '\u{abc}'),
+        ('\u{ac1}', '\u{ac5}'),
+        ('\u{ac7}', '\u{ac8}'),
+        ('\u{acd}', '\u{acd}'),
+        ('\u{ae2}', '\u{ae3}'),
+        ('\u{afa}', '\u{aff}'),
+        ('\u{b01}', '\u{b01}'),
+        ('\u{b3c}', '\u{b3c}'),
+        ('\u{b3e}', '\u{b3f}'),
+        ('\u{b41}', '\u{b44}'),
+        ('\u{b4d}', '\u{b4d}'),
+        ('\u{b55}', '\u{b57}'),
+        ('\u{b62}', '\u{b63}'),
+        ('\u{b82}', '\u{b82}'),
+        ('\u{bbe}', '\u{bbe}'),
+        ('\u{bc0}', '\u{bc0}'),
+        ('\u{bcd}', '\u{bcd}'),
+        ('\u{bd7}', '\u{bd7}'),
+        ('\u{c00}', '\u{c00}'),
+        ('\u{c04}', '\u{c04}'),
+        ('\u{c3c}', '\u{c3c}'),
+        ('\u{c3e}', '\u{c40}'),
+        ('\u{c46}', '\u{c48}'),
+        ('\u{c4a}', '\u{c4d}'),
+        ('\u{c55}', '\u{c56}'),
+        ('\u{c62}', '\u{c63}'),
+        ('\u{c81}', '\u{c81}'),
+        ('\u{cbc}', '\u{cbc}'),
+        ('\u{cbf}', '\u{cbf}'),
+        ('\u{cc2}', '\u{cc2}'),
+        ('\u{cc6}', '\u{cc6}'),
+        ('\u{ccc}', '\u{ccd}'),
+        ('\u{cd5}', '\u{cd6}'),
+        ('\u{ce2}', '\u{ce3}'),
+        ('\u{d00}', '\u{d01}'),
+        ('\u{d3b}', '\u{d3c}'),
+        ('\u{d3e}', '\u{d3e}'),
+        ('\u{d41}', '\u{d44}'),
+        ('\u{d4d}', '\u{d4d}'),
+        ('\u{d57}', '\u{d57}'),
+        ('\u{d62}', '\u{d63}'),
+        ('\u{d81}', '\u{d81}'),
+        ('\u{dca}', '\u{dca}'),
+        ('\u{dcf}', '\u{dcf}'),
+        ('\u{dd2}', '\u{dd4}'),
+        ('\u{dd6}', '\u{dd6}'),
+        ('\u{ddf}', '\u{ddf}'),
+        ('\u{e31}', '\u{e31}'),
+        ('\u{e34}', '\u{e3a}'),
+        ('\u{e47}', '\u{e4e}'),
+        ('\u{eb1}', '\u{eb1}'),
+        ('\u{eb4}', '\u{ebc}'),
+        ('\u{ec8}', '\u{ece}'),
+        ('\u{f18}', '\u{f19}'),
+        ('\u{f35}', '\u{f35}'),
+        ('\u{f37}', '\u{f37}'),
+        ('\u{f39}', '\u{f39}'),
+        ('\u{f71}', '\u{f7e}'),
+        ('\u{f80}', '\u{f84}'),
+        ('\u{f86}', '\u{f87}'),
+        ('\u{f8d}', '\u{f97}'),
+        ('\u{f99}', '\u{fbc}'),
+        ('\u{fc6}', '\u{fc6}'),
+        ('\u{102d}', '\u{1030}'),
+        ('\u{1032}', '\u{1037}'),
+        ('\u{1039}', '\u{103a}'),
+        ('\u{103d}', '\u{103e}'),
+        ('\u{1058}', '\u{1059}'),
+        ('\u{105e}', '\u{1060}'),
+        ('\u{1071}', '\u{1074}'),
+        ('\u{1082}', '\u{1082}'),
+        ('\u{1085}', '\u{1086}'),
+        ('\u{108d}', '\u{108d}'),
+        ('\u{109d}', '\u{109d}'),
+        ('\u{135d}', '\u{135f}'),
+        ('\u{1712}', '\u{1714}'),
+        ('\u{1732}', '\u{1733}'),
+        ('\u{1752}', '\u{1753}'),
+        ('\u{1772}', '\u{1773}'),
+        ('\u{17b4}', '\u{17b5}'),
+        ('\u{17b7}', '\u{17bd}'),
+        ('\u{17c6}', '\u{17c6}'),
+        ('\u{17c9}', '\u{17d3}'),
+        ('\u{17dd}', '\u{17dd}'),
+        ('\u{180b}', '\u{180d}'),
+        ('\u{180f}', '\u{180f}'),
+        ('\u{1885}', '\u{1886}'),
+        ('\u{18a9}', '\u{18a9}'),
+        ('\u{1920}', '\u{1922}'),
+        ('\u{1927}', '\u{1928}'),
+        ('\u{1932}', '\u{1932}'),
+        ('\u{1939}', '\u{193b}'),
+        ('\u{1a17}', '\u{1a18}'),
+        ('\u{1a1b}', '\u{1a1b}'),
+        ('\u{1a56}', '\u{1a56}'),
+        ('\u{1a58}', '\u{1a5e}'),
+        ('\u{1a60}', '\u{1a60}'),
+        ('\u{1a62}', '\u{1a62}'),
+        ('\u{1a65}', '\u{1a6c}'),
+        ('\u{1a73}', '\u{1a7c}'),
+        ('\u{1a7f}', '\u{1a7f}'),
+        ('\u{1ab0}', '\u{1ace}'),
+        ('\u{1b00}', '\u{1b03}'),
+        ('\u{1b34}', '\u{1b3a}'),
+        ('\u{1b3c}', '\u{1b3c}'),
+        ('\u{1b42}', '\u{1b42}'),
+        ('\u{1b6b}', '\u{1b73}'),
+        ('\u{1b80}', '\u{1b81}'),
+        ('\u{1ba2}', '\u{1ba5}'),
+        ('\u{1ba8}', '\u{1ba9}'),
+        ('\u{1bab}', '\u{1bad}'),
+        ('\u{1be6}', '\u{1be6}'),
+        ('\u{1be8}', '\u{1be9}'),
+        ('\u{1bed}', '\u{1bed}'),
+        ('\u{1bef}', '\u{1bf1}'),
+        ('\u{1c2c}', '\u{1c33}'),
+        ('\u{1c36}', '\u{1c37}'),
+        ('\u{1cd0}', '\u{1cd2}'),
+        ('\u{1cd4}', '\u{1ce0}'),
+        ('\u{1ce2}', '\u{1ce8}'),
+        ('\u{1ced}', '\u{1ced}'),
+        ('\u{1cf4}', '\u{1cf4}'),
+        ('\u{1cf8}', '\u{1cf9}'),
+        ('\u{1dc0}', '\u{1dff}'),
+        ('\u{200c}', '\u{200c}'),
+        ('\u{20d0}', '\u{20f0}'),
+        ('\u{2cef}', '\u{2cf1}'),
+        ('\u{2d7f}', '\u{2d7f}'),
+        ('\u{2de0}', '\u{2dff}'),
+        ('\u{302a}', '\u{302f}'),
+        ('\u{3099}', '\u{309a}'),
+        ('\u{a66f}', '\u{a672}'),
+        ('\u{a674}', '\u{a67d}'),
+        ('\u{a69e}', '\u{a69f}'),
+        ('\u{a6f0}', '\u{a6f1}'),
+        ('\u{a802}', '\u{a802}'),
+        ('\u{a806}', '\u{a806}'),
+        ('\u{a80b}', '\u{a80b}'),
+        ('\u{a825}', '\u{a826}'),
+        ('\u{a82c}', '\u{a82c}'),
+        ('\u{a8c4}', '\u{a8c5}'),
+        ('\u{a8e0}', '\u{a8f1}'),
+        ('\u{a8ff}', '\u{a8ff}'),
+        ('\u{a926}', '\u{a92d}'),
+        ('\u{a947}', '\u{a951}'),
+        ('\u{a980}', '\u{a982}'),
+        ('\u{a9b3}', '\u{a9b3}'),
+        ('\u{a9b6}', '\u{a9b9}'),
+        ('\u{a9bc}', '\u{a9bd}'),
+        ('\u{a9e5}', '\u{a9e5}'),
+        ('\u{aa29}', '\u{aa2e}'),
+        ('\u{aa31}', '\u{aa32}'),
+        ('\u{aa35}', '\u{aa36}'),
+        ('\u{aa43}', '\u{aa43}'),
+        ('\u{aa4c}', '\u{aa4c}'),
+        ('\u{aa7c}', '\u{aa7c}'),
+        ('\u{aab0}', '\u{aab0}'),
+        ('\u{aab2}', '\u{aab4}'),
+        ('\u{aab7}', '\u{aab8}'),
+        ('\u{aabe}', '\u{aabf}'),
+        ('\u{aac1}', '\u{aac1}'),
+        ('\u{aaec}', '\u{aaed}'),
+        ('\u{aaf6}', '\u{aaf6}'),
+        ('\u{abe5}', '\u{abe5}'),
+        ('\u{abe8}', '\u{abe8}'),
+        ('\u{abed}', '\u{abed}'),
+        ('\u{fb1e}', '\u{fb1e}'),
+        ('\u{fe00}', '\u{fe0f}'),
+        ('\u{fe20}', '\u{fe2f}'),
+        ('\u{ff9e}', '\u{ff9f}'),
+        ('\u{101fd}', '\u{101fd}'),
+        ('\u{102e0}', '\u{102e0}'),
+        ('\u{10376}', '\u{1037a}'),
+        ('\u{10a01}', '\u{10a03}'),
+        ('\u{10a05}', '\u{10a06}'),
+        ('\u{10a0c}', '\u{10a0f}'),
+        ('\u{10a38}', '\u{10a3a}'),
+        ('\u{10a3f}', '\u{10a3f}'),
+        ('\u{10ae5}', '\u{10ae6}'),
+        ('\u{10d24}', '\u{10d27}'),
+        ('\u{10eab}', '\u{10eac}'),
+        ('\u{10efd}', '\u{10eff}'),
+        ('\u{10f46}', '\u{10f50}'),
+        ('\u{10f82}', '\u{10f85}'),
+        ('\u{11001}', '\u{11001}'),
+        ('\u{11038}', '\u{11046}'),
+        ('\u{11070}', '\u{11070}'),
+        ('\u{11073}', '\u{11074}'),
+        ('\u{1107f}', '\u{11081}'),
+        ('\u{110b3}', '\u{110b6}'),
+        ('\u{110b9}', '\u{110ba}'),
+        ('\u{110c2}', '\u{110c2}'),
+        ('\u{11100}', '\u{11102}'),
+        ('\u{11127}', '\u{1112b}'),
+        ('\u{1112d}', '\u{11134}'),
+        ('\u{11173}', '\u{11173}'),
+        ('\u{11180}', '\u{11181}'),
+        ('\u{111b6}', '\u{111be}'),
+        ('\u{111c9}', '\u{111cc}'),
+        ('\u{111cf}', '\u{111cf}'),
+        ('\u{1122f}', '\u{11231}'),
+        ('\u{11234}', '\u{11234}'),
+        ('\u{11236}', '\u{11237}'),
+        ('\u{1123e}', '\u{1123e}'),
+        ('\u{11241}', '\u{11241}'),
+        ('\u{112df}', '\u{112df}'),
+        ('\u{112e3}', '\u{112ea}'),
+        ('\u{11300}', '\u{11301}'),
+        ('\u{1133b}', '\u{1133c}'),
+        ('\u{1133e}', '\u{1133e}'),
+        ('\u{11340}', '\u{11340}'),
+        ('\u{11357}', '\u{11357}'),
+        ('\u{11366}', '\u{1136c}'),
+        ('\u{11370}', '\u{11374}'),
+        ('\u{11438}', '\u{1143f}'),
+        ('\u{11442}', '\u{11444}'),
+        ('\u{11446}', '\u{11446}'),
+        ('\u{1145e}', '\u{1145e}'),
+        ('\u{114b0}', '\u{114b0}'),
+        ('\u{114b3}', '\u{114b8}'),
+        ('\u{114ba}', '\u{114ba}'),
+        ('\u{114bd}', '\u{114bd}'),
+        ('\u{114bf}', '\u{114c0}'),
+        ('\u{114c2}', '\u{114c3}'),
+        ('\u{115af}', '\u{115af}'),
+        ('\u{115b2}', '\u{115b5}'),
+        ('\u{115bc}', '\u{115bd}'),
+        ('\u{115bf}', '\u{115c0}'),
+        ('\u{115dc}', '\u{115dd}'),
+        ('\u{11633}', '\u{1163a}'),
+        ('\u{1163d}', '\u{1163d}'),
+        ('\u{1163f}', '\u{11640}'),
+        ('\u{116ab}', '\u{116ab}'),
+        ('\u{116ad}', '\u{116ad}'),
+        ('\u{116b0}', '\u{116b5}'),
+        ('\u{116b7}', '\u{116b7}'),
+        ('\u{1171d}', '\u{1171f}'),
+        ('\u{11722}', '\u{11725}'),
+        ('\u{11727}', '\u{1172b}'),
+        ('\u{1182f}', '\u{11837}'),
+        ('\u{11839}', '\u{1183a}'),
+        ('\u{11930}', '\u{11930}'),
+        ('\u{1193b}', '\u{1193c}'),
+        ('\u{1193e}', '\u{1193e}'),
+        ('\u{11943}', '\u{11943}'),
+        ('\u{119d4}', '\u{119d7}'),
+        ('\u{119da}', '\u{119db}'),
+        ('\u{119e0}', '\u{119e0}'),
+        ('\u{11a01}', '\u{11a0a}'),
+        ('\u{11a33}', '\u{11a38}'),
+        ('\u{11a3b}', '\u{11a3e}'),
+        ('\u{11a47}', '\u{11a47}'),
+        ('\u{11a51}', '\u{11a56}'),
+        ('\u{11a59}', '\u{11a5b}'),
+        ('\u{11a8a}', '\u{11a96}'),
+        ('\u{11a98}', '\u{11a99}'),
+        ('\u{11c30}', '\u{11c36}'),
+        ('\u{11c38}', '\u{11c3d}'),
+        ('\u{11c3f}', '\u{11c3f}'),
+        ('\u{11c92}', '\u{11ca7}'),
+        ('\u{11caa}', '\u{11cb0}'),
+        ('\u{11cb2}', '\u{11cb3}'),
+        ('\u{11cb5}', '\u{11cb6}'),
+        ('\u{11d31}', '\u{11d36}'),
+        ('\u{11d3a}', '\u{11d3a}'),
+        ('\u{11d3c}', '\u{11d3d}'),
+        ('\u{11d3f}', '\u{11d45}'),
+        ('\u{11d47}', '\u{11d47}'),
+        ('\u{11d90}', '\u{11d91}'),
+        ('\u{11d95}', '\u{11d95}'),
+        ('\u{11d97}', '\u{11d97}'),
+        ('\u{11ef3}', '\u{11ef4}'),
+        ('\u{11f00}', '\u{11f01}'),
+        ('\u{11f36}', '\u{11f3a}'),
+        ('\u{11f40}', '\u{11f40}'),
+        ('\u{11f42}', '\u{11f42}'),
+        ('\u{13440}', '\u{13440}'),
+        ('\u{13447}', '\u{13455}'),
+        ('\u{16af0}', '\u{16af4}'),
+        ('\u{16b30}', '\u{16b36}'),
+        ('\u{16f4f}', '\u{16f4f}'),
+        ('\u{16f8f}', '\u{16f92}'),
+        ('\u{16fe4}', '\u{16fe4}'),
+        ('\u{1bc9d}', '\u{1bc9e}'),
+        ('\u{1cf00}', '\u{1cf2d}'),
+        ('\u{1cf30}', '\u{1cf46}'),
+        ('\u{1d165}', '\u{1d165}'),
+        ('\u{1d167}', '\u{1d169}'),
+        ('\u{1d16e}', '\u{1d172}'),
+        ('\u{1d17b}', '\u{1d182}'),
+        ('\u{1d185}', '\u{1d18b}'),
+        ('\u{1d1aa}', '\u{1d1ad}'),
+        ('\u{1d242}', '\u{1d244}'),
+        ('\u{1da00}', '\u{1da36}'),
+        ('\u{1da3b}', '\u{1da6c}'),
+        ('\u{1da75}', '\u{1da75}'),
+        ('\u{1da84}', '\u{1da84}'),
+        ('\u{1da9b}', '\u{1da9f}'),
+        ('\u{1daa1}', '\u{1daaf}'),
+        ('\u{1e000}', '\u{1e006}'),
+        ('\u{1e008}', '\u{1e018}'),
+        ('\u{1e01b}', '\u{1e021}'),
+        ('\u{1e023}', '\u{1e024}'),
+        ('\u{1e026}', '\u{1e02a}'),
+        ('\u{1e08f}', '\u{1e08f}'),
+        ('\u{1e130}', '\u{1e136}'),
+        ('\u{1e2ae}', '\u{1e2ae}'),
+        ('\u{1e2ec}', '\u{1e2ef}'),
+        ('\u{1e4ec}', '\u{1e4ef}'),
+        ('\u{1e8d0}', '\u{1e8d6}'),
+        ('\u{1e944}', '\u{1e94a}'),
+        ('\u{e0020}', '\u{e007f}'),
+        ('\u{e0100}', '\u{e01ef}'),
+    ];
+
+    /// Autogenerated. 310 sorted, non-overlapping range(s).
+    static MARK_TABLE: [(char, char); 310] = [
+        ('\u{300}', '\u{36f}'),
+        ('\u{483}', '\u{489}'),
+        ('\u{591}', '\u{5bd}'),
+        ('\u{5bf}', '\u{5bf}'),
+        ('\u{5c1}', '\u{5c2}'),
+        ('\u{5c4}', '\u{5c5}'),
+        ('\u{5c7}', '\u{5c7}'),
+        ('\u{610}', '\u{61a}'),
+        ('\u{64b}', '\u{65f}'),
+        ('\u{670}', '\u{670}'),
+        ('\u{6d6}', '\u{6dc}'),
+        ('\u{6df}', '\u{6e4}'),
+        ('\u{6e7}', '\u{6e8}'),
+        ('\u{6ea}', '\u{6ed}'),
+        ('\u{711}', '\u{711}'),
+        ('\u{730}', '\u{74a}'),
+        ('\u{7a6}', '\u{7b0}'),
+        ('\u{7eb}', '\u{7f3}'),
+        ('\u{7fd}', '\u{7fd}'),
+        ('\u{816}', '\u{819}'),
+        ('\u{81b}', '\u{823}'),
+        ('\u{825}', '\u{827}'),
+        ('\u{829}', '\u{82d}'),
+        ('\u{859}', '\u{85b}'),
+        ('\u{898}', '\u{89f}'),
+        ('\u{8ca}', '\u{8e1}'),
+        ('\u{8e3}', '\u{903}'),
+        ('\u{93a}', '\u{93c}'),
+        ('\u{93e}', '\u{94f}'),
+        ('\u{951}', '\u{957}'),
+        ('\u{962}', '\u{963}'),
+        ('\u{981}', '\u{983}'),
+        ('\u{9bc}', '\u{9bc}'),
+        ('\u{9be}', '\u{9c4}'),
+        ('\u{9c7}', '\u{9c8}'),
+        ('\u{9cb}', '\u{9cd}'),
+        ('\u{9d7}', '\u{9d7}'),
+        ('\u{9e2}', '\u{9e3}'),
+        ('\u{9fe}', '\u{9fe}'),
+        ('\u{a01}', '\u{a03}'),
+        ('\u{a3c}', '\u{a3c}'),
+        ('\u{a3e}', '\u{a42}'),
+        ('\u{a47}', '\u{a48}'),
+        ('\u{a4b}', '\u{a4d}'),
+        ('\u{a51}', '\u{a51}'),
+        ('\u{a70}', '\u{a71}'),
+        ('\u{a75}', '\u{a75}'),
+        ('\u{a81}', '\u{a83}'),
+        ('\u{abc}', '\u{abc}'),
+        ('\u{abe}', '\u{ac5}'),
+        ('\u{ac7}', '\u{ac9}'),
+        ('\u{acb}', '\u{acd}'),
+        ('\u{ae2}', '\u{ae3}'),
+        ('\u{afa}', '\u{aff}'),
+        ('\u{b01}', '\u{b03}'),
+        ('\u{b3c}', '\u{b3c}'),
+        ('\u{b3e}', '\u{b44}'),
+        ('\u{b47}', '\u{b48}'),
+        ('\u{b4b}', '\u{b4d}'),
+        ('\u{b55}', '\u{b57}'),
+        ('\u{b62}', '\u{b63}'),
+        ('\u{b82}', '\u{b82}'),
+        ('\u{bbe}', '\u{bc2}'),
+        ('\u{bc6}', '\u{bc8}'),
+        ('\u{bca}', '\u{bcd}'),
+        ('\u{bd7}', '\u{bd7}'),
+        ('\u{c00}', '\u{c04}'),
+        ('\u{c3c}', '\u{c3c}'),
+        ('\u{c3e}', '\u{c44}'),
+        ('\u{c46}', '\u{c48}'),
+        ('\u{c4a}', '\u{c4d}'),
+        ('\u{c55}', '\u{c56}'),
+        ('\u{c62}', '\u{c63}'),
+        ('\u{c81}', '\u{c83}'),
+        ('\u{cbc}', '\u{cbc}'),
+        ('\u{cbe}', '\u{cc4}'),
+        ('\u{cc6}', '\u{cc8}'),
+        ('\u{cca}', '\u{ccd}'),
+        ('\u{cd5}', '\u{cd6}'),
+        ('\u{ce2}', '\u{ce3}'),
+        ('\u{cf3}', '\u{cf3}'),
+        ('\u{d00}', '\u{d03}'),
+        ('\u{d3b}', '\u{d3c}'),
+        ('\u{d3e}', '\u{d44}'),
+        ('\u{d46}', '\u{d48}'),
+        ('\u{d4a}', '\u{d4d}'),
+        ('\u{d57}', '\u{d57}'),
+        ('\u{d62}', '\u{d63}'),
+        ('\u{d81}', '\u{d83}'),
+        ('\u{dca}', '\u{dca}'),
+        ('\u{dcf}', '\u{dd4}'),
+        ('\u{dd6}', '\u{dd6}'),
+        ('\u{dd8}', '\u{ddf}'),
+        ('\u{df2}', '\u{df3}'),
+        ('\u{e31}', '\u{e31}'),
+        ('\u{e34}', '\u{e3a}'),
+        ('\u{e47}', '\u{e4e}'),
+        ('\u{eb1}', '\u{eb1}'),
+        ('\u{eb4}', '\u{ebc}'),
+        ('\u{ec8}', '\u{ece}'),
+        ('\u{f18}', '\u{f19}'),
+        ('\u{f35}', '\u{f35}'),
+        ('\u{f37}', '\u{f37}'),
+        ('\u{f39}', '\u{f39}'),
+        ('\u{f3e}', '\u{f3f}'),
+        ('\u{f71}', '\u{f84}'),
+        ('\u{f86}', '\u{f87}'),
+        ('\u{f8d}', '\u{f97}'),
+        ('\u{f99}', '\u{fbc}'),
+        ('\u{fc6}', '\u{fc6}'),
+        ('\u{102b}', '\u{103e}'),
+        ('\u{1056}', '\u{1059}'),
+        ('\u{105e}', '\u{1060}'),
+        ('\u{1062}', '\u{1064}'),
+        ('\u{1067}', '\u{106d}'),
+        ('\u{1071}', '\u{1074}'),
+        ('\u{1082}', '\u{108d}'),
+        ('\u{108f}', '\u{108f}'),
+        ('\u{109a}', '\u{109d}'),
+        ('\u{135d}', '\u{135f}'),
+        ('\u{1712}', '\u{1715}'),
+        ('\u{1732}', '\u{1734}'),
+        ('\u{1752}', '\u{1753}'),
+        ('\u{1772}', '\u{1773}'),
+        ('\u{17b4}', '\u{17d3}'),
+        ('\u{17dd}', '\u{17dd}'),
+        ('\u{180b}', '\u{180d}'),
+        ('\u{180f}', '\u{180f}'),
+        ('\u{1885}', '\u{1886}'),
+        ('\u{18a9}', '\u{18a9}'),
+        ('\u{1920}', '\u{192b}'),
+        ('\u{1930}', '\u{193b}'),
+        ('\u{1a17}', '\u{1a1b}'),
+        ('\u{1a55}', '\u{1a5e}'),
+        ('\u{1a60}', '\u{1a7c}'),
+        ('\u{1a7f}', '\u{1a7f}'),
+        ('\u{1ab0}', '\u{1ace}'),
+        ('\u{1b00}', '\u{1b04}'),
+        ('\u{1b34}', '\u{1b44}'),
+        ('\u{1b6b}', '\u{1b73}'),
+        ('\u{1b80}', '\u{1b82}'),
+        ('\u{1ba1}', '\u{1bad}'),
+        ('\u{1be6}', '\u{1bf3}'),
+        ('\u{1c24}', '\u{1c37}'),
+        ('\u{1cd0}', '\u{1cd2}'),
+        ('\u{1cd4}', '\u{1ce8}'),
+        ('\u{1ced}', '\u{1ced}'),
+        ('\u{1cf4}', '\u{1cf4}'),
+        ('\u{1cf7}', '\u{1cf9}'),
+        ('\u{1dc0}', '\u{1dff}'),
+        ('\u{20d0}', '\u{20f0}'),
+        ('\u{2cef}', '\u{2cf1}'),
+        ('\u{2d7f}', '\u{2d7f}'),
+        ('\u{2de0}', '\u{2dff}'),
+        ('\u{302a}', '\u{302f}'),
+        ('\u{3099}', '\u{309a}'),
+        ('\u{a66f}', '\u{a672}'),
+        ('\u{a674}', '\u{a67d}'),
+        ('\u{a69e}', '\u{a69f}'),
+        ('\u{a6f0}', '\u{a6f1}'),
+        ('\u{a802}', '\u{a802}'),
+        ('\u{a806}', '\u{a806}'),
+        ('\u{a80b}', '\u{a80b}'),
+        ('\u{a823}', '\u{a827}'),
+        ('\u{a82c}', '\u{a82c}'),
+        ('\u{a880}', '\u{a881}'),
+        ('\u{a8b4}', '\u{a8c5}'),
+        ('\u{a8e0}', '\u{a8f1}'),
+        ('\u{a8ff}', '\u{a8ff}'),
+        ('\u{a926}', '\u{a92d}'),
+        ('\u{a947}', '\u{a953}'),
+        ('\u{a980}', '\u{a983}'),
+        ('\u{a9b3}', '\u{a9c0}'),
+        ('\u{a9e5}', '\u{a9e5}'),
+        ('\u{aa29}', '\u{aa36}'),
+        ('\u{aa43}', '\u{aa43}'),
+        ('\u{aa4c}', '\u{aa4d}'),
+        ('\u{aa7b}', '\u{aa7d}'),
+        ('\u{aab0}', '\u{aab0}'),
+        ('\u{aab2}', '\u{aab4}'),
+        ('\u{aab7}', '\u{aab8}'),
+        ('\u{aabe}', '\u{aabf}'),
+        ('\u{aac1}', '\u{aac1}'),
+        ('\u{aaeb}', '\u{aaef}'),
+        ('\u{aaf5}', '\u{aaf6}'),
+        ('\u{abe3}', '\u{abea}'),
+        ('\u{abec}', '\u{abed}'),
+        ('\u{fb1e}', '\u{fb1e}'),
+        ('\u{fe00}', '\u{fe0f}'),
+        ('\u{fe20}', '\u{fe2f}'),
+        ('\u{101fd}', '\u{101fd}'),
+        ('\u{102e0}', '\u{102e0}'),
+        ('\u{10376}', '\u{1037a}'),
+        ('\u{10a01}', '\u{10a03}'),
+        ('\u{10a05}', '\u{10a06}'),
+        ('\u{10a0c}', '\u{10a0f}'),
+        ('\u{10a38}', '\u{10a3a}'),
+        ('\u{10a3f}', '\u{10a3f}'),
+        ('\u{10ae5}', '\u{10ae6}'),
+        ('\u{10d24}', '\u{10d27}'),
+        ('\u{10eab}', '\u{10eac}'),
+        ('\u{10efd}', '\u{10eff}'),
+        ('\u{10f46}', '\u{10f50}'),
+        ('\u{10f82}', '\u{10f85}'),
+        ('\u{11000}', '\u{11002}'),
+        ('\u{11038}', '\u{11046}'),
+        ('\u{11070}', '\u{11070}'),
+        ('\u{11073}', '\u{11074}'),
+        ('\u{1107f}', '\u{11082}'),
+        ('\u{110b0}', '\u{110ba}'),
+        ('\u{110c2}', '\u{110c2}'),
+        ('\u{11100}', '\u{11102}'),
+        ('\u{11127}', '\u{11134}'),
+        ('\u{11145}', '\u{11146}'),
+        ('\u{11173}', '\u{11173}'),
+        ('\u{11180}', '\u{11182}'),
+        ('\u{111b3}', '\u{111c0}'),
+        ('\u{111c9}', '\u{111cc}'),
+        ('\u{111ce}', '\u{111cf}'),
+        ('\u{1122c}', '\u{11237}'),
+        ('\u{1123e}', '\u{1123e}'),
+        ('\u{11241}', '\u{11241}'),
+        ('\u{112df}', '\u{112ea}'),
+        ('\u{11300}', '\u{11303}'),
+        ('\u{1133b}', '\u{1133c}'),
+        ('\u{1133e}', '\u{11344}'),
+        ('\u{11347}', '\u{11348}'),
+        ('\u{1134b}', '\u{1134d}'),
+        ('\u{11357}', '\u{11357}'),
+        ('\u{11362}', '\u{11363}'),
+        ('\u{11366}', '\u{1136c}'),
+        ('\u{11370}', '\u{11374}'),
+        ('\u{11435}', '\u{11446}'),
+        ('\u{1145e}', '\u{1145e}'),
+        ('\u{114b0}', '\u{114c3}'),
+        ('\u{115af}', '\u{115b5}'),
+        ('\u{115b8}', '\u{115c0}'),
+        ('\u{115dc}', '\u{115dd}'),
+        ('\u{11630}', '\u{11640}'),
+        ('\u{116ab}', '\u{116b7}'),
+        ('\u{1171d}', '\u{1172b}'),
+        ('\u{1182c}', '\u{1183a}'),
+        ('\u{11930}', '\u{11935}'),
+        ('\u{11937}', '\u{11938}'),
+        ('\u{1193b}', '\u{1193e}'),
+        ('\u{11940}', '\u{11940}'),
+        ('\u{11942}', '\u{11943}'),
+        ('\u{119d1}', '\u{119d7}'),
+        ('\u{119da}', '\u{119e0}'),
+        ('\u{119e4}', '\u{119e4}'),
+        ('\u{11a01}', '\u{11a0a}'),
+        ('\u{11a33}', '\u{11a39}'),
+        ('\u{11a3b}', '\u{11a3e}'),
+        ('\u{11a47}', '\u{11a47}'),
+        ('\u{11a51}', '\u{11a5b}'),
+        ('\u{11a8a}', '\u{11a99}'),
+        ('\u{11c2f}', '\u{11c36}'),
+        ('\u{11c38}', '\u{11c3f}'),
+        ('\u{11c92}', '\u{11ca7}'),
+        ('\u{11ca9}', '\u{11cb6}'),
+        ('\u{11d31}', '\u{11d36}'),
+        ('\u{11d3a}', '\u{11d3a}'),
+        ('\u{11d3c}', '\u{11d3d}'),
+        ('\u{11d3f}', '\u{11d45}'),
+        ('\u{11d47}', '\u{11d47}'),
+        ('\u{11d8a}', '\u{11d8e}'),
+        ('\u{11d90}', '\u{11d91}'),
+        ('\u{11d93}', '\u{11d97}'),
+        ('\u{11ef3}', '\u{11ef6}'),
+        ('\u{11f00}', '\u{11f01}'),
+        ('\u{11f03}', '\u{11f03}'),
+        ('\u{11f34}', '\u{11f3a}'),
+        ('\u{11f3e}', '\u{11f42}'),
+        ('\u{13440}', '\u{13440}'),
+        ('\u{13447}', '\u{13455}'),
+        ('\u{16af0}', '\u{16af4}'),
+        ('\u{16b30}', '\u{16b36}'),
+        ('\u{16f4f}', '\u{16f4f}'),
+        ('\u{16f51}', '\u{16f87}'),
+        ('\u{16f8f}', '\u{16f92}'),
+        ('\u{16fe4}', '\u{16fe4}'),
+        ('\u{16ff0}', '\u{16ff1}'),
+        ('\u{1bc9d}', '\u{1bc9e}'),
+        ('\u{1cf00}', '\u{1cf2d}'),
+        ('\u{1cf30}', '\u{1cf46}'),
+        ('\u{1d165}', '\u{1d169}'),
+        ('\u{1d16d}', '\u{1d172}'),
+        ('\u{1d17b}', '\u{1d182}'),
+        ('\u{1d185}', '\u{1d18b}'),
+        ('\u{1d1aa}', '\u{1d1ad}'),
+        ('\u{1d242}', '\u{1d244}'),
+        ('\u{1da00}', '\u{1da36}'),
+        ('\u{1da3b}', '\u{1da6c}'),
+        ('\u{1da75}', '\u{1da75}'),
+        ('\u{1da84}', '\u{1da84}'),
+        ('\u{1da9b}', '\u{1da9f}'),
+        ('\u{1daa1}', '\u{1daaf}'),
+        ('\u{1e000}', '\u{1e006}'),
+        ('\u{1e008}', '\u{1e018}'),
+        ('\u{1e01b}', '\u{1e021}'),
+        ('\u{1e023}', '\u{1e024}'),
+        ('\u{1e026}', '\u{1e02a}'),
+        ('\u{1e08f}', '\u{1e08f}'),
+        ('\u{1e130}', '\u{1e136}'),
+        ('\u{1e2ae}', '\u{1e2ae}'),
+        ('\u{1e2ec}', '\u{1e2ef}'),
+        ('\u{1e4ec}', '\u{1e4ef}'),
+        ('\u{1e8d0}', '\u{1e8d6}'),
+        ('\u{1e944}', '\u{1e94a}'),
+        ('\u{e0100}', '\u{e01ef}'),
+    ];
+
+    /// Autogenerated. 5 sorted, non-overlapping range(s).
+    static PATTERN_WHITE_SPACE_TABLE: [(char, char); 5] = [
+        ('\u{9}', '\u{d}'),
+        ('\u{20}', '\u{20}'),
+        ('\u{85}', '\u{85}'),
+        ('\u{200e}', '\u{200f}'),
+        ('\u{2028}', '\u{2029}'),
+    ];
+
+    /// Autogenerated. 132 sorted, non-overlapping range(s).
+    static RTL_TABLE: [(char, char); 132] = [
+        ('\u{5be}', '\u{5be}'),
+        ('\u{5c0}', '\u{5c0}'),
+        ('\u{5c3}', '\u{5c3}'),
+        ('\u{5c6}', '\u{5c6}'),
+        ('\u{5d0}', '\u{5ea}'),
+        ('\u{5ef}', '\u{5f4}'),
+        ('\u{608}', '\u{608}'),
+        ('\u{60b}', '\u{60b}'),
+        ('\u{60d}', '\u{60d}'),
+        ('\u{61b}', '\u{64a}'),
+        ('\u{66d}', '\u{66f}'),
+        ('\u{671}', '\u{6d5}'),
+        ('\u{6e5}', '\u{6e6}'),
+        ('\u{6ee}', '\u{6ef}'),
+        ('\u{6fa}', '\u{70d}'),
+        ('\u{70f}', '\u{710}'),
+        ('\u{712}', '\u{72f}'),
+        ('\u{74d}', '\u{7a5}'),
+        ('\u{7b1}', '\u{7b1}'),
+        ('\u{7c0}', '\u{7ea}'),
+        ('\u{7f4}', '\u{7f5}'),
+        ('\u{7fa}', '\u{7fa}'),
+        ('\u{7fe}', '\u{815}'),
+        ('\u{81a}', '\u{81a}'),
+        ('\u{824}', '\u{824}'),
+        ('\u{828}', '\u{828}'),
+        ('\u{830}', '\u{83e}'),
+        ('\u{840}', '\u{858}'),
+        ('\u{85e}', '\u{85e}'),
+        ('\u{860}', '\u{86a}'),
+        ('\u{870}', '\u{88e}'),
+        ('\u{8a0}', '\u{8c9}'),
+        ('\u{200f}', '\u{200f}'),
+        ('\u{fb1d}', '\u{fb1d}'),
+        ('\u{fb1f}', '\u{fb28}'),
+        ('\u{fb2a}', '\u{fb36}'),
+        ('\u{fb38}', '\u{fb3c}'),
+        ('\u{fb3e}', '\u{fb3e}'),
+        ('\u{fb40}', '\u{fb41}'),
+        ('\u{fb43}', '\u{fb44}'),
+        ('\u{fb46}', '\u{fbc2}'),
+        ('\u{fbd3}', '\u{fd3d}'),
+        ('\u{fd50}', '\u{fd8f}'),
+        ('\u{fd92}', '\u{fdc7}'),
+        ('\u{fdf0}', '\u{fdfc}'),
+        ('\u{fe70}', '\u{fe74}'),
+        ('\u{fe76}', '\u{fefc}'),
+        ('\u{10800}', '\u{10805}'),
+        ('\u{10808}', '\u{10808}'),
+        ('\u{1080a}', '\u{10835}'),
+        ('\u{10837}', '\u{10838}'),
+        ('\u{1083c}', '\u{1083c}'),
+        ('\u{1083f}', '\u{10855}'),
+        ('\u{10857}', '\u{1089e}'),
+        ('\u{108a7}', '\u{108af}'),
+        ('\u{108e0}', '\u{108f2}'),
+        ('\u{108f4}', '\u{108f5}'),
+        ('\u{108fb}', '\u{1091b}'),
+        ('\u{10920}', '\u{10939}'),
+        ('\u{1093f}', '\u{1093f}'),
+        ('\u{10980}', '\u{109b7}'),
+        ('\u{109bc}', '\u{109cf}'),
+        ('\u{109d2}', '\u{10a00}'),
+        ('\u{10a10}', '\u{10a13}'),
+        ('\u{10a15}', '\u{10a17}'),
+        ('\u{10a19}', '\u{10a35}'),
+        ('\u{10a40}', '\u{10a48}'),
+        ('\u{10a50}', '\u{10a58}'),
+        ('\u{10a60}', '\u{10a9f}'),
+        ('\u{10ac0}', '\u{10ae4}'),
+        ('\u{10aeb}', '\u{10af6}'),
+        ('\u{10b00}', '\u{10b35}'),
+        ('\u{10b40}', '\u{10b55}'),
+        ('\u{10b58}', '\u{10b72}'),
+        ('\u{10b78}', '\u{10b91}'),
+        ('\u{10b99}', '\u{10b9c}'),
+        ('\u{10ba9}', '\u{10baf}'),
+        ('\u{10c00}', '\u{10c48}'),
+        ('\u{10c80}', '\u{10cb2}'),
+        ('\u{10cc0}', '\u{10cf2}'),
+        ('\u{10cfa}', '\u{10d23}'),
+        ('\u{10e80}', '\u{10ea9}'),
+        ('\u{10ead}', '\u{10ead}'),
+        ('\u{10eb0}', '\u{10eb1}'),
+        ('\u{10f00}', '\u{10f27}'),
+        ('\u{10f30}', '\u{10f45}'),
+        ('\u{10f51}', '\u{10f59}'),
+        ('\u{10f70}', '\u{10f81}'),
+        ('\u{10f86}', '\u{10f89}'),
+        ('\u{10fb0}', '\u{10fcb}'),
+        ('\u{10fe0}', '\u{10ff6}'),
+        ('\u{1e800}', '\u{1e8c4}'),
+        ('\u{1e8c7}', '\u{1e8cf}'),
+        ('\u{1e900}', '\u{1e943}'),
+        ('\u{1e94b}', '\u{1e94b}'),
+        ('\u{1e950}', '\u{1e959}'),
+        ('\u{1e95e}', '\u{1e95f}'),
+        ('\u{1ec71}', '\u{1ecb4}'),
+        ('\u{1ed01}', '\u{1ed3d}'),
+        ('\u{1ee00}', '\u{1ee03}'),
+        ('\u{1ee05}', '\u{1ee1f}'),
+        ('\u{1ee21}', '\u{1ee22}'),
+        ('\u{1ee24}', '\u{1ee24}'),
+        ('\u{1ee27}', '\u{1ee27}'),
+        ('\u{1ee29}', '\u{1ee32}'),
+        ('\u{1ee34}', '\u{1ee37}'),
+        ('\u{1ee39}', '\u{1ee39}'),
+        ('\u{1ee3b}', '\u{1ee3b}'),
+        ('\u{1ee42}', '\u{1ee42}'),
+        ('\u{1ee47}', '\u{1ee47}'),
+        ('\u{1ee49}', '\u{1ee49}'),
+        ('\u{1ee4b}', '\u{1ee4b}'),
+        ('\u{1ee4d}', '\u{1ee4f}'),
+        ('\u{1ee51}', '\u{1ee52}'),
+        ('\u{1ee54}', '\u{1ee54}'),
+        ('\u{1ee57}', '\u{1ee57}'),
+        ('\u{1ee59}', '\u{1ee59}'),
+        ('\u{1ee5b}', '\u{1ee5b}'),
+        ('\u{1ee5d}', '\u{1ee5d}'),
+        ('\u{1ee5f}', '\u{1ee5f}'),
+        ('\u{1ee61}', '\u{1ee62}'),
+        ('\u{1ee64}', '\u{1ee64}'),
+        ('\u{1ee67}', '\u{1ee6a}'),
+        ('\u{1ee6c}', '\u{1ee72}'),
+        ('\u{1ee74}', '\u{1ee77}'),
+        ('\u{1ee79}', '\u{1ee7c}'),
+        ('\u{1ee7e}', '\u{1ee7e}'),
+        ('\u{1ee80}', '\u{1ee89}'),
+        ('\u{1ee8b}', '\u{1ee9b}'),
+        ('\u{1eea1}', '\u{1eea3}'),
+        ('\u{1eea5}', '\u{1eea9}'),
+        ('\u{1eeab}', '\u{1eebb}'),
+    ];
+
+    /// Autogenerated. 7 sorted, non-overlapping range(s).
+    #[cfg(feature = "cjk-punctuation-wide")]
+    static CJK_PUNCTUATION_TABLE: [(char, char); 7] = [
+        ('\u{2014}', '\u{2015}'),
+        ('\u{2018}', '\u{2019}'),
+        ('\u{201c}', '\u{201d}'),
+        ('\u{2025}', '\u{2026}'),
+        ('\u{2030}', '\u{2030}'),
+        ('\u{2032}', '\u{2033}'),
+        ('\u{203b}', '\u{203b}'),
+    ];
+
+    /// Autogenerated. 315 sorted, non-overlapping range(s) of non-Neutral characters.
+    static EAW_TABLE: [(char, char, EastAsianWidth); 315] = [
+        ('\u{20}', '\u{7e}', EastAsianWidth::Narrow),
+        ('\u{a1}', '\u{a1}', EastAsianWidth::Ambiguous),
+        ('\u{a2}', '\u{a3}', EastAsianWidth::Narrow),
+        ('\u{a4}', '\u{a4}', EastAsianWidth::Ambiguous),
+        ('\u{a5}', '\u{a6}', EastAsianWidth::Narrow),
+        ('\u{a7}', '\u{a8}', EastAsianWidth::Ambiguous),
+        ('\u{aa}', '\u{aa}', EastAsianWidth::Ambiguous),
+        ('\u{ac}', '\u{ac}', EastAsianWidth::Narrow),
+        ('\u{ad}', '\u{ae}', EastAsianWidth::Ambiguous),
+        ('\u{af}', '\u{af}', EastAsianWidth::Narrow),
+        ('\u{b0}', '\u{b4}', EastAsianWidth::Ambiguous),
+        ('\u{b6}', '\u{ba}', EastAsianWidth::Ambiguous),
+        ('\u{bc}', '\u{bf}', EastAsianWidth::Ambiguous),
+        ('\u{c6}', '\u{c6}', EastAsianWidth::Ambiguous),
+        ('\u{d0}', '\u{d0}', EastAsianWidth::Ambiguous),
+        ('\u{d7}', '\u{d8}', EastAsianWidth::Ambiguous),
+        ('\u{de}', '\u{e1}', EastAsianWidth::Ambiguous),
+        ('\u{e6}', '\u{e6}', EastAsianWidth::Ambiguous),
+        ('\u{e8}', '\u{ea}', EastAsianWidth::Ambiguous),
+        ('\u{ec}', '\u{ed}', EastAsianWidth::Ambiguous),
+        ('\u{f0}', '\u{f0}', EastAsianWidth::Ambiguous),
+        ('\u{f2}', '\u{f3}', EastAsianWidth::Ambiguous),
+        ('\u{f7}', '\u{fa}', EastAsianWidth::Ambiguous),
+        ('\u{fc}', '\u{fc}', EastAsianWidth::Ambiguous),
+        ('\u{fe}', '\u{fe}', EastAsianWidth::Ambiguous),
+        ('\u{101}', '\u{101}', EastAsianWidth::Ambiguous),
+        ('\u{111}', '\u{111}', EastAsianWidth::Ambiguous),
+        ('\u{113}', '\u{113}', EastAsianWidth::Ambiguous),
+        ('\u{11b}', '\u{11b}', EastAsianWidth::Ambiguous),
+        ('\u{126}', '\u{127}', EastAsianWidth::Ambiguous),
+        ('\u{12b}', '\u{12b}', EastAsianWidth::Ambiguous),
+        ('\u{131}', '\u{133}', EastAsianWidth::Ambiguous),
+        ('\u{138}', '\u{138}', EastAsianWidth::Ambiguous),
+        ('\u{13f}', '\u{142}', EastAsianWidth::Ambiguous),
+        ('\u{144}', '\u{144}', EastAsianWidth::Ambiguous),
+        ('\u{148}', '\u{14b}', EastAsianWidth::Ambiguous),
+        ('\u{14d}', '\u{14d}', EastAsianWidth::Ambiguous),
+        ('\u{152}', '\u{153}', EastAsianWidth::Ambiguous),
+        ('\u{166}', '\u{167}', EastAsianWidth::Ambiguous),
+        ('\u{16b}', '\u{16b}', EastAsianWidth::Ambiguous),
+        ('\u{1ce}', '\u{1ce}', EastAsianWidth::Ambiguous),
+        ('\u{1d0}', '\u{1d0}', EastAsianWidth::Ambiguous),
+        ('\u{1d2}', '\u{1d2}', EastAsianWidth::Ambiguous),
+        ('\u{1d4}', '\u{1d4}', EastAsianWidth::Ambiguous),
+        ('\u{1d6}', '\u{1d6}', EastAsianWidth::Ambiguous),
+        ('\u{1d8}', '\u{1d8}', EastAsianWidth::Ambiguous),
+        ('\u{1da}', '\u{1da}', EastAsianWidth::Ambiguous),
+        ('\u{1dc}', '\u{1dc}', EastAsianWidth::Ambiguous),
+        ('\u{251}', '\u{251}', EastAsianWidth::Ambiguous),
+        ('\u{261}', '\u{261}', EastAsianWidth::Ambiguous),
+        ('\u{2c4}', '\u{2c4}', EastAsianWidth::Ambiguous),
+        ('\u{2c7}', '\u{2c7}', EastAsianWidth::Ambiguous),
+        ('\u{2c9}', '\u{2cb}', EastAsianWidth::Ambiguous),
+        ('\u{2cd}', '\u{2cd}', EastAsianWidth::Ambiguous),
+        ('\u{2d0}', '\u{2d0}', EastAsianWidth::Ambiguous),
+        ('\u{2d8}', '\u{2db}', EastAsianWidth::Ambiguous),
+        ('\u{2dd}', '\u{2dd}', EastAsianWidth::Ambiguous),
+        ('\u{2df}', '\u{2df}', EastAsianWidth::Ambiguous),
+        ('\u{300}', '\u{36f}', EastAsianWidth::Ambiguous),
+        ('\u{391}', '\u{3a1}', EastAsianWidth::Ambiguous),
+        ('\u{3a3}', '\u{3a9}', EastAsianWidth::Ambiguous),
+        ('\u{3b1}', '\u{3c1}', EastAsianWidth::Ambiguous),
+        ('\u{3c3}', '\u{3c9}', EastAsianWidth::Ambiguous),
+        ('\u{401}', '\u{401}', EastAsianWidth::Ambiguous),
+        ('\u{410}', '\u{44f}', EastAsianWidth::Ambiguous),
+        ('\u{451}', '\u{451}', EastAsianWidth::Ambiguous),
+        ('\u{1100}', '\u{115f}', EastAsianWidth::Wide),
+        ('\u{2010}', '\u{2010}', EastAsianWidth::Ambiguous),
+        ('\u{2013}', '\u{2016}', EastAsianWidth::Ambiguous),
+        ('\u{2018}', '\u{2019}', EastAsianWidth::Ambiguous),
+        ('\u{201c}', '\u{201d}', EastAsianWidth::Ambiguous),
+        ('\u{2020}', '\u{2022}', EastAsianWidth::Ambiguous),
+        ('\u{2024}', '\u{2027}', EastAsianWidth::Ambiguous),
+        ('\u{2030}', '\u{2030}', EastAsianWidth::Ambiguous),
+        ('\u{2032}', '\u{2033}', EastAsianWidth::Ambiguous),
+        ('\u{2035}', '\u{2035}', EastAsianWidth::Ambiguous),
+        ('\u{203b}', '\u{203b}', EastAsianWidth::Ambiguous),
+        ('\u{203e}', '\u{203e}', EastAsianWidth::Ambiguous),
+        ('\u{2074}', '\u{2074}', EastAsianWidth::Ambiguous),
+        ('\u{207f}', '\u{207f}', EastAsianWidth::Ambiguous),
+        ('\u{2081}', '\u{2084}', EastAsianWidth::Ambiguous),
+        ('\u{20a9}', '\u{20a9}', EastAsianWidth::HalfWidth),
+        ('\u{20ac}', '\u{20ac}', EastAsianWidth::Ambiguous),
+        ('\u{2103}', '\u{2103}', EastAsianWidth::Ambiguous),
+        ('\u{2105}', '\u{2105}', EastAsianWidth::Ambiguous),
+        ('\u{2109}', '\u{2109}', EastAsianWidth::Ambiguous),
+        ('\u{2113}', '\u{2113}', EastAsianWidth::Ambiguous),
+        ('\u{2116}', '\u{2116}', EastAsianWidth::Ambiguous),
+        ('\u{2121}', '\u{2122}', EastAsianWidth::Ambiguous),
+        ('\u{2126}', '\u{2126}', EastAsianWidth::Ambiguous),
+        ('\u{212b}', '\u{212b}', EastAsianWidth::Ambiguous),
+        ('\u{2153}', '\u{2154}', EastAsianWidth::Ambiguous),
+        ('\u{215b}', '\u{215e}', EastAsianWidth::Ambiguous),
+        ('\u{2160}', '\u{216b}', EastAsianWidth::Ambiguous),
+        ('\u{2170}', '\u{2179}', EastAsianWidth::Ambiguous),
+        ('\u{2189}', '\u{2189}', EastAsianWidth::Ambiguous),
+        ('\u{2190}', '\u{2199}', EastAsianWidth::Ambiguous),
+        ('\u{21b8}', '\u{21b9}', EastAsianWidth::Ambiguous),
+        ('\u{21d2}', '\u{21d2}', EastAsianWidth::Ambiguous),
+        ('\u{21d4}', '\u{21d4}', EastAsianWidth::Ambiguous),
+        ('\u{21e7}', '\u{21e7}', EastAsianWidth::Ambiguous),
+        ('\u{2200}', '\u{2200}', EastAsianWidth::Ambiguous),
+        ('\u{2202}', '\u{2203}', EastAsianWidth::Ambiguous),
+        ('\u{2207}', '\u{2208}', EastAsianWidth::Ambiguous),
+        ('\u{220b}', '\u{220b}', EastAsianWidth::Ambiguous),
+        ('\u{220f}', '\u{220f}', EastAsianWidth::Ambiguous),
+        ('\u{2211}', '\u{2211}', EastAsianWidth::Ambiguous),
+        ('\u{2215}', '\u{2215}', EastAsianWidth::Ambiguous),
+        ('\u{221a}', '\u{221a}', EastAsianWidth::Ambiguous),
+        ('\u{221d}', '\u{2220}', EastAsianWidth::Ambiguous),
+        ('\u{2223}', '\u{2223}', EastAsianWidth::Ambiguous),
+        ('\u{2225}', '\u{2225}', EastAsianWidth::Ambiguous),
+        ('\u{2227}', '\u{222c}', EastAsianWidth::Ambiguous),
+        ('\u{222e}', '\u{222e}', EastAsianWidth::Ambiguous),
+        ('\u{2234}', '\u{2237}', EastAsianWidth::Ambiguous),
+        ('\u{223c}', '\u{223d}', EastAsianWidth::Ambiguous),
+        ('\u{2248}', '\u{2248}', EastAsianWidth::Ambiguous),
+        ('\u{224c}', '\u{224c}', EastAsianWidth::Ambiguous),
+        ('\u{2252}', '\u{2252}', EastAsianWidth::Ambiguous),
+        ('\u{2260}', '\u{2261}', EastAsianWidth::Ambiguous),
+        ('\u{2264}', '\u{2267}', EastAsianWidth::Ambiguous),
+        ('\u{226a}', '\u{226b}', EastAsianWidth::Ambiguous),
+        ('\u{226e}', '\u{226f}', EastAsianWidth::Ambiguous),
+        ('\u{2282}', '\u{2283}', EastAsianWidth::Ambiguous),
+        ('\u{2286}', '\u{2287}', EastAsianWidth::Ambiguous),
+        ('\u{2295}', '\u{2295}', EastAsianWidth::Ambiguous),
+        ('\u{2299}', '\u{2299}', EastAsianWidth::Ambiguous),
+        ('\u{22a5}', '\u{22a5}', EastAsianWidth::Ambiguous),
+        ('\u{22bf}', '\u{22bf}', EastAsianWidth::Ambiguous),
+        ('\u{2312}', '\u{2312}', EastAsianWidth::Ambiguous),
+        ('\u{231a}', '\u{231b}', EastAsianWidth::Wide),
+        ('\u{2329}', '\u{232a}', EastAsianWidth::Wide),
+        ('\u{23e9}', '\u{23ec}', EastAsianWidth::Wide),
+        ('\u{23f0}', '\u{23f0}', EastAsianWidth::Wide),
+        ('\u{23f3}', '\u{23f3}', EastAsianWidth::Wide),
+        ('\u{2460}', '\u{24e9}', EastAsianWidth::Ambiguous),
+        ('\u{24eb}', '\u{254b}', EastAsianWidth::Ambiguous),
+        ('\u{2550}', '\u{2573}', EastAsianWidth::Ambiguous),
+        ('\u{2580}', '\u{258f}', EastAsianWidth::Ambiguous),
+        ('\u{2592}', '\u{2595}', EastAsianWidth::Ambiguous),
+        ('\u{25a0}', '\u{25a1}', EastAsianWidth::Ambiguous),
+        ('\u{25a3}', '\u{25a9}', EastAsianWidth::Ambiguous),
+        ('\u{25b2}', '\u{25b3}', EastAsianWidth::Ambiguous),
+        ('\u{25b6}', '\u{25b7}', EastAsianWidth::Ambiguous),
+        ('\u{25bc}', '\u{25bd}', EastAsianWidth::Ambiguous),
+        ('\u{25c0}', '\u{25c1}', EastAsianWidth::Ambiguous),
+        ('\u{25c6}', '\u{25c8}', EastAsianWidth::Ambiguous),
+        ('\u{25cb}', '\u{25cb}', EastAsianWidth::Ambiguous),
+        ('\u{25ce}', '\u{25d1}', EastAsianWidth::Ambiguous),
+        ('\u{25e2}', '\u{25e5}', EastAsianWidth::Ambiguous),
+        ('\u{25ef}', '\u{25ef}', EastAsianWidth::Ambiguous),
+        ('\u{25fd}', '\u{25fe}', EastAsianWidth::Wide),
+        ('\u{2605}', '\u{2606}', EastAsianWidth::Ambiguous),
+        ('\u{2609}', '\u{2609}', EastAsianWidth::Ambiguous),
+        ('\u{260e}', '\u{260f}', EastAsianWidth::Ambiguous),
+        ('\u{2614}', '\u{2615}', EastAsianWidth::Wide),
+        ('\u{261c}', '\u{261c}', EastAsianWidth::Ambiguous),
+        ('\u{261e}', '\u{261e}', EastAsianWidth::Ambiguous),
+        ('\u{2640}', '\u{2640}', EastAsianWidth::Ambiguous),
+        ('\u{2642}', '\u{2642}', EastAsianWidth::Ambiguous),
+        ('\u{2648}', '\u{2653}', EastAsianWidth::Wide),
+        ('\u{2660}', '\u{2661}', EastAsianWidth::Ambiguous),
+        ('\u{2663}', '\u{2665}', EastAsianWidth::Ambiguous),
+        ('\u{2667}', '\u{266a}', EastAsianWidth::Ambiguous),
+        ('\u{266c}', '\u{266d}', EastAsianWidth::Ambiguous),
+        ('\u{266f}', '\u{266f}', EastAsianWidth::Ambiguous),
+        ('\u{267f}', '\u{267f}', EastAsianWidth::Wide),
+        ('\u{2693}', '\u{2693}', EastAsianWidth::Wide),
+        ('\u{269e}', '\u{269f}', EastAsianWidth::Ambiguous),
+        ('\u{26a1}', '\u{26a1}', EastAsianWidth::Wide),
+        ('\u{26aa}', '\u{26ab}', EastAsianWidth::Wide),
+        ('\u{26bd}', '\u{26be}', EastAsianWidth::Wide),
+        ('\u{26bf}', '\u{26bf}', EastAsianWidth::Ambiguous),
+        ('\u{26c4}', '\u{26c5}', EastAsianWidth::Wide),
+        ('\u{26c6}', '\u{26cd}', EastAsianWidth::Ambiguous),
+        ('\u{26ce}', '\u{26ce}', EastAsianWidth::Wide),
+        ('\u{26cf}', '\u{26d3}', EastAsianWidth::Ambiguous),
+        ('\u{26d4}', '\u{26d4}', EastAsianWidth::Wide),
+        ('\u{26d5}', '\u{26e1}', EastAsianWidth::Ambiguous),
+        ('\u{26e3}', '\u{26e3}', EastAsianWidth::Ambiguous),
+        ('\u{26e8}', '\u{26e9}', EastAsianWidth::Ambiguous),
+        ('\u{26ea}', '\u{26ea}', EastAsianWidth::Wide),
+        ('\u{26eb}', '\u{26f1}', EastAsianWidth::Ambiguous),
+        ('\u{26f2}', '\u{26f3}', EastAsianWidth::Wide),
+        ('\u{26f4}', '\u{26f4}', EastAsianWidth::Ambiguous),
+        ('\u{26f5}', '\u{26f5}', EastAsianWidth::Wide),
+        ('\u{26f6}', '\u{26f9}', EastAsianWidth::Ambiguous),
+        ('\u{26fa}', '\u{26fa}', EastAsianWidth::Wide),
+        ('\u{26fb}', '\u{26fc}', EastAsianWidth::Ambiguous),
+        ('\u{26fd}', '\u{26fd}', EastAsianWidth::Wide),
+        ('\u{26fe}', '\u{26ff}', EastAsianWidth::Ambiguous),
+        ('\u{2705}', '\u{2705}', EastAsianWidth::Wide),
+        ('\u{270a}', '\u{270b}', EastAsianWidth::Wide),
+        ('\u{2728}', '\u{2728}', EastAsianWidth::Wide),
+        ('\u{273d}', '\u{273d}', EastAsianWidth::Ambiguous),
+        ('\u{274c}', '\u{274c}', EastAsianWidth::Wide),
+        ('\u{274e}', '\u{274e}', EastAsianWidth::Wide),
+        ('\u{2753}', '\u{2755}', EastAsianWidth::Wide),
+        ('\u{2757}', '\u{2757}', EastAsianWidth::Wide),
+        ('\u{2776}', '\u{277f}', EastAsianWidth::Ambiguous),
+        ('\u{2795}', '\u{2797}', EastAsianWidth::Wide),
+        ('\u{27b0}', '\u{27b0}', EastAsianWidth::Wide),
+        ('\u{27bf}', '\u{27bf}', EastAsianWidth::Wide),
+        ('\u{27e6}', '\u{27ed}', EastAsianWidth::Narrow),
+        ('\u{2985}', '\u{2986}', EastAsianWidth::Narrow),
+        ('\u{2b1b}', '\u{2b1c}', EastAsianWidth::Wide),
+        ('\u{2b50}', '\u{2b50}', EastAsianWidth::Wide),
+        ('\u{2b55}', '\u{2b55}', EastAsianWidth::Wide),
+        ('\u{2b56}', '\u{2b59}', EastAsianWidth::Ambiguous),
+        ('\u{2e80}', '\u{2e99}', EastAsianWidth::Wide),
+        ('\u{2e9b}', '\u{2ef3}', EastAsianWidth::Wide),
+        ('\u{2f00}', '\u{2fd5}', EastAsianWidth::Wide),
+        ('\u{2ff0}', '\u{2fff}', EastAsianWidth::Wide),
+        ('\u{3000}', '\u{3000}', EastAsianWidth::FullWidth),
+        ('\u{3001}', '\u{303e}', EastAsianWidth::Wide),
+        ('\u{3041}', '\u{3096}', EastAsianWidth::Wide),
+        ('\u{3099}', '\u{30ff}', EastAsianWidth::Wide),
+        ('\u{3105}', '\u{312f}', EastAsianWidth::Wide),
+        ('\u{3131}', '\u{318e}', EastAsianWidth::Wide),
+        ('\u{3190}', '\u{31e3}', EastAsianWidth::Wide),
+        ('\u{31ef}', '\u{321e}', EastAsianWidth::Wide),
+        ('\u{3220}', '\u{3247}', EastAsianWidth::Wide),
+        ('\u{3248}', '\u{324f}', EastAsianWidth::Ambiguous),
+        ('\u{3250}', '\u{4dbf}', EastAsianWidth::Wide),
+        ('\u{4e00}', '\u{a48c}', EastAsianWidth::Wide),
+        ('\u{a490}', '\u{a4c6}', EastAsianWidth::Wide),
+        ('\u{a960}', '\u{a97c}', EastAsianWidth::Wide),
+        ('\u{ac00}', '\u{d7a3}', EastAsianWidth::Wide),
+        ('\u{e000}', '\u{f8ff}', EastAsianWidth::Ambiguous),
+        ('\u{f900}', '\u{faff}', EastAsianWidth::Wide),
+        ('\u{fe00}', '\u{fe0f}', EastAsianWidth::Ambiguous),
+        ('\u{fe10}', '\u{fe19}', EastAsianWidth::Wide),
+        ('\u{fe30}', '\u{fe52}', EastAsianWidth::Wide),
+        ('\u{fe54}', '\u{fe66}', EastAsianWidth::Wide),
+        ('\u{fe68}', '\u{fe6b}', EastAsianWidth::Wide),
+        ('\u{ff01}', '\u{ff60}', EastAsianWidth::FullWidth),
+        ('\u{ff61}', '\u{ffbe}', EastAsianWidth::HalfWidth),
+        ('\u{ffc2}', '\u{ffc7}', EastAsianWidth::HalfWidth),
+        ('\u{ffca}', '\u{ffcf}', EastAsianWidth::HalfWidth),
+        ('\u{ffd2}', '\u{ffd7}', EastAsianWidth::HalfWidth),
+        ('\u{ffda}', '\u{ffdc}', EastAsianWidth::HalfWidth),
+        ('\u{ffe0}', '\u{ffe6}', EastAsianWidth::FullWidth),
+        ('\u{ffe8}', '\u{ffee}', EastAsianWidth::HalfWidth),
+        ('\u{fffd}', '\u{fffd}', EastAsianWidth::Ambiguous),
+        ('\u{16fe0}', '\u{16fe4}', EastAsianWidth::Wide),
+        ('\u{16ff0}', '\u{16ff1}', EastAsianWidth::Wide),
+        ('\u{17000}', '\u{187f7}', EastAsianWidth::Wide),
+        ('\u{18800}', '\u{18cd5}', EastAsianWidth::Wide),
+        ('\u{18d00}', '\u{18d08}', EastAsianWidth::Wide),
+        ('\u{1aff0}', '\u{1aff3}', EastAsianWidth::Wide),
+        ('\u{1aff5}', '\u{1affb}', EastAsianWidth::Wide),
+        ('\u{1affd}', '\u{1affe}', EastAsianWidth::Wide),
+        ('\u{1b000}', '\u{1b122}', EastAsianWidth::Wide),
+        ('\u{1b132}', '\u{1b132}', EastAsianWidth::Wide),
+        ('\u{1b150}', '\u{1b152}', EastAsianWidth::Wide),
+        ('\u{1b155}', '\u{1b155}', EastAsianWidth::Wide),
+        ('\u{1b164}', '\u{1b167}', EastAsianWidth::Wide),
+        ('\u{1b170}', '\u{1b2fb}', EastAsianWidth::Wide),
+        ('\u{1f004}', '\u{1f004}', EastAsianWidth::Wide),
+        ('\u{1f0cf}', '\u{1f0cf}', EastAsianWidth::Wide),
+        ('\u{1f100}', '\u{1f10a}', EastAsianWidth::Ambiguous),
+        ('\u{1f110}', '\u{1f12d}', EastAsianWidth::Ambiguous),
+        ('\u{1f130}', '\u{1f169}', EastAsianWidth::Ambiguous),
+        ('\u{1f170}', '\u{1f18d}', EastAsianWidth::Ambiguous),
+        ('\u{1f18e}', '\u{1f18e}', EastAsianWidth::Wide),
+        ('\u{1f18f}', '\u{1f190}', EastAsianWidth::Ambiguous),
+        ('\u{1f191}', '\u{1f19a}', EastAsianWidth::Wide),
+        ('\u{1f19b}', '\u{1f1ac}', EastAsianWidth::Ambiguous),
+        ('\u{1f200}', '\u{1f202}', EastAsianWidth::Wide),
+        ('\u{1f210}', '\u{1f23b}', EastAsianWidth::Wide),
+        ('\u{1f240}', '\u{1f248}', EastAsianWidth::Wide),
+        ('\u{1f250}', '\u{1f251}', EastAsianWidth::Wide),
+        ('\u{1f260}', '\u{1f265}', EastAsianWidth::Wide),
+        ('\u{1f300}', '\u{1f320}', EastAsianWidth::Wide),
+        ('\u{1f32d}', '\u{1f335}', EastAsianWidth::Wide),
+        ('\u{1f337}', '\u{1f37c}', EastAsianWidth::Wide),
+        ('\u{1f37e}', '\u{1f393}', EastAsianWidth::Wide),
+        ('\u{1f3a0}', '\u{1f3ca}', EastAsianWidth::Wide),
+        ('\u{1f3cf}', '\u{1f3d3}', EastAsianWidth::Wide),
+        ('\u{1f3e0}', '\u{1f3f0}', EastAsianWidth::Wide),
+        ('\u{1f3f4}', '\u{1f3f4}', EastAsianWidth::Wide),
+        ('\u{1f3f8}', '\u{1f43e}', EastAsianWidth::Wide),
+        ('\u{1f440}', '\u{1f440}', EastAsianWidth::Wide),
+        ('\u{1f442}', '\u{1f4fc}', EastAsianWidth::Wide),
+        ('\u{1f4ff}', '\u{1f53d}', EastAsianWidth::Wide),
+        ('\u{1f54b}', '\u{1f54e}', EastAsianWidth::Wide),
+        ('\u{1f550}', '\u{1f567}', EastAsianWidth::Wide),
+        ('\u{1f57a}', '\u{1f57a}', EastAsianWidth::Wide),
+        ('\u{1f595}', '\u{1f596}', EastAsianWidth::Wide),
+        ('\u{1f5a4}', '\u{1f5a4}', EastAsianWidth::Wide),
+        ('\u{1f5fb}', '\u{1f64f}', EastAsianWidth::Wide),
+        ('\u{1f680}', '\u{1f6c5}', EastAsianWidth::Wide),
+        ('\u{1f6cc}', '\u{1f6cc}', EastAsianWidth::Wide),
+        ('\u{1f6d0}', '\u{1f6d2}', EastAsianWidth::Wide),
+        ('\u{1f6d5}', '\u{1f6d7}', EastAsianWidth::Wide),
+        ('\u{1f6dc}', '\u{1f6df}', EastAsianWidth::Wide),
+        ('\u{1f6eb}', '\u{1f6ec}', EastAsianWidth::Wide),
+        ('\u{1f6f4}', '\u{1f6fc}', EastAsianWidth::Wide),
+        ('\u{1f7e0}', '\u{1f7eb}', EastAsianWidth::Wide),
+        ('\u{1f7f0}', '\u{1f7f0}', EastAsianWidth::Wide),
+        ('\u{1f90c}', '\u{1f93a}', EastAsianWidth::Wide),
+        ('\u{1f93c}', '\u{1f945}', EastAsianWidth::Wide),
+        ('\u{1f947}', '\u{1f9ff}', EastAsianWidth::Wide),
+        ('\u{1fa70}', '\u{1fa7c}', EastAsianWidth::Wide),
+        ('\u{1fa80}', '\u{1fa88}', EastAsianWidth::Wide),
+        ('\u{1fa90}', '\u{1fabd}', EastAsianWidth::Wide),
+        ('\u{1fabf}', '\u{1fac5}', EastAsianWidth::Wide),
+        ('\u{1face}', '\u{1fadb}', EastAsianWidth::Wide),
+        ('\u{1fae0}', '\u{1fae8}', EastAsianWidth::Wide),
+        ('\u{1faf0}', '\u{1faf8}', EastAsianWidth::Wide),
+        ('\u{20000}', '\u{2fffd}', EastAsianWidth::Wide),
+        ('\u{30000}', '\u{3fffd}', EastAsianWidth::Wide),
+        ('\u{e0100}', '\u{e01ef}', EastAsianWidth::Ambiguous),
+        ('\u{f0000}', '\u{ffffd}', EastAsianWidth::Ambiguous),
+        ('\u{100000}', '\u{10fffd}', EastAsianWidth::Ambiguous),
+    ];
+
+    /// Autogenerated. 953 sorted, non-overlapping range(s) of characters with a known script.
+    static SCRIPT_TABLE: [(char, char, Script); 953] = [
+        ('\u{0}', '\u{40}', Script::Common),
+        ('\u{41}', '\u{5a}', Script::Latin),
+        ('\u{5b}', '\u{60}', Script::Common),
+        ('\u{61}', '\u{7a}', Script::Latin),
+        ('\u{7b}', '\u{a9}', Script::Common),
+        ('\u{aa}', '\u{aa}', Script::Latin),
+        ('\u{ab}', '\u{b9}', Script::Common),
+        ('\u{ba}', '\u{ba}', Script::Latin),
+        ('\u{bb}', '\u{bf}', Script::Common),
+        ('\u{c0}', '\u{d6}', Script::Latin),
+        ('\u{d7}', '\u{d7}', Script::Common),
+        ('\u{d8}', '\u{f6}', Script::Latin),
+        ('\u{f7}', '\u{f7}', Script::Common),
+        ('\u{f8}', '\u{2b8}', Script::Latin),
+        ('\u{2b9}', '\u{2df}', Script::Common),
+        ('\u{2e0}', '\u{2e4}', Script::Latin),
+        ('\u{2e5}', '\u{2e9}', Script::Common),
+        ('\u{2ea}', '\u{2eb}', Script::Bopomofo),
+        ('\u{2ec}', '\u{2ff}', Script::Common),
+        ('\u{300}', '\u{36f}', Script::Inherited),
+        ('\u{370}', '\u{373}', Script::Greek),
+        ('\u{374}', '\u{374}', Script::Common),
+        ('\u{375}', '\u{377}', Script::Greek),
+        ('\u{37a}', '\u{37d}', Script::Greek),
+        ('\u{37e}', '\u{37e}', Script::Common),
+        ('\u{37f}', '\u{37f}', Script::Greek),
+        ('\u{384}', '\u{384}', Script::Greek),
+        ('\u{385}', '\u{385}', Script::Common),
+        ('\u{386}', '\u{386}', Script::Greek),
+        ('\u{387}', '\u{387}', Script::Common),
+        ('\u{388}', '\u{38a}', Script::Greek),
+        ('\u{38c}', '\u{38c}', Script::Greek),
+        ('\u{38e}', '\u{3a1}', Script::Greek),
+        ('\u{3a3}', '\u{3e1}', Script::Greek),
+        ('\u{3e2}', '\u{3ef}', Script::Coptic),
+        ('\u{3f0}', '\u{3ff}', Script::Greek),
+        ('\u{400}', '\u{484}', Script::Cyrillic),
+        ('\u{485}', '\u{486}', Script::Inherited),
+        ('\u{487}', '\u{52f}', Script::Cyrillic),
+        ('\u{531}', '\u{556}', Script::Armenian),
+        ('\u{559}', '\u{58a}', Script::Armenian),
+        ('\u{58d}', '\u{58f}', Script::Armenian),
+        ('\u{591}', '\u{5c7}', Script::Hebrew),
+        ('\u{5d0}', '\u{5ea}', Script::Hebrew),
+        ('\u{5ef}', '\u{5f4}', Script::Hebrew),
+        ('\u{600}', '\u{604}', Script::Arabic),
+        ('\u{605}', '\u{605}', Script::Common),
+        ('\u{606}', '\u{60b}', Script::Arabic),
+        ('\u{60c}', '\u{60c}', Script::Common),
+        ('\u{60d}', '\u{61a}', Script::Arabic),
+        ('\u{61b}', '\u{61b}', Script::Common),
+        ('\u{61c}', '\u{61e}', Script::Arabic),
+        ('\u{61f}', '\u{61f}', Script::Common),
+        ('\u{620}', '\u{63f}', Script::Arabic),
+        ('\u{640}', '\u{640}', Script::Common),
+        ('\u{641}', '\u{64a}', Script::Arabic),
+        ('\u{64b}', '\u{655}', Script::Inherited),
+        ('\u{656}', '\u{66f}', Script::Arabic),
+        ('\u{670}', '\u{670}', Script::Inherited),
+        ('\u{671}', '\u{6dc}', Script::Arabic),
+        ('\u{6dd}', '\u{6dd}', Script::Common),
+        ('\u{6de}', '\u{6ff}', Script::Arabic),
+        ('\u{700}', '\u{70d}', Script::Syriac),
+        ('\u{70f}', '\u{74a}', Script::Syriac),
+        ('\u{74d}', '\u{74f}', Script::Syriac),
+        ('\u{750}', '\u{77f}', Script::Arabic),
+        ('\u{780}', '\u{7b1}', Script::Thaana),
+        ('\u{7c0}', '\u{7fa}', Script::Nko),
+        ('\u{7fd}', '\u{7ff}', Script::Nko),
+        ('\u{800}', '\u{82d}', Script::Samaritan),
+        ('\u{830}', '\u{83e}', Script::Samaritan),
+        ('\u{840}', '\u{85b}', Script::Mandaic),
+        ('\u{85e}', '\u{85e}', Script::Mandaic),
+        ('\u{860}', '\u{86a}', Script::Syriac),
+        ('\u{870}', '\u{88e}', Script::Arabic),
+        ('\u{890}', '\u{891}', Script::Arabic),
+        ('\u{898}', '\u{8e1}', Script::Arabic),
+        ('\u{8e2}', '\u{8e2}', Script::Common),
+        ('\u{8e3}', '\u{8ff}', Script::Arabic),
+        ('\u{900}', '\u{950}', Script::Devanagari),
+        ('\u{951}', '\u{954}', Script::Inherited),
+        ('\u{955}', '\u{963}', Script::Devanagari),
+        ('\u{964}', '\u{965}', Script::Common),
+        ('\u{966}', '\u{97f}', Script::Devanagari),
+        ('\u{980}', '\u{983}', Script::Bengali),
+        ('\u{985}', '\u{98c}', Script::Bengali),
+        ('\u{98f}', '\u{990}', Script::Bengali),
+        ('\u{993}', '\u{9a8}', Script::Bengali),
+        ('\u{9aa}', '\u{9b0}', Script::Bengali),
+        ('\u{9b2}', '\u{9b2}', Script::Bengali),
+        ('\u{9b6}', '\u{9b9}', Script::Bengali),
+        ('\u{9bc}', '\u{9c4}', Script::Bengali),
+        ('\u{9c7}', '\u{9c8}', Script::Bengali),
+        ('\u{9cb}', '\u{9ce}', Script::Bengali),
+        ('\u{9d7}', '\u{9d7}', Script::Bengali),
+        ('\u{9dc}', '\u{9dd}', Script::Bengali),
+        ('\u{9df}', '\u{9e3}', Script::Bengali),
+        ('\u{9e6}', '\u{9fe}', Script::Bengali),
+        ('\u{a01}', '\u{a03}', Script::Gurmukhi),
+        ('\u{a05}', '\u{a0a}', Script::Gurmukhi),
+        ('\u{a0f}', '\u{a10}', Script::Gurmukhi),
+        ('\u{a13}', '\u{a28}', Script::Gurmukhi),
+        ('\u{a2a}', '\u{a30}', Script::Gurmukhi),
+        ('\u{a32}', '\u{a33}', Script::Gurmukhi),
+        ('\u{a35}', '\u{a36}', Script::Gurmukhi),
+        ('\u{a38}', '\u{a39}', Script::Gurmukhi),
+        ('\u{a3c}', '\u{a3c}', Script::Gurmukhi),
+        ('\u{a3e}', '\u{a42}', Script::Gurmukhi),
+        ('\u{a47}', '\u{a48}', Script::Gurmukhi),
+        ('\u{a4b}', '\u{a4d}', Script::Gurmukhi),
+        ('\u{a51}', '\u{a51}', Script::Gurmukhi),
+        ('\u{a59}', '\u{a5c}', Script::Gurmukhi),
+        ('\u{a5e}', '\u{a5e}', Script::Gurmukhi),
+        ('\u{a66}', '\u{a76}', Script::Gurmukhi),
+        ('\u{a81}', '\u{a83}', Script::Gujarati),
+        ('\u{a85}', '\u{a8d}', Script::Gujarati),
+        ('\u{a8f}', '\u{a91}', Script::Gujarati),
+        ('\u{a93}', '\u{aa8}', Script::Gujarati),
+        ('\u{aaa}', '\u{ab0}', Script::Gujarati),
+        ('\u{ab2}', '\u{ab3}', Script::Gujarati),
+        ('\u{ab5}', '\u{ab9}', Script::Gujarati),
+        ('\u{abc}', '\u{ac5}', Script::Gujarati),
+        ('\u{ac7}', '\u{ac9}', Script::Gujarati),
+        ('\u{acb}', '\u{acd}', Script::Gujarati),
+        ('\u{ad0}', '\u{ad0}', Script::Gujarati),
+        ('\u{ae0}', '\u{ae3}', Script::Gujarati),
+        ('\u{ae6}', '\u{af1}', Script::Gujarati),
+        ('\u{af9}', '\u{aff}', Script::Gujarati),
+        ('\u{b01}', '\u{b03}', Script::Oriya),
+        ('\u{b05}', '\u{b0c}', Script::Oriya),
+        ('\u{b0f}', '\u{b10}', Script::Oriya),
+        ('\u{b13}', '\u{b28}', Script::Oriya),
+        ('\u{b2a}', '\u{b30}', Script::Oriya),
+        ('\u{b32}', '\u{b33}', Script::Oriya),
+        ('\u{b35}', '\u{b39}', Script::Oriya),
+        ('\u{b3c}', '\u{b44}', Script::Oriya),
+        ('\u{b47}', '\u{b48}', Script::Oriya),
+        ('\u{b4b}', '\u{b4d}', Script::Oriya),
+        ('\u{b55}', '\u{b57}', Script::Oriya),
+        ('\u{b5c}', '\u{b5d}', Script::Oriya),
+        ('\u{b5f}', '\u{b63}', Script::Oriya),
+        ('\u{b66}', '\u{b77}', Script::Oriya),
+        ('\u{b82}', '\u{b83}', Script::Tamil),
+        ('\u{b85}', '\u{b8a}', Script::Tamil),
+        ('\u{b8e}', '\u{b90}', Script::Tamil),
+        ('\u{b92}', '\u{b95}', Script::Tamil),
+        ('\u{b99}', '\u{b9a}', Script::Tamil),
+        ('\u{b9c}', '\u{b9c}', Script::Tamil),
+        ('\u{b9e}', '\u{b9f}', Script::Tamil),
+        ('\u{ba3}', '\u{ba4}', Script::Tamil),
+        ('\u{ba8}', '\u{baa}', Script::Tamil),
+        ('\u{bae}', '\u{bb9}', Script::Tamil),
+        ('\u{bbe}', '\u{bc2}', Script::Tamil),
+        ('\u{bc6}', '\u{bc8}', Script::Tamil),
+        ('\u{bca}', '\u{bcd}', Script::Tamil),
+        ('\u{bd0}', '\u{bd0}', Script::Tamil),
+        ('\u{bd7}', '\u{bd7}', Script::Tamil),
+        ('\u{be6}', '\u{bfa}', Script::Tamil),
+        ('\u{c00}', '\u{c0c}', Script::Telugu),
+        ('\u{c0e}', '\u{c10}', Script::Telugu),
+        ('\u{c12}', '\u{c28}', Script::Telugu),
+        ('\u{c2a}', '\u{c39}', Script::Telugu),
+        ('\u{c3c}', '\u{c44}', Script::Telugu),
+        ('\u{c46}', '\u{c48}', Script::Telugu),
+        ('\u{c4a}', '\u{c4d}', Script::Telugu),
+        ('\u{c55}', '\u{c56}', Script::Telugu),
+        ('\u{c58}', '\u{c5a}', Script::Telugu),
+        ('\u{c5d}', '\u{c5d}', Script::Telugu),
+        ('\u{c60}', '\u{c63}', Script::Telugu),
+        ('\u{c66}', '\u{c6f}', Script::Telugu),
+        ('\u{c77}', '\u{c7f}', Script::Telugu),
+        ('\u{c80}', '\u{c8c}', Script::Kannada),
+        ('\u{c8e}', '\u{c90}', Script::Kannada),
+        ('\u{c92}', '\u{ca8}', Script::Kannada),
+        ('\u{caa}', '\u{cb3}', Script::Kannada),
+        ('\u{cb5}', '\u{cb9}', Script::Kannada),
+        ('\u{cbc}', '\u{cc4}', Script::Kannada),
+        ('\u{cc6}', '\u{cc8}', Script::Kannada),
+        ('\u{cca}', '\u{ccd}', Script::Kannada),
+        ('\u{cd5}', '\u{cd6}', Script::Kannada),
+        ('\u{cdd}', '\u{cde}', Script::Kannada),
+        ('\u{ce0}', '\u{ce3}', Script::Kannada),
+        ('\u{ce6}', '\u{cef}', Script::Kannada),
+        ('\u{cf1}', '\u{cf3}', Script::Kannada),
+        ('\u{d00}', '\u{d0c}', Script::Malayalam),
+        ('\u{d0e}', '\u{d10}', Script::Malayalam),
+        ('\u{d12}', '\u{d44}', Script::Malayalam),
+        ('\u{d46}', '\u{d48}', Script::Malayalam),
+        ('\u{d4a}', '\u{d4f}', Script::Malayalam),
+        ('\u{d54}', '\u{d63}', Script::Malayalam),
+        ('\u{d66}', '\u{d7f}', Script::Malayalam),
+        ('\u{d81}', '\u{d83}', Script::Sinhala),
+        ('\u{d85}', '\u{d96}', Script::Sinhala),
+        ('\u{d9a}', '\u{db1}', Script::Sinhala),
+        ('\u{db3}', '\u{dbb}', Script::Sinhala),
+        ('\u{dbd}', '\u{dbd}', Script::Sinhala),
+        ('\u{dc0}', '\u{dc6}', Script::Sinhala),
+        ('\u{dca}', '\u{dca}', Script::Sinhala),
+        ('\u{dcf}', '\u{dd4}', Script::Sinhala),
+        ('\u{dd6}', '\u{dd6}', Script::Sinhala),
+        ('\u{dd8}', '\u{ddf}', Script::Sinhala),
+        ('\u{de6}', '\u{def}', Script::Sinhala),
+        ('\u{df2}', '\u{df4}', Script::Sinhala),
+        ('\u{e01}', '\u{e3a}', Script::Thai),
+        ('\u{e3f}', '\u{e3f}', Script::Common),
+        ('\u{e40}', '\u{e5b}', Script::Thai),
+        ('\u{e81}', '\u{e82}', Script::Lao),
+        ('\u{e84}', '\u{e84}', Script::Lao),
+        ('\u{e86}', '\u{e8a}', Script::Lao),
+        ('\u{e8c}', '\u{ea3}', Script::Lao),
+        ('\u{ea5}', '\u{ea5}', Script::Lao),
+        ('\u{ea7}', '\u{ebd}', Script::Lao),
+        ('\u{ec0}', '\u{ec4}', Script::Lao),
+        ('\u{ec6}', '\u{ec6}', Script::Lao),
+        ('\u{ec8}', '\u{ece}', Script::Lao),
+        ('\u{ed0}', '\u{ed9}', Script::Lao),
+        ('\u{edc}', '\u{edf}', Script::Lao),
+        ('\u{f00}', '\u{f47}', Script::Tibetan),
+        ('\u{f49}', '\u{f6c}', Script::Tibetan),
+        ('\u{f71}', '\u{f97}', Script::Tibetan),
+        ('\u{f99}', '\u{fbc}', Script::Tibetan),
+        ('\u{fbe}', '\u{fcc}', Script::Tibetan),
+        ('\u{fce}', '\u{fd4}', Script::Tibetan),
+        ('\u{fd5}', '\u{fd8}', Script::Common),
+        ('\u{fd9}', '\u{fda}', Script::Tibetan),
+        ('\u{1000}', '\u{109f}', Script::Myanmar),
+        ('\u{10a0}', '\u{10c5}', Script::Georgian),
+        ('\u{10c7}', '\u{10c7}', Script::Georgian),
+        ('\u{10cd}', '\u{10cd}', Script::Georgian),
+        ('\u{10d0}', '\u{10fa}', Script::Georgian),
+        ('\u{10fb}', '\u{10fb}', Script::Common),
+        ('\u{10fc}', '\u{10ff}', Script::Georgian),
+        ('\u{1100}', '\u{11ff}', Script::Hangul),
+        ('\u{1200}', '\u{1248}', Script::Ethiopic),
+        ('\u{124a}', '\u{124d}', Script::Ethiopic),
+        ('\u{1250}', '\u{1256}', Script::Ethiopic),
+        ('\u{1258}', '\u{1258}', Script::Ethiopic),
+        ('\u{125a}', '\u{125d}', Script::Ethiopic),
+        ('\u{1260}', '\u{1288}', Script::Ethiopic),
+        ('\u{128a}', '\u{128d}', Script::Ethiopic),
+        ('\u{1290}', '\u{12b0}', Script::Ethiopic),
+        ('\u{12b2}', '\u{12b5}', Script::Ethiopic),
+        ('\u{12b8}', '\u{12be}', Script::Ethiopic),
+        ('\u{12c0}', '\u{12c0}', Script::Ethiopic),
+        ('\u{12c2}', '\u{12c5}', Script::Ethiopic),
+        ('\u{12c8}', '\u{12d6}', Script::Ethiopic),
+        ('\u{12d8}', '\u{1310}', Script::Ethiopic),
+        ('\u{1312}', '\u{1315}', Script::Ethiopic),
+        ('\u{1318}', '\u{135a}', Script::Ethiopic),
+        ('\u{135d}', '\u{137c}', Script::Ethiopic),
+        ('\u{1380}', '\u{1399}', Script::Ethiopic),
+        ('\u{13a0}', '\u{13f5}', Script::Cherokee),
+        ('\u{13f8}', '\u{13fd}', Script::Cherokee),
+        ('\u{1400}', '\u{167f}', Script::CanadianAboriginal),
+        ('\u{1680}', '\u{169c}', Script::Ogham),
+        ('\u{16a0}', '\u{16ea}', Script::Runic),
+        ('\u{16eb}', '\u{16ed}', Script::Common),
+        ('\u{16ee}', '\u{16f8}', Script::Runic),
+        ('\u{1700}', '\u{1715}', Script::Tagalog),
+        ('\u{171f}', '\u{171f}', Script::Tagalog),
+        ('\u{1720}', '\u{1734}', Script::Hanunoo),
+        ('\u{1735}', '\u{1736}', Script::Common),
+        ('\u{1740}', '\u{1753}', Script::Buhid),
+        ('\u{1760}', '\u{176c}', Script::Tagbanwa),
+        ('\u{176e}', '\u{1770}', Script::Tagbanwa),
+        ('\u{1772}', '\u{1773}', Script::Tagbanwa),
+        ('\u{1780}', '\u{17dd}', Script::Khmer),
+        ('\u{17e0}', '\u{17e9}', Script::Khmer),
+        ('\u{17f0}', '\u{17f9}', Script::Khmer),
+        ('\u{1800}', '\u{1801}', Script::Mongolian),
+        ('\u{1802}', '\u{1803}', Script::Common),
+        ('\u{1804}', '\u{1804}', Script::Mongolian),
+        ('\u{1805}', '\u{1805}', Script::Common),
+        ('\u{1806}', '\u{1819}', Script::Mongolian),
+        ('\u{1820}', '\u{1878}', Script::Mongolian),
+        ('\u{1880}', '\u{18aa}', Script::Mongolian),
+        ('\u{18b0}', '\u{18f5}', Script::CanadianAboriginal),
+        ('\u{1900}', '\u{191e}', Script::Limbu),
+        ('\u{1920}', '\u{192b}', Script::Limbu),
+        ('\u{1930}', '\u{193b}', Script::Limbu),
+        ('\u{1940}', '\u{1940}', Script::Limbu),
+        ('\u{1944}', '\u{194f}', Script::Limbu),
+        ('\u{1950}', '\u{196d}', Script::TaiLe),
+        ('\u{1970}', '\u{1974}', Script::TaiLe),
+        ('\u{1980}', '\u{19ab}', Script::NewTaiLue),
+        ('\u{19b0}', '\u{19c9}', Script::NewTaiLue),
+        ('\u{19d0}', '\u{19da}', Script::NewTaiLue),
+        ('\u{19de}', '\u{19df}', Script::NewTaiLue),
+        ('\u{19e0}', '\u{19ff}', Script::Khmer),
+        ('\u{1a00}', '\u{1a1b}', Script::Buginese),
+        ('\u{1a1e}', '\u{1a1f}', Script::Buginese),
+        ('\u{1a20}', '\u{1a5e}', Script::TaiTham),
+        ('\u{1a60}', '\u{1a7c}', Script::TaiTham),
+        ('\u{1a7f}', '\u{1a89}', Script::TaiTham),
+        ('\u{1a90}', '\u{1a99}', Script::TaiTham),
+        ('\u{1aa0}', '\u{1aad}', Script::TaiTham),
+        ('\u{1ab0}', '\u{1ace}', Script::Inherited),
+        ('\u{1b00}', '\u{1b4c}', Script::Balinese),
+        ('\u{1b50}', '\u{1b7e}', Script::Balinese),
+        ('\u{1b80}', '\u{1bbf}', Script::Sundanese),
+        ('\u{1bc0}', '\u{1bf3}', Script::Batak),
+        ('\u{1bfc}', '\u{1bff}', Script::Batak),
+        ('\u{1c00}', '\u{1c37}', Script::Lepcha),
+        ('\u{1c3b}', '\u{1c49}', Script::Lepcha),
+        ('\u{1c4d}', '\u{1c4f}', Script::Lepcha),
+        ('\u{1c50}', '\u{1c7f}', Script::OlChiki),
+        ('\u{1c80}', '\u{1c88}', Script::Cyrillic),
+        ('\u{1c90}', '\u{1cba}', Script::Georgian),
+        ('\u{1cbd}', '\u{1cbf}', Script::Georgian),
+        ('\u{1cc0}', '\u{1cc7}', Script::Sundanese),
+        ('\u{1cd0}', '\u{1cd2}', Script::Inherited),
+        ('\u{1cd3}', '\u{1cd3}', Script::Common),
+        ('\u{1cd4}', '\u{1ce0}', Script::Inherited),
+        ('\u{1ce1}', '\u{1ce1}', Script::Common),
+        ('\u{1ce2}', '\u{1ce8}', Script::Inherited),
+        ('\u{1ce9}', '\u{1cec}', Script::Common),
+        ('\u{1ced}', '\u{1ced}', Script::Inherited),
+        ('\u{1cee}', '\u{1cf3}', Script::Common),
+        ('\u{1cf4}', '\u{1cf4}', Script::Inherited),
+        ('\u{1cf5}', '\u{1cf7}', Script::Common),
+        ('\u{1cf8}', '\u{1cf9}', Script::Inherited),
+        ('\u{1cfa}', '\u{1cfa}', Script::Common),
+        ('\u{1d00}', '\u{1d25}', Script::Latin),
+        ('\u{1d26}', '\u{1d2a}', Script::Greek),
+        ('\u{1d2b}', '\u{1d2b}', Script::Cyrillic),
+        ('\u{1d2c}', '\u{1d5c}', Script::Latin),
+        ('\u{1d5d}', '\u{1d61}', Script::Greek),
+        ('\u{1d62}', '\u{1d65}', Script::Latin),
+        ('\u{1d66}', '\u{1d6a}', Script::Greek),
+        ('\u{1d6b}', '\u{1d77}', Script::Latin),
+        ('\u{1d78}', '\u{1d78}', Script::Cyrillic),
+        ('\u{1d79}', '\u{1dbe}', Script::Latin),
+        ('\u{1dbf}', '\u{1dbf}', Script::Greek),
+        ('\u{1dc0}', '\u{1dff}', Script::Inherited),
+        ('\u{1e00}', '\u{1eff}', Script::Latin),
+        ('\u{1f00}', '\u{1f15}', Script::Greek),
+        ('\u{1f18}', '\u{1f1d}', Script::Greek),
+        ('\u{1f20}', '\u{1f45}', Script::Greek),
+        ('\u{1f48}', '\u{1f4d}', Script::Greek),
+        ('\u{1f50}', '\u{1f57}', Script::Greek),
+        ('\u{1f59}', '\u{1f59}', Script::Greek),
+        ('\u{1f5b}', '\u{1f5b}', Script::Greek),
+        ('\u{1f5d}', '\u{1f5d}', Script::Greek),
+        ('\u{1f5f}', '\u{1f7d}', Script::Greek),
+        ('\u{1f80}', '\u{1fb4}', Script::Greek),
+        ('\u{1fb6}', '\u{1fc4}', Script::Greek),
+        ('\u{1fc6}', '\u{1fd3}', Script::Greek),
+        ('\u{1fd6}', '\u{1fdb}', Script::Greek),
+        ('\u{1fdd}', '\u{1fef}', Script::Greek),
+        ('\u{1ff2}', '\u{1ff4}', Script::Greek),
+        ('\u{1ff6}', '\u{1ffe}', Script::Greek),
+        ('\u{2000}', '\u{200b}', Script::Common),
+        ('\u{200c}', '\u{200d}', Script::Inherited),
+        ('\u{200e}', '\u{2064}', Script::Common),
+        ('\u{2066}', '\u{2070}', Script::Common),
+        ('\u{2071}', '\u{2071}', Script::Latin),
+        ('\u{2074}', '\u{207e}', Script::Common),
+        ('\u{207f}', '\u{207f}', Script::Latin),
+        ('\u{2080}', '\u{208e}', Script::Common),
+        ('\u{2090}', '\u{209c}', Script::Latin),
+        ('\u{20a0}', '\u{20c0}', Script::Common),
+        ('\u{20d0}', '\u{20f0}', Script::Inherited),
+        ('\u{2100}', '\u{2125}', Script::Common),
+        ('\u{2126}', '\u{2126}', Script::Greek),
+        ('\u{2127}', '\u{2129}', Script::Common),
+        ('\u{212a}', '\u{212b}', Script::Latin),
+        ('\u{212c}', '\u{2131}', Script::Common),
+        ('\u{2132}', '\u{2132}', Script::Latin),
+        ('\u{2133}', '\u{214d}', Script::Common),
+        ('\u{214e}', '\u{214e}', Script::Latin),
+        ('\u{214f}', '\u{215f}', Script::Common),
+        ('\u{2160}', '\u{2188}', Script::Latin),
+        ('\u{2189}', '\u{218b}', Script::Common),
+        ('\u{2190}', '\u{2426}', Script::Common),
+        ('\u{2440}', '\u{244a}', Script::Common),
+        ('\u{2460}', '\u{27ff}', Script::Common),
+        ('\u{2800}', '\u{28ff}', Script::Braille),
+        ('\u{2900}', '\u{2b73}', Script::Common),
+        ('\u{2b76}', '\u{2b95}', Script::Common),
+        ('\u{2b97}', '\u{2bff}', Script::Common),
+        ('\u{2c00}', '\u{2c5f}', Script::Glagolitic),
+        ('\u{2c60}', '\u{2c7f}', Script::Latin),
+        ('\u{2c80}', '\u{2cf3}', Script::Coptic),
+        ('\u{2cf9}', '\u{2cff}', Script::Coptic),
+        ('\u{2d00}', '\u{2d25}', Script::Georgian),
+        ('\u{2d27}', '\u{2d27}', Script::Georgian),
+        ('\u{2d2d}', '\u{2d2d}', Script::Georgian),
+        ('\u{2d30}', '\u{2d67}', Script::Tifinagh),
+        ('\u{2d6f}', '\u{2d70}', Script::Tifinagh),
+        ('\u{2d7f}', '\u{2d7f}', Script::Tifinagh),
+        ('\u{2d80}', '\u{2d96}', Script::Ethiopic),
+        ('\u{2da0}', '\u{2da6}', Script::Ethiopic),
+        ('\u{2da8}', '\u{2dae}', Script::Ethiopic),
+        ('\u{2db0}', '\u{2db6}', Script::Ethiopic),
+        ('\u{2db8}', '\u{2dbe}', Script::Ethiopic),
+        ('\u{2dc0}', '\u{2dc6}', Script::Ethiopic),
+        ('\u{2dc8}', '\u{2dce}', Script::Ethiopic),
+        ('\u{2dd0}', '\u{2dd6}', Script::Ethiopic),
+        ('\u{2dd8}', '\u{2dde}', Script::Ethiopic),
+        ('\u{2de0}', '\u{2dff}', Script::Cyrillic),
+        ('\u{2e00}', '\u{2e5d}', Script::Common),
+        ('\u{2e80}', '\u{2e99}', Script::Han),
+        ('\u{2e9b}', '\u{2ef3}', Script::Han),
+        ('\u{2f00}', '\u{2fd5}', Script::Han),
+        ('\u{2ff0}', '\u{3004}', Script::Common),
+        ('\u{3005}', '\u{3005}', Script::Han),
+        ('\u{3006}', '\u{3006}', Script::Common),
+        ('\u{3007}', '\u{3007}', Script::Han),
+        ('\u{3008}', '\u{3020}', Script::Common),
+        ('\u{3021}', '\u{3029}', Script::Han),
+        ('\u{302a}', '\u{302d}', Script::Inherited),
+        ('\u{302e}', '\u{302f}', Script::Hangul),
+        ('\u{3030}', '\u{3037}', Script::Common),
+        ('\u{3038}', '\u{303b}', Script::Han),
+        ('\u{303c}', '\u{303f}', Script::Common),
+        ('\u{3041}', '\u{3096}', Script::Hiragana),
+        ('\u{3099}', '\u{309a}', Script::Inherited),
+        ('\u{309b}', '\u{309c}', Script::Common),
+        ('\u{309d}', '\u{309f}', Script::Hiragana),
+        ('\u{30a0}', '\u{30a0}', Script::Common),
+        ('\u{30a1}', '\u{30fa}', Script::Katakana),
+        ('\u{30fb}', '\u{30fc}', Script::Common),
+        ('\u{30fd}', '\u{30ff}', Script::Katakana),
+        ('\u{3105}', '\u{312f}', Script::Bopomofo),
+        ('\u{3131}', '\u{318e}', Script::Hangul),
+        ('\u{3190}', '\u{319f}', Script::Common),
+        ('\u{31a0}', '\u{31bf}', Script::Bopomofo),
+        ('\u{31c0}', '\u{31e3}', Script::Common),
+        ('\u{31ef}', '\u{31ef}', Script::Common),
+        ('\u{31f0}', '\u{31ff}', Script::Katakana),
+        ('\u{3200}', '\u{321e}', Script::Hangul),
+        ('\u{3220}', '\u{325f}', Script::Common),
+        ('\u{3260}', '\u{327e}', Script::Hangul),
+        ('\u{327f}', '\u{32cf}', Script::Common),
+        ('\u{32d0}', '\u{32fe}', Script::Katakana),
+        ('\u{32ff}', '\u{32ff}', Script::Common),
+        ('\u{3300}', '\u{3357}', Script::Katakana),
+        ('\u{3358}', '\u{33ff}', Script::Common),
+        ('\u{3400}', '\u{4dbf}', Script::Han),
+        ('\u{4dc0}', '\u{4dff}', Script::Common),
+        ('\u{4e00}', '\u{9fff}', Script::Han),
+        ('\u{a000}', '\u{a48c}', Script::Yi),
+        ('\u{a490}', '\u{a4c6}', Script::Yi),
+        ('\u{a4d0}', '\u{a4ff}', Script::Lisu),
+        ('\u{a500}', '\u{a62b}', Script::Vai),
+        ('\u{a640}', '\u{a69f}', Script::Cyrillic),
+        ('\u{a6a0}', '\u{a6f7}', Script::Bamum),
+        ('\u{a700}', '\u{a721}', Script::Common),
+        ('\u{a722}', '\u{a787}', Script::Latin),
+        ('\u{a788}', '\u{a78a}', Script::Common),
+        ('\u{a78b}', '\u{a7ca}', Script::Latin),
+        ('\u{a7d0}', '\u{a7d1}', Script::Latin),
+        ('\u{a7d3}', '\u{a7d3}', Script::Latin),
+        ('\u{a7d5}', '\u{a7d9}', Script::Latin),
+        ('\u{a7f2}', '\u{a7ff}', Script::Latin),
+        ('\u{a800}', '\u{a82c}', Script::SylotiNagri),
+        ('\u{a830}', '\u{a839}', Script::Common),
+        ('\u{a840}', '\u{a877}', Script::PhagsPa),
+        ('\u{a880}', '\u{a8c5}', Script::Saurashtra),
+        ('\u{a8ce}', '\u{a8d9}', Script::Saurashtra),
+        ('\u{a8e0}', '\u{a8ff}', Script::Devanagari),
+        ('\u{a900}', '\u{a92d}', Script::KayahLi),
+        ('\u{a92e}', '\u{a92e}', Script::Common),
+        ('\u{a92f}', '\u{a92f}', Script::KayahLi),
+        ('\u{a930}', '\u{a953}', Script::Rejang),
+        ('\u{a95f}', '\u{a95f}', Script::Rejang),
+        ('\u{a960}', '\u{a97c}', Script::Hangul),
+        ('\u{a980}', '\u{a9cd}', Script::Javanese),
+        ('\u{a9cf}', '\u{a9cf}', Script::Common),
+        ('\u{a9d0}', '\u{a9d9}', Script::Javanese),
+        ('\u{a9de}', '\u{a9df}', Script::Javanese),
+        ('\u{a9e0}', '\u{a9fe}', Script::Myanmar),
+        ('\u{aa00}', '\u{aa36}', Script::Cham),
+        ('\u{aa40}', '\u{aa4d}', Script::Cham),
+        ('\u{aa50}', '\u{aa59}', Script::Cham),
+        ('\u{aa5c}', '\u{aa5f}', Script::Cham),
+        ('\u{aa60}', '\u{aa7f}', Script::Myanmar),
+        ('\u{aa80}', '\u{aac2}', Script::TaiViet),
+        ('\u{aadb}', '\u{aadf}', Script::TaiViet),
+        ('\u{aae0}', '\u{aaf6}', Script::MeeteiMayek),
+        ('\u{ab01}', '\u{ab06}', Script::Ethiopic),
+        ('\u{ab09}', '\u{ab0e}', Script::Ethiopic),
+        ('\u{ab11}', '\u{ab16}', Script::Ethiopic),
+        ('\u{ab20}', '\u{ab26}', Script::Ethiopic),
+        ('\u{ab28}', '\u{ab2e}', Script::Ethiopic),
+        ('\u{ab30}', '\u{ab5a}', Script::Latin),
+        ('\u{ab5b}', '\u{ab5b}', Script::Common),
+        ('\u{ab5c}', '\u{ab64}', Script::Latin),
+        ('\u{ab65}', '\u{ab65}', Script::Greek),
+        ('\u{ab66}', '\u{ab69}', Script::Latin),
+        ('\u{ab6a}', '\u{ab6b}', Script::Common),
+        ('\u{ab70}', '\u{abbf}', Script::Cherokee),
+        ('\u{abc0}', '\u{abed}', Script::MeeteiMayek),
+        ('\u{abf0}', '\u{abf9}', Script::MeeteiMayek),
+        ('\u{ac00}', '\u{d7a3}', Script::Hangul),
+        ('\u{d7b0}', '\u{d7c6}', Script::Hangul),
+        ('\u{d7cb}', '\u{d7fb}', Script::Hangul),
+        ('\u{f900}', '\u{fa6d}', Script::Han),
+        ('\u{fa70}', '\u{fad9}', Script::Han),
+        ('\u{fb00}', '\u{fb06}', Script::Latin),
+        ('\u{fb13}', '\u{fb17}', Script::Armenian),
+        ('\u{fb1d}', '\u{fb36}', Script::Hebrew),
+        ('\u{fb38}', '\u{fb3c}', Script::Hebrew),
+        ('\u{fb3e}', '\u{fb3e}', Script::Hebrew),
+        ('\u{fb40}', '\u{fb41}', Script::Hebrew),
+        ('\u{fb43}', '\u{fb44}', Script::Hebrew),
+        ('\u{fb46}', '\u{fb4f}', Script::Hebrew),
+        ('\u{fb50}', '\u{fbc2}', Script::Arabic),
+        ('\u{fbd3}', '\u{fd3d}', Script::Arabic),
+        ('\u{fd3e}', '\u{fd3f}', Script::Common),
+        ('\u{fd40}', '\u{fd8f}', Script::Arabic),
+        ('\u{fd92}', '\u{fdc7}', Script::Arabic),
+        ('\u{fdcf}', '\u{fdcf}', Script::Arabic),
+        ('\u{fdf0}', '\u{fdff}', Script::Arabic),
+        ('\u{fe00}', '\u{fe0f}', Script::Inherited),
+        ('\u{fe10}', '\u{fe19}', Script::Common),
+        ('\u{fe20}', '\u{fe2d}', Script::Inherited),
+        ('\u{fe2e}', '\u{fe2f}', Script::Cyrillic),
+        ('\u{fe30}', '\u{fe52}', Script::Common),
+        ('\u{fe54}', '\u{fe66}', Script::Common),
+        ('\u{fe68}', '\u{fe6b}', Script::Common),
+        ('\u{fe70}', '\u{fe74}', Script::Arabic),
+        ('\u{fe76}', '\u{fefc}', Script::Arabic),
+        ('\u{feff}', '\u{feff}', Script::Common),
+        ('\u{ff01}', '\u{ff20}', Script::Common),
+        ('\u{ff21}', '\u{ff3a}', Script::Latin),
+        ('\u{ff3b}', '\u{ff40}', Script::Common),
+        ('\u{ff41}', '\u{ff5a}', Script::Latin),
+        ('\u{ff5b}', '\u{ff65}', Script::Common),
+        ('\u{ff66}', '\u{ff6f}', Script::Katakana),
+        ('\u{ff70}', '\u{ff70}', Script::Common),
+        ('\u{ff71}', '\u{ff9d}', Script::Katakana),
+        ('\u{ff9e}', '\u{ff9f}', Script::Common),
+        ('\u{ffa0}', '\u{ffbe}', Script::Hangul),
+        ('\u{ffc2}', '\u{ffc7}', Script::Hangul),
+        ('\u{ffca}', '\u{ffcf}', Script::Hangul),
+        ('\u{ffd2}', '\u{ffd7}', Script::Hangul),
+        ('\u{ffda}', '\u{ffdc}', Script::Hangul),
+        ('\u{ffe0}', '\u{ffe6}', Script::Common),
+        ('\u{ffe8}', '\u{ffee}', Script::Common),
+        ('\u{fff9}', '\u{fffd}', Script::Common),
+        ('\u{10000}', '\u{1000b}', Script::LinearB),
+        ('\u{1000d}', '\u{10026}', Script::LinearB),
+        ('\u{10028}', '\u{1003a}', Script::LinearB),
+        ('\u{1003c}', '\u{1003d}', Script::LinearB),
+        ('\u{1003f}', '\u{1004d}', Script::LinearB),
+        ('\u{10050}', '\u{1005d}', Script::LinearB),
+        ('\u{10080}', '\u{100fa}', Script::LinearB),
+        ('\u{10100}', '\u{10102}', Script::Common),
+        ('\u{10107}', '\u{10133}', Script::Common),
+        ('\u{10137}', '\u{1013f}', Script::Common),
+        ('\u{10140}', '\u{1018e}', Script::Greek),
+        ('\u{10190}', '\u{1019c}', Script::Common),
+        ('\u{101a0}', '\u{101a0}', Script::Greek),
+        ('\u{101d0}', '\u{101fc}', Script::Common),
+        ('\u{101fd}', '\u{101fd}', Script::Inherited),
+        ('\u{10280}', '\u{1029c}', Script::Lycian),
+        ('\u{102a0}', '\u{102d0}', Script::Carian),
+        ('\u{102e0}', '\u{102e0}', Script::Inherited),
+        ('\u{102e1}', '\u{102fb}', Script::Common),
+        ('\u{10300}', '\u{10323}', Script::OldItalic),
+        ('\u{1032d}', '\u{1032f}', Script::OldItalic),
+        ('\u{10330}', '\u{1034a}', Script::Gothic),
+        ('\u{10350}', '\u{1037a}', Script::OldPermic),
+        ('\u{10380}', '\u{1039d}', Script::Ugaritic),
+        ('\u{1039f}', '\u{1039f}', Script::Ugaritic),
+        ('\u{103a0}', '\u{103c3}', Script::OldPersian),
+        ('\u{103c8}', '\u{103d5}', Script::OldPersian),
+        ('\u{10400}', '\u{1044f}', Script::Deseret),
+        ('\u{10450}', '\u{1047f}', Script::Shavian),
+        ('\u{10480}', '\u{1049d}', Script::Osmanya),
+        ('\u{104a0}', '\u{104a9}', Script::Osmanya),
+        ('\u{104b0}', '\u{104d3}', Script::Osage),
+        ('\u{104d8}', '\u{104fb}', Script::Osage),
+        ('\u{10500}', '\u{10527}', Script::Elbasan),
+        ('\u{10530}', '\u{10563}', Script::CaucasianAlbanian),
+        ('\u{1056f}', '\u{1056f}', Script::CaucasianAlbanian),
+        ('\u{10570}', '\u{1057a}', Script::Vithkuqi),
+        ('\u{1057c}', '\u{1058a}', Script::Vithkuqi),
+        ('\u{1058c}', '\u{10592}', Script::Vithkuqi),
+        ('\u{10594}', '\u{10595}', Script::Vithkuqi),
+        ('\u{10597}', '\u{105a1}', Script::Vithkuqi),
+        ('\u{105a3}', '\u{105b1}', Script::Vithkuqi),
+        ('\u{105b3}', '\u{105b9}', Script::Vithkuqi),
+        ('\u{105bb}', '\u{105bc}', Script::Vithkuqi),
+        ('\u{10600}', '\u{10736}', Script::LinearA),
+        ('\u{10740}', '\u{10755}', Script::LinearA),
+        ('\u{10760}', '\u{10767}', Script::LinearA),
+        ('\u{10780}', '\u{10785}', Script::Latin),
+        ('\u{10787}', '\u{107b0}', Script::Latin),
+        ('\u{107b2}', '\u{107ba}', Script::Latin),
+        ('\u{10800}', '\u{10805}', Script::Cypriot),
+        ('\u{10808}', '\u{10808}', Script::Cypriot),
+        ('\u{1080a}', '\u{10835}', Script::Cypriot),
+        ('\u{10837}', '\u{10838}', Script::Cypriot),
+        ('\u{1083c}', '\u{1083c}', Script::Cypriot),
+        ('\u{1083f}', '\u{1083f}', Script::Cypriot),
+        ('\u{10840}', '\u{10855}', Script::ImperialAramaic),
+        ('\u{10857}', '\u{1085f}', Script::ImperialAramaic),
+        ('\u{10860}', '\u{1087f}', Script::Palmyrene),
+        ('\u{10880}', '\u{1089e}', Script::Nabataean),
+        ('\u{108a7}', '\u{108af}', Script::Nabataean),
+        ('\u{108e0}', '\u{108f2}', Script::Hatran),
+        ('\u{108f4}', '\u{108f5}', Script::Hatran),
+        ('\u{108fb}', '\u{108ff}', Script::Hatran),
+        ('\u{10900}', '\u{1091b}', Script::Phoenician),
+        ('\u{1091f}', '\u{1091f}', Script::Phoenician),
+        ('\u{10920}', '\u{10939}', Script::Lydian),
+        ('\u{1093f}', '\u{1093f}', Script::Lydian),
+        ('\u{10980}', '\u{1099f}', Script::MeroiticHieroglyphs),
+        ('\u{109a0}', '\u{109b7}', Script::MeroiticCursive),
+        ('\u{109bc}', '\u{109cf}', Script::MeroiticCursive),
+        ('\u{109d2}', '\u{109ff}', Script::MeroiticCursive),
+        ('\u{10a00}', '\u{10a03}', Script::Kharoshthi),
+        ('\u{10a05}', '\u{10a06}', Script::Kharoshthi),
+        ('\u{10a0c}', '\u{10a13}', Script::Kharoshthi),
+        ('\u{10a15}', '\u{10a17}', Script::Kharoshthi),
+        ('\u{10a19}', '\u{10a35}', Script::Kharoshthi),
+        ('\u{10a38}', '\u{10a3a}', Script::Kharoshthi),
+        ('\u{10a3f}', '\u{10a48}', Script::Kharoshthi),
+        ('\u{10a50}', '\u{10a58}', Script::Kharoshthi),
+        ('\u{10a60}', '\u{10a7f}', Script::OldSouthArabian),
+        ('\u{10a80}', '\u{10a9f}', Script::OldNorthArabian),
+        ('\u{10ac0}', '\u{10ae6}', Script::Manichaean),
+        ('\u{10aeb}', '\u{10af6}', Script::Manichaean),
+        ('\u{10b00}', '\u{10b35}', Script::Avestan),
+        ('\u{10b39}', '\u{10b3f}', Script::Avestan),
+        ('\u{10b40}', '\u{10b55}', Script::InscriptionalParthian),
+        ('\u{10b58}', '\u{10b5f}', Script::InscriptionalParthian),
+        ('\u{10b60}', '\u{10b72}', Script::InscriptionalPahlavi),
+        ('\u{10b78}', '\u{10b7f}', Script::InscriptionalPahlavi),
+        ('\u{10b80}', '\u{10b91}', Script::PsalterPahlavi),
+        ('\u{10b99}', '\u{10b9c}', Script::PsalterPahlavi),
+        ('\u{10ba9}', '\u{10baf}', Script::PsalterPahlavi),
+        ('\u{10c00}', '\u{10c48}', Script::OldTurkic),
+        ('\u{10c80}', '\u{10cb2}', Script::OldHungarian),
+        ('\u{10cc0}', '\u{10cf2}', Script::OldHungarian),
+        ('\u{10cfa}', '\u{10cff}', Script::OldHungarian),
+        ('\u{10d00}', '\u{10d27}', Script::HanifiRohingya),
+        ('\u{10d30}', '\u{10d39}', Script::HanifiRohingya),
+        ('\u{10e60}', '\u{10e7e}', Script::Arabic),
+        ('\u{10e80}', '\u{10ea9}', Script::Yezidi),
+        ('\u{10eab}', '\u{10ead}', Script::Yezidi),
+        ('\u{10eb0}', '\u{10eb1}', Script::Yezidi),
+        ('\u{10efd}', '\u{10eff}', Script::Arabic),
+        ('\u{10f00}', '\u{10f27}', Script::OldSogdian),
+        ('\u{10f30}', '\u{10f59}', Script::Sogdian),
+        ('\u{10f70}', '\u{10f89}', Script::OldUyghur),
+        ('\u{10fb0}', '\u{10fcb}', Script::Chorasmian),
+        ('\u{10fe0}', '\u{10ff6}', Script::Elymaic),
+        ('\u{11000}', '\u{1104d}', Script::Brahmi),
+        ('\u{11052}', '\u{11075}', Script::Brahmi),
+        ('\u{1107f}', '\u{1107f}', Script::Brahmi),
+        ('\u{11080}', '\u{110c2}', Script::Kaithi),
+        ('\u{110cd}', '\u{110cd}', Script::Kaithi),
+        ('\u{110d0}', '\u{110e8}', Script::SoraSompeng),
+        ('\u{110f0}', '\u{110f9}', Script::SoraSompeng),
+        ('\u{11100}', '\u{11134}', Script::Chakma),
+        ('\u{11136}', '\u{11147}', Script::Chakma),
+        ('\u{11150}', '\u{11176}', Script::Mahajani),
+        ('\u{11180}', '\u{111df}', Script::Sharada),
+        ('\u{111e1}', '\u{111f4}', Script::Sinhala),
+        ('\u{11200}', '\u{11211}', Script::Khojki),
+        ('\u{11213}', '\u{11241}', Script::Khojki),
+        ('\u{11280}', '\u{11286}', Script::Multani),
+        ('\u{11288}', '\u{11288}', Script::Multani),
+        ('\u{1128a}', '\u{1128d}', Script::Multani),
+        ('\u{1128f}', '\u{1129d}', Script::Multani),
+        ('\u{1129f}', '\u{112a9}', Script::Multani),
+        ('\u{112b0}', '\u{112ea}', Script::Khudawadi),
+        ('\u{112f0}', '\u{112f9}', Script::Khudawadi),
+        ('\u{11300}', '\u{11303}', Script::Grantha),
+        ('\u{11305}', '\u{1130c}', Script::Grantha),
+        ('\u{1130f}', '\u{11310}', Script::Grantha),
+        ('\u{11313}', '\u{11328}', Script::Grantha),
+        ('\u{1132a}', '\u{11330}', Script::Grantha),
+        ('\u{11332}', '\u{11333}', Script::Grantha),
+        ('\u{11335}', '\u{11339}', Script::Grantha),
+        ('\u{1133b}', '\u{1133b}', Script::Inherited),
+        ('\u{1133c}', '\u{11344}', Script::Grantha),
+        ('\u{11347}', '\u{11348}', Script::Grantha),
+        ('\u{1134b}', '\u{1134d}', Script::Grantha),
+        ('\u{11350}', '\u{11350}', Script::Grantha),
+        ('\u{11357}', '\u{11357}', Script::Grantha),
+        ('\u{1135d}', '\u{11363}', Script::Grantha),
+        ('\u{11366}', '\u{1136c}', Script::Grantha),
+        ('\u{11370}', '\u{11374}', Script::Grantha),
+        ('\u{11400}', '\u{1145b}', Script::Newa),
+        ('\u{1145d}', '\u{11461}', Script::Newa),
+        ('\u{11480}', '\u{114c7}', Script::Tirhuta),
+        ('\u{114d0}', '\u{114d9}', Script::Tirhuta),
+        ('\u{11580}', '\u{115b5}', Script::Siddham),
+        ('\u{115b8}', '\u{115dd}', Script::Siddham),
+        ('\u{11600}', '\u{11644}', Script::Modi),
+        ('\u{11650}', '\u{11659}', Script::Modi),
+        ('\u{11660}', '\u{1166c}', Script::Mongolian),
+        ('\u{11680}', '\u{116b9}', Script::Takri),
+        ('\u{116c0}', '\u{116c9}', Script::Takri),
+        ('\u{11700}', '\u{1171a}', Script::Ahom),
+        ('\u{1171d}', '\u{1172b}', Script::Ahom),
+        ('\u{11730}', '\u{11746}', Script::Ahom),
+        ('\u{11800}', '\u{1183b}', Script::Dogra),
+        ('\u{118a0}', '\u{118f2}', Script::WarangCiti),
+        ('\u{118ff}', '\u{118ff}', Script::WarangCiti),
+        ('\u{11900}', '\u{11906}', Script::DivesAkuru),
+        ('\u{11909}', '\u{11909}', Script::DivesAkuru),
+        ('\u{1190c}', '\u{11913}', Script::DivesAkuru),
+        ('\u{11915}', '\u{11916}', Script::DivesAkuru),
+        ('\u{11918}', '\u{11935}', Script::DivesAkuru),
+        ('\u{11937}', '\u{11938}', Script::DivesAkuru),
+        ('\u{1193b}', '\u{11946}', Script::DivesAkuru),
+        ('\u{11950}', '\u{11959}', Script::DivesAkuru),
+        ('\u{119a0}', '\u{119a7}', Script::Nandinagari),
+        ('\u{119aa}', '\u{119d7}', Script::Nandinagari),
+        ('\u{119da}', '\u{119e4}', Script::Nandinagari),
+        ('\u{11a00}', '\u{11a47}', Script::ZanabazarSquare),
+        ('\u{11a50}', '\u{11aa2}', Script::Soyombo),
+        ('\u{11ab0}', '\u{11abf}', Script::CanadianAboriginal),
+        ('\u{11ac0}', '\u{11af8}', Script::PauCinHau),
+        ('\u{11b00}', '\u{11b09}', Script::Devanagari),
+        ('\u{11c00}', '\u{11c08}', Script::Bhaiksuki),
+        ('\u{11c0a}', '\u{11c36}', Script::Bhaiksuki),
+        ('\u{11c38}', '\u{11c45}', Script::Bhaiksuki),
+        ('\u{11c50}', '\u{11c6c}', Script::Bhaiksuki),
+        ('\u{11c70}', '\u{11c8f}', Script::Marchen),
+        ('\u{11c92}', '\u{11ca7}', Script::Marchen),
+        ('\u{11ca9}', '\u{11cb6}', Script::Marchen),
+        ('\u{11d00}', '\u{11d06}', Script::MasaramGondi),
+        ('\u{11d08}', '\u{11d09}', Script::MasaramGondi),
+        ('\u{11d0b}', '\u{11d36}', Script::MasaramGondi),
+        ('\u{11d3a}', '\u{11d3a}', Script::MasaramGondi),
+        ('\u{11d3c}', '\u{11d3d}', Script::MasaramGondi),
+        ('\u{11d3f}', '\u{11d47}', Script::MasaramGondi),
+        ('\u{11d50}', '\u{11d59}', Script::MasaramGondi),
+        ('\u{11d60}', '\u{11d65}', Script::GunjalaGondi),
+        ('\u{11d67}', '\u{11d68}', Script::GunjalaGondi),
+        ('\u{11d6a}', '\u{11d8e}', Script::GunjalaGondi),
+        ('\u{11d90}', '\u{11d91}', Script::GunjalaGondi),
+        ('\u{11d93}', '\u{11d98}', Script::GunjalaGondi),
+        ('\u{11da0}', '\u{11da9}', Script::GunjalaGondi),
+        ('\u{11ee0}', '\u{11ef8}', Script::Makasar),
+        ('\u{11f00}', '\u{11f10}', Script::Kawi),
+        ('\u{11f12}', '\u{11f3a}', Script::Kawi),
+        ('\u{11f3e}', '\u{11f59}', Script::Kawi),
+        ('\u{11fb0}', '\u{11fb0}', Script::Lisu),
+        ('\u{11fc0}', '\u{11ff1}', Script::Tamil),
+        ('\u{11fff}', '\u{11fff}', Script::Tamil),
+        ('\u{12000}', '\u{12399}', Script::Cuneiform),
+        ('\u{12400}', '\u{1246e}', Script::Cuneiform),
+        ('\u{12470}', '\u{12474}', Script::Cuneiform),
+        ('\u{12480}', '\u{12543}', Script::Cuneiform),
+        ('\u{12f90}', '\u{12ff2}', Script::CyproMinoan),
+        ('\u{13000}', '\u{13455}', Script::EgyptianHieroglyphs),
+        ('\u{14400}', '\u{14646}', Script::AnatolianHieroglyphs),
+        ('\u{16800}', '\u{16a38}', Script::Bamum),
+        ('\u{16a40}', '\u{16a5e}', Script::Mro),
+        ('\u{16a60}', '\u{16a69}', Script::Mro),
+        ('\u{16a6e}', '\u{16a6f}', Script::Mro),
+        ('\u{16a70}', '\u{16abe}', Script::Tangsa),
+        ('\u{16ac0}', '\u{16ac9}', Script::Tangsa),
+        ('\u{16ad0}', '\u{16aed}', Script::BassaVah),
+        ('\u{16af0}', '\u{16af5}', Script::BassaVah),
+        ('\u{16b00}', '\u{16b45}', Script::PahawhHmong),
+        ('\u{16b50}', '\u{16b59}', Script::PahawhHmong),
+        ('\u{16b5b}', '\u{16b61}', Script::PahawhHmong),
+        ('\u{16b63}', '\u{16b77}', Script::PahawhHmong),
+        ('\u{16b7d}', '\u{16b8f}', Script::PahawhHmong),
+        ('\u{16e40}', '\u{16e9a}', Script::Medefaidrin),
+        ('\u{16f00}', '\u{16f4a}', Script::Miao),
+        ('\u{16f4f}', '\u{16f87}', Script::Miao),
+        ('\u{16f8f}', '\u{16f9f}', Script::Miao),
+        ('\u{16fe0}', '\u{16fe0}', Script::Tangut),
+        ('\u{16fe1}', '\u{16fe1}', Script::Nushu),
+        ('\u{16fe2}', '\u{16fe3}', Script::Han),
+        ('\u{16fe4}', '\u{16fe4}', Script::KhitanSmallScript),
+        ('\u{16ff0}', '\u{16ff1}', Script::Han),
+        ('\u{17000}', '\u{187f7}', Script::Tangut),
+        ('\u{18800}', '\u{18aff}', Script::Tangut),
+        ('\u{18b00}', '\u{18cd5}', Script::KhitanSmallScript),
+        ('\u{18d00}', '\u{18d08}', Script::Tangut),
+        ('\u{1aff0}', '\u{1aff3}', Script::Katakana),
+        ('\u{1aff5}', '\u{1affb}', Script::Katakana),
+        ('\u{1affd}', '\u{1affe}', Script::Katakana),
+        ('\u{1b000}', '\u{1b000}', Script::Katakana),
+        ('\u{1b001}', '\u{1b11f}', Script::Hiragana),
+        ('\u{1b120}', '\u{1b122}', Script::Katakana),
+        ('\u{1b132}', '\u{1b132}', Script::Hiragana),
+        ('\u{1b150}', '\u{1b152}', Script::Hiragana),
+        ('\u{1b155}', '\u{1b155}', Script::Katakana),
+        ('\u{1b164}', '\u{1b167}', Script::Katakana),
+        ('\u{1b170}', '\u{1b2fb}', Script::Nushu),
+        ('\u{1bc00}', '\u{1bc6a}', Script::Duployan),
+        ('\u{1bc70}', '\u{1bc7c}', Script::Duployan),
+        ('\u{1bc80}', '\u{1bc88}', Script::Duployan),
+        ('\u{1bc90}', '\u{1bc99}', Script::Duployan),
+        ('\u{1bc9c}', '\u{1bc9f}', Script::Duployan),
+        ('\u{1bca0}', '\u{1bca3}', Script::Common),
+        ('\u{1cf00}', '\u{1cf2d}', Script::Inherited),
+        ('\u{1cf30}', '\u{1cf46}', Script::Inherited),
+        ('\u{1cf50}', '\u{1cfc3}', Script::Common),
+        ('\u{1d000}', '\u{1d0f5}', Script::Common),
+        ('\u{1d100}', '\u{1d126}', Script::Common),
+        ('\u{1d129}', '\u{1d166}', Script::Common),
+        ('\u{1d167}', '\u{1d169}', Script::Inherited),
+        ('\u{1d16a}', '\u{1d17a}', Script::Common),
+        ('\u{1d17b}', '\u{1d182}', Script::Inherited),
+        ('\u{1d183}', '\u{1d184}', Script::Common),
+        ('\u{1d185}', '\u{1d18b}', Script::Inherited),
+        ('\u{1d18c}', '\u{1d1a9}', Script::Common),
+        ('\u{1d1aa}', '\u{1d1ad}', Script::Inherited),
+        ('\u{1d1ae}', '\u{1d1ea}', Script::Common),
+        ('\u{1d200}', '\u{1d245}', Script::Greek),
+        ('\u{1d2c0}', '\u{1d2d3}', Script::Common),
+        ('\u{1d2e0}', '\u{1d2f3}', Script::Common),
+        ('\u{1d300}', '\u{1d356}', Script::Common),
+        ('\u{1d360}', '\u{1d378}', Script::Common),
+        ('\u{1d400}', '\u{1d454}', Script::Common),
+        ('\u{1d456}', '\u{1d49c}', Script::Common),
+        ('\u{1d49e}', '\u{1d49f}', Script::Common),
+        ('\u{1d4a2}', '\u{1d4a2}', Script::Common),
+        ('\u{1d4a5}', '\u{1d4a6}', Script::Common),
+        ('\u{1d4a9}', '\u{1d4ac}', Script::Common),
+        ('\u{1d4ae}', '\u{1d4b9}', Script::Common),
+        ('\u{1d4bb}', '\u{1d4bb}', Script::Common),
+        ('\u{1d4bd}', '\u{1d4c3}', Script::Common),
+        ('\u{1d4c5}', '\u{1d505}', Script::Common),
+        ('\u{1d507}', '\u{1d50a}', Script::Common),
+        ('\u{1d50d}', '\u{1d514}', Script::Common),
+        ('\u{1d516}', '\u{1d51c}', Script::Common),
+        ('\u{1d51e}', '\u{1d539}', Script::Common),
+        ('\u{1d53b}', '\u{1d53e}', Script::Common),
+        ('\u{1d540}', '\u{1d544}', Script::Common),
+        ('\u{1d546}', '\u{1d546}', Script::Common),
+        ('\u{1d54a}', '\u{1d550}', Script::Common),
+        ('\u{1d552}', '\u{1d6a5}', Script::Common),
+        ('\u{1d6a8}', '\u{1d7cb}', Script::Common),
+        ('\u{1d7ce}', '\u{1d7ff}', Script::Common),
+        ('\u{1d800}', '\u{1da8b}', Script::SignWriting),
+        ('\u{1da9b}', '\u{1da9f}', Script::SignWriting),
+        ('\u{1daa1}', '\u{1daaf}', Script::SignWriting),
+        ('\u{1df00}', '\u{1df1e}', Script::Latin),
+        ('\u{1df25}', '\u{1df2a}', Script::Latin),
+        ('\u{1e000}', '\u{1e006}', Script::Glagolitic),
+        ('\u{1e008}', '\u{1e018}', Script::Glagolitic),
+        ('\u{1e01b}', '\u{1e021}', Script::Glagolitic),
+        ('\u{1e023}', '\u{1e024}', Script::Glagolitic),
+        ('\u{1e026}', '\u{1e02a}', Script::Glagolitic),
+        ('\u{1e030}', '\u{1e06d}', Script::Cyrillic),
+        ('\u{1e08f}', '\u{1e08f}', Script::Cyrillic),
+        ('\u{1e100}', '\u{1e12c}', Script::NyiakengPuachueHmong),
+        ('\u{1e130}', '\u{1e13d}', Script::NyiakengPuachueHmong),
+        ('\u{1e140}', '\u{1e149}', Script::NyiakengPuachueHmong),
+        ('\u{1e14e}', '\u{1e14f}', Script::NyiakengPuachueHmong),
+        ('\u{1e290}', '\u{1e2ae}', Script::Toto),
+        ('\u{1e2c0}', '\u{1e2f9}', Script::Wancho),
+        ('\u{1e2ff}', '\u{1e2ff}', Script::Wancho),
+        ('\u{1e4d0}', '\u{1e4f9}', Script::NagMundari),
+        ('\u{1e7e0}', '\u{1e7e6}', Script::Ethiopic),
+        ('\u{1e7e8}', '\u{1e7eb}', Script::Ethiopic),
+        ('\u{1e7ed}', '\u{1e7ee}', Script::Ethiopic),
+        ('\u{1e7f0}', '\u{1e7fe}', Script::Ethiopic),
+        ('\u{1e800}', '\u{1e8c4}', Script::MendeKikakui),
+        ('\u{1e8c7}', '\u{1e8d6}', Script::MendeKikakui),
+        ('\u{1e900}', '\u{1e94b}', Script::Adlam),
+        ('\u{1e950}', '\u{1e959}', Script::Adlam),
+        ('\u{1e95e}', '\u{1e95f}', Script::Adlam),
+        ('\u{1ec71}', '\u{1ecb4}', Script::Common),
+        ('\u{1ed01}', '\u{1ed3d}', Script::Common),
+        ('\u{1ee00}', '\u{1ee03}', Script::Arabic),
+        ('\u{1ee05}', '\u{1ee1f}', Script::Arabic),
+        ('\u{1ee21}', '\u{1ee22}', Script::Arabic),
+        ('\u{1ee24}', '\u{1ee24}', Script::Arabic),
+        ('\u{1ee27}', '\u{1ee27}', Script::Arabic),
+        ('\u{1ee29}', '\u{1ee32}', Script::Arabic),
+        ('\u{1ee34}', '\u{1ee37}', Script::Arabic),
+        ('\u{1ee39}', '\u{1ee39}', Script::Arabic),
+        ('\u{1ee3b}', '\u{1ee3b}', Script::Arabic),
+        ('\u{1ee42}', '\u{1ee42}', Script::Arabic),
+        ('\u{1ee47}', '\u{1ee47}', Script::Arabic),
+        ('\u{1ee49}', '\u{1ee49}', Script::Arabic),
+        ('\u{1ee4b}', '\u{1ee4b}', Script::Arabic),
+        ('\u{1ee4d}', '\u{1ee4f}', Script::Arabic),
+        ('\u{1ee51}', '\u{1ee52}', Script::Arabic),
+        ('\u{1ee54}', '\u{1ee54}', Script::Arabic),
+        ('\u{1ee57}', '\u{1ee57}', Script::Arabic),
+        ('\u{1ee59}', '\u{1ee59}', Script::Arabic),
+        ('\u{1ee5b}', '\u{1ee5b}', Script::Arabic),
+        ('\u{1ee5d}', '\u{1ee5d}', Script::Arabic),
+        ('\u{1ee5f}', '\u{1ee5f}', Script::Arabic),
+        ('\u{1ee61}', '\u{1ee62}', Script::Arabic),
+        ('\u{1ee64}', '\u{1ee64}', Script::Arabic),
+        ('\u{1ee67}', '\u{1ee6a}', Script::Arabic),
+        ('\u{1ee6c}', '\u{1ee72}', Script::Arabic),
+        ('\u{1ee74}', '\u{1ee77}', Script::Arabic),
+        ('\u{1ee79}', '\u{1ee7c}', Script::Arabic),
+        ('\u{1ee7e}', '\u{1ee7e}', Script::Arabic),
+        ('\u{1ee80}', '\u{1ee89}', Script::Arabic),
+        ('\u{1ee8b}', '\u{1ee9b}', Script::Arabic),
+        ('\u{1eea1}', '\u{1eea3}', Script::Arabic),
+        ('\u{1eea5}', '\u{1eea9}', Script::Arabic),
+        ('\u{1eeab}', '\u{1eebb}', Script::Arabic),
+        ('\u{1eef0}', '\u{1eef1}', Script::Arabic),
+        ('\u{1f000}', '\u{1f02b}', Script::Common),
+        ('\u{1f030}', '\u{1f093}', Script::Common),
+        ('\u{1f0a0}', '\u{1f0ae}', Script::Common),
+        ('\u{1f0b1}', '\u{1f0bf}', Script::Common),
+        ('\u{1f0c1}', '\u{1f0cf}', Script::Common),
+        ('\u{1f0d1}', '\u{1f0f5}', Script::Common),
+        ('\u{1f100}', '\u{1f1ad}', Script::Common),
+        ('\u{1f1e6}', '\u{1f1ff}', Script::Common),
+        ('\u{1f200}', '\u{1f200}', Script::Hiragana),
+        ('\u{1f201}', '\u{1f202}', Script::Common),
+        ('\u{1f210}', '\u{1f23b}', Script::Common),
+        ('\u{1f240}', '\u{1f248}', Script::Common),
+        ('\u{1f250}', '\u{1f251}', Script::Common),
+        ('\u{1f260}', '\u{1f265}', Script::Common),
+        ('\u{1f300}', '\u{1f6d7}', Script::Common),
+        ('\u{1f6dc}', '\u{1f6ec}', Script::Common),
+        ('\u{1f6f0}', '\u{1f6fc}', Script::Common),
+        ('\u{1f700}', '\u{1f776}', Script::Common),
+        ('\u{1f77b}', '\u{1f7d9}', Script::Common),
+        ('\u{1f7e0}', '\u{1f7eb}', Script::Common),
+        ('\u{1f7f0}', '\u{1f7f0}', Script::Common),
+        ('\u{1f800}', '\u{1f80b}', Script::Common),
+        ('\u{1f810}', '\u{1f847}', Script::Common),
+        ('\u{1f850}', '\u{1f859}', Script::Common),
+        ('\u{1f860}', '\u{1f887}', Script::Common),
+        ('\u{1f890}', '\u{1f8ad}', Script::Common),
+        ('\u{1f8b0}', '\u{1f8b1}', Script::Common),
+        ('\u{1f900}', '\u{1fa53}', Script::Common),
+        ('\u{1fa60}', '\u{1fa6d}', Script::Common),
+        ('\u{1fa70}', '\u{1fa7c}', Script::Common),
+        ('\u{1fa80}', '\u{1fa88}', Script::Common),
+        ('\u{1fa90}', '\u{1fabd}', Script::Common),
+        ('\u{1fabf}', '\u{1fac5}', Script::Common),
+        ('\u{1face}', '\u{1fadb}', Script::Common),
+        ('\u{1fae0}', '\u{1fae8}', Script::Common),
+        ('\u{1faf0}', '\u{1faf8}', Script::Common),
+        ('\u{1fb00}', '\u{1fb92}', Script::Common),
+        ('\u{1fb94}', '\u{1fbca}', Script::Common),
+        ('\u{1fbf0}', '\u{1fbf9}', Script::Common),
+        ('\u{20000}', '\u{2a6df}', Script::Han),
+        ('\u{2a700}', '\u{2b739}', Script::Han),
+        ('\u{2b740}', '\u{2b81d}', Script::Han),
+        ('\u{2b820}', '\u{2cea1}', Script::Han),
+        ('\u{2ceb0}', '\u{2ebe0}', Script::Han),
+        ('\u{2ebf0}', '\u{2ee5d}', Script::Han),
+        ('\u{2f800}', '\u{2fa1d}', Script::Han),
+        ('\u{30000}', '\u{3134a}', Script::Han),
+        ('\u{31350}', '\u{323af}', Script::Han),
+        ('\u{e0001}', '\u{e0001}', Script::Common),
+        ('\u{e0020}', '\u{e007f}', Script::Common),
+        ('\u{e0100}', '\u{e01ef}', Script::Inherited),
+    ];
+}
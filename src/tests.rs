@@ -110,6 +110,135 @@ fn jawiki(b: &mut Bencher) {
     let string = std::fs::read_to_string(data_path).unwrap_or_default();
     b.iter(|| test::black_box(UnicodeWidthStr::width(string.as_str())));
 }
+#[cfg(feature = "bench")]
+#[bench]
+fn tiny_strings(b: &mut Bencher) {
+    let cells = ["", "a", "ｈ", "\u{e9}", "ab", "", "1", "\u{1F600}"];
+    let strings = iter::repeat(&cells[..]).take(512).flatten().copied().collect::<Vec<_>>();
+
+    b.iter(|| {
+        for s in strings.iter() {
+            test::black_box(UnicodeWidthStr::width(*s));
+        }
+    });
+}
+#[cfg(feature = "bench")]
+#[bench]
+fn latin1(b: &mut Bencher) {
+    let string = "Le cœur a ses raisons que la raison ne connaît point. \
+                  Über allen Gipfeln ist Ruh, in allen Wipfeln spürest du kaum einen Hauch. "
+        .repeat(32);
+
+    b.iter(|| test::black_box(UnicodeWidthStr::width(string.as_str())));
+}
+// `UnicodeWidthChar::width` is statically dispatched, so it compiles to the same code as calling
+// the underlying free function directly. These benchmarks confirm it: both measure the same
+// within noise, so there's no performance reason to expose free functions.
+#[cfg(feature = "bench")]
+#[bench]
+fn char_width_trait(b: &mut Bencher) {
+    let chars = (0..0x30000).filter_map(::core::char::from_u32).step_by(7).collect::<Vec<_>>();
+
+    b.iter(|| {
+        for &c in chars.iter() {
+            test::black_box(UnicodeWidthChar::width(test::black_box(c)));
+        }
+    });
+}
+#[cfg(feature = "bench")]
+#[bench]
+fn char_width_free_fn(b: &mut Bencher) {
+    let chars = (0..0x30000).filter_map(::core::char::from_u32).step_by(7).collect::<Vec<_>>();
+
+    b.iter(|| {
+        for &c in chars.iter() {
+            test::black_box(super::cw::width(test::black_box(c), false));
+        }
+    });
+}
+// `str_width` sums `usize` widths with `Iterator::sum`. These benchmarks compare it against
+// folding with `Add::add`, and against summing `u8` widths into a `u32` in chunks of 128 chars in
+// the hope that the narrower accumulator vectorizes. It doesn't: the loop is bound by UTF-8
+// decoding and the table lookup for each char, not by the addition. Neither alternative beat
+// `sum` (both measured 5-20% slower, with wide error bars), so `str_width` keeps using it.
+#[cfg(feature = "bench")]
+fn long_mixed_string() -> String {
+    "Hello, world! Ｈｅｌｌｏ, ｗｏｒｌｄ! caf\u{E9} \u{1F600} \u{0915}\u{094D}\u{0937} "
+        .repeat(256)
+}
+#[cfg(feature = "bench")]
+#[bench]
+fn str_width_sum(b: &mut Bencher) {
+    let string = long_mixed_string();
+
+    b.iter(|| test::black_box(UnicodeWidthStr::width(test::black_box(string.as_str()))));
+}
+#[cfg(feature = "bench")]
+#[bench]
+fn str_width_fold(b: &mut Bencher) {
+    use core::ops::Add;
+    let string = long_mixed_string();
+
+    b.iter(|| {
+        let widths = test::black_box(string.as_str())
+            .chars()
+            .map(|c| super::cw::width(c, false).unwrap_or(0));
+        test::black_box(widths.fold(0, Add::add))
+    });
+}
+#[cfg(feature = "bench")]
+#[bench]
+fn str_width_u8_chunked(b: &mut Bencher) {
+    let string = long_mixed_string();
+    let width = |c| super::cw::width(c, false).unwrap_or(0) as u8;
+
+    b.iter(|| {
+        let mut chars = test::black_box(string.as_str()).chars();
+        let mut total = 0usize;
+        loop {
+            // 128 chars of at most 2 columns each can't overflow a `u32`, or even a `u16`
+            let chunk: u32 = chars.by_ref().take(128).map(|c| u32::from(width(c))).sum();
+            if chunk == 0 && chars.as_str().is_empty() {
+                break;
+            }
+            total += chunk as usize;
+        }
+        test::black_box(total)
+    });
+}
+// `str_width` counts printable bytes of an ASCII prefix directly instead of decoding and looking
+// up each char. These benchmarks measure 1 MiB of ASCII with it and with the char-by-char path;
+// the fast path measured about 9 times faster.
+#[cfg(feature = "bench")]
+fn long_ascii_string() -> String {
+    "2024-01-01T00:00:00Z INFO request handled in 12ms\t(status=200)\n"
+        .chars()
+        .cycle()
+        .take(1 << 20)
+        .collect()
+}
+#[cfg(feature = "bench")]
+#[bench]
+fn str_width_ascii_fast_path(b: &mut Bencher) {
+    let string = long_ascii_string();
+
+    b.iter(|| test::black_box(UnicodeWidthStr::width(test::black_box(string.as_str()))));
+}
+#[cfg(feature = "bench")]
+#[bench]
+fn str_width_ascii_chars(b: &mut Bencher) {
+    let string = long_ascii_string();
+
+    b.iter(|| test::black_box(super::chars_width(test::black_box(string.as_str()), false)));
+}
+#[cfg(all(feature = "bench", feature = "segmentation"))]
+#[bench]
+fn graphemes_ascii_heavy(b: &mut Bencher) {
+    let string =
+        "The quick brown fox jumps over the lazy dog. ".repeat(64) + "\u{1F468}\u{200D}\u{1F9B0}";
+
+    b.iter(|| test::black_box(super::width_graphemes(string.as_str(), false)));
+}
 #[test]
 fn test_str() {
     use super::UnicodeWidthStr;
@@ -122,6 +251,21 @@ fn test_str() {
     assert_eq!("".width_cjk(), 0);
     assert_eq!(UnicodeWidthStr::width("\u{2081}\u{2082}\u{2083}\u{2084}"), 4);
     assert_eq!("\u{2081}\u{2082}\u{2083}\u{2084}".width_cjk(), 8);
+    assert_eq!(UnicodeWidthStr::width("ｈ"), 2);
+    assert_eq!("\u{2081}".width_cjk(), 2);
+    assert_eq!(UnicodeWidthStr::width("\x01"), 0);
+    assert_eq!("\x7F".width_cjk(), 0);
+    assert_eq!("\0".width_cjk(), 0);
+    assert_eq!("~".width_cjk(), 1);
+    // Single non-ASCII characters are measured on their own
+    assert_eq!(UnicodeWidthStr::width("\u{FF21}"), 2);
+    assert_eq!(UnicodeWidthStr::width("\u{E9}"), 1);
+    assert_eq!("\u{A1}".width_cjk(), 2);
+    assert_eq!(UnicodeWidthStr::width("\u{1F600}"), 2);
+    assert_eq!(UnicodeWidthStr::width("\u{301}"), 0);
+    assert_eq!(UnicodeWidthStr::width("\u{1F1FA}"), 2);
+    assert_eq!(UnicodeWidthStr::width("\u{FE0F}"), 0);
+    assert_eq!("\u{FE0E}".width_cjk(), 0);
 }
 
 #[test]
@@ -189,3 +333,3115 @@ fn unicode_12() {
 
     assert_eq!(UnicodeWidthChar::width('\u{1F971}'), Some(2));
 }
+
+#[test]
+fn test_fit() {
+    use super::{Fit, UnicodeWidthStr};
+
+    assert_eq!(format!("{}", Fit("Ｈｉ", 6)), "Ｈｉ  ");
+    assert_eq!(UnicodeWidthStr::width(&*format!("{}", Fit("Ｈｉ", 6))), 6);
+    assert_eq!(format!("{}", Fit("hello", 3)), "hel");
+    assert_eq!(format!("{}", Fit("", 2)), "  ");
+    assert_eq!(format!("{}", Fit("abc", 0)), "");
+    // A double-width character straddling the edge is replaced with padding.
+    assert_eq!(format!("{}", Fit("aＢc", 2)), "a ");
+    assert_eq!(UnicodeWidthStr::width(&*format!("{}", Fit("aＢc", 2))), 2);
+    // Combining marks stay attached to their base at the edge.
+    assert_eq!(format!("{}", Fit("e\u{301}x", 1)), "e\u{301}");
+}
+
+#[test]
+fn test_fill_widths() {
+    use super::{fill_widths, UnicodeWidthChar};
+
+    let chars = ['a', 'Ｈ', '\u{300}', '\x01', '\u{a1}', '\0'];
+    let mut out = [0xFF; 6];
+    fill_widths(&chars, &mut out, false);
+    for (&c, &w) in chars.iter().zip(out.iter()) {
+        assert_eq!(w as usize, UnicodeWidthChar::width(c).unwrap_or(0));
+    }
+    fill_widths(&chars, &mut out, true);
+    assert_eq!(out, [1, 2, 0, 0, 2, 0]);
+}
+
+#[test]
+#[should_panic]
+fn test_fill_widths_length_mismatch() {
+    use super::fill_widths;
+
+    let mut out = [0; 2];
+    fill_widths(&['a', 'b', 'c'], &mut out, false);
+}
+
+#[test]
+fn test_count_unassigned() {
+    use super::UnicodeWidthStr;
+
+    assert_eq!("".count_unassigned(), 0);
+    assert_eq!("ｈｅｌｌｏ, world!".count_unassigned(), 0);
+    assert_eq!("a\u{378}b".count_unassigned(), 1);
+    // Private-use characters are assigned, noncharacters aren't
+    assert_eq!("\u{E000}\u{FFFF}\u{10FFFF}".count_unassigned(), 2);
+}
+
+#[test]
+fn test_range_constant_width() {
+    use super::range_constant_width;
+
+    // Hiragana letters
+    assert_eq!(range_constant_width('\u{3041}'..='\u{3096}'), Some(2));
+    // The Hiragana block also contains combining marks
+    assert_eq!(range_constant_width('\u{3040}'..='\u{309F}'), None);
+    assert_eq!(range_constant_width('a'..='z'), Some(1));
+    assert_eq!(range_constant_width('\u{300}'..='\u{36F}'), Some(0));
+    assert_eq!(range_constant_width('x'..='x'), Some(1));
+    assert_eq!(range_constant_width('\x01'..='\x1F'), None);
+    assert_eq!(range_constant_width('z'..='a'), None);
+}
+
+#[test]
+fn test_width_with_min_cell() {
+    use super::UnicodeWidthStr;
+
+    assert_eq!("e\u{0301}".width_with_min_cell(1), 2);
+    assert_eq!("e\u{0301}".width_with_min_cell(0), 1);
+    assert_eq!("a\x01\0".width_with_min_cell(1), 3);
+    assert_eq!("ａb".width_with_min_cell(1), 3);
+    assert_eq!("ａb".width_with_min_cell(2), 4);
+    assert_eq!("".width_with_min_cell(1), 0);
+}
+
+#[test]
+fn test_table_hash() {
+    use super::{UnicodeWidthStr, TABLE_HASH};
+
+    let before = TABLE_HASH;
+    assert_eq!(UnicodeWidthStr::width("ｈｅｌｌｏ"), 10);
+    assert_eq!("\u{a1}".width_cjk(), 2);
+    assert_eq!(TABLE_HASH, before);
+    assert_ne!(TABLE_HASH, 0);
+}
+
+#[test]
+fn test_default_ignorable_width() {
+    use super::{UnicodeWidthChar, UnicodeWidthStr, WidthOptions};
+
+    let default = WidthOptions::new();
+    let visible = WidthOptions::new().default_ignorable_width(1);
+
+    // Default-ignorable characters
+    for &c in &['\u{200B}', '\u{2060}', '\u{FEFF}', '\u{180E}', '\u{E0001}'] {
+        assert_eq!(c.width_with(default), Some(0));
+        assert_eq!(c.width_with(visible), Some(1));
+    }
+    assert_eq!("a\u{200B}b".width_with(default), 2);
+    assert_eq!("a\u{200B}b".width_with(visible), 3);
+    assert_eq!("a\u{200B}b".width_with(visible.default_ignorable_width(2)), 4);
+
+    // Prepended concatenation marks, combining marks, and controls aren't default-ignorable
+    for &c in &['\u{600}', '\u{110BD}', '\u{301}', '\0'] {
+        assert_eq!(c.width_with(visible), Some(0));
+    }
+    assert_eq!('\x01'.width_with(visible), None);
+    // Default-ignorable characters with a nonzero width keep it
+    assert_eq!('\u{AD}'.width_with(visible), Some(1));
+
+    assert_eq!('\u{a1}'.width_with(default.ambiguous_wide(true)), Some(2));
+    assert_eq!("\u{a1}\u{200B}".width_with(default), UnicodeWidthStr::width("\u{a1}\u{200B}"));
+}
+
+#[test]
+fn test_bom_width() {
+    use super::{UnicodeWidthChar, UnicodeWidthStr, WidthCalculator, WidthOptions};
+
+    let default = WidthOptions::new();
+    assert_eq!('\u{FEFF}'.width(), Some(0));
+    assert_eq!('\u{FEFF}'.width_cjk(), Some(0));
+    assert_eq!('\u{FEFF}'.width_with(default), Some(0));
+    assert_eq!(UnicodeWidthStr::width("\u{FEFF}hello"), 5);
+
+    let visible = default.bom_width(1);
+    assert_eq!('\u{FEFF}'.width_with(visible), Some(1));
+    assert_eq!("\u{FEFF}hello".width_with(visible), 6);
+    // Other default-ignorable characters are unaffected
+    assert_eq!('\u{200B}'.width_with(visible), Some(0));
+    // The larger of the two options wins
+    assert_eq!('\u{FEFF}'.width_with(visible.default_ignorable_width(2)), Some(2));
+    assert_eq!('\u{FEFF}'.width_with(default.bom_width(2).default_ignorable_width(1)), Some(2));
+
+    assert_eq!(WidthCalculator::new().bom_width(1).measure("\u{FEFF}ab"), 3);
+}
+
+#[cfg(feature = "ansi")]
+#[test]
+fn test_visible_width() {
+    use super::visible_width;
+
+    assert_eq!(visible_width(""), 0);
+    assert_eq!(visible_width("plain"), 5);
+    assert_eq!(visible_width("\x1b[31mred\x1b[0m"), 3);
+    // OSC 8 hyperlinks terminated by ST and by BEL
+    assert_eq!(visible_width("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\"), 4);
+    assert_eq!(visible_width("\x1b]8;id=1;https://example.com\x07リンク\x1b]8;;\x07"), 6);
+    assert_eq!(visible_width("see \x1b]8;;http://a.b/c\x1b\\here\x1b]8;;\x1b\\."), 9);
+    // An ESC inside an OSC string starts a new sequence
+    assert_eq!(visible_width("\x1b]8;;url\x1b[1mbold"), 4);
+    // Unterminated sequences extend to the end of the string
+    assert_eq!(visible_width("ab\x1b]8;;https://example.com"), 2);
+    assert_eq!(visible_width("ab\x1b[31"), 2);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_measure_reader() {
+    use super::measure_reader;
+    use std::io::Cursor;
+
+    let widths = measure_reader(Cursor::new("hello\nｈｅｌｌｏ\r\n\nabc")).unwrap();
+    assert_eq!(widths.widths, vec![5, 10, 0, 3]);
+    assert_eq!(widths.max, 10);
+
+    let widths = measure_reader(Cursor::new(&b"a\xFFb\n"[..])).unwrap();
+    assert_eq!(widths.widths, vec![3]);
+
+    let widths = measure_reader(Cursor::new("")).unwrap();
+    assert!(widths.widths.is_empty());
+    assert_eq!(widths.max, 0);
+}
+
+#[cfg(feature = "segmentation")]
+#[test]
+fn test_keycap_sequences() {
+    use super::{cluster_width, width_graphemes};
+
+    for base in "#*0123456789".chars() {
+        let qualified = format!("{}\u{FE0F}\u{20E3}", base);
+        let unqualified = format!("{}\u{20E3}", base);
+        assert_eq!(cluster_width(&qualified, false), 2, "{:?}", qualified);
+        assert_eq!(cluster_width(&unqualified, false), 2, "{:?}", unqualified);
+        assert_eq!(width_graphemes(&qualified, true), 2, "{:?}", qualified);
+    }
+    assert_eq!(width_graphemes("1\u{FE0F}\u{20E3}2\u{FE0F}\u{20E3}", false), 4);
+    assert_eq!(width_graphemes("a1\u{FE0F}\u{20E3}b", false), 4);
+    // Not keycaps
+    assert_eq!(cluster_width("1", false), 1);
+    assert_eq!(cluster_width("a\u{20E3}", false), 1);
+}
+
+#[cfg(feature = "segmentation")]
+#[test]
+fn test_emoji_components() {
+    use super::{cluster_width, width_graphemes, UnicodeWidthChar};
+
+    // MAN + ZWJ + each hair component
+    for hair in '\u{1F9B0}'..='\u{1F9B3}' {
+        let person = format!("\u{1F468}\u{200D}{}", hair);
+        assert_eq!(cluster_width(&person, false), 2, "{:?}", person);
+        assert_eq!(width_graphemes(&person, true), 2, "{:?}", person);
+        // A standalone hair component uses its table width
+        assert_eq!(cluster_width(&hair.to_string(), false), hair.width().unwrap());
+    }
+    // WOMAN + skin tone modifier + ZWJ + RED HAIR
+    assert_eq!(cluster_width("\u{1F469}\u{1F3FD}\u{200D}\u{1F9B0}", false), 2);
+    // THUMBS UP + skin tone modifier
+    assert_eq!(cluster_width("\u{1F44D}\u{1F3FB}", false), 2);
+    assert_eq!(width_graphemes("a\u{1F468}\u{200D}\u{1F9B0}b", false), 4);
+    // Components after something other than an emoji keep their width
+    assert_eq!(width_graphemes("a\u{200D}\u{1F9B0}", false), 3);
+    // Regional indicators aren't collapsed into the emoji before them
+    assert_eq!(cluster_width("\u{1F1FA}\u{1F1F8}", false), 2);
+}
+
+#[test]
+fn test_widths_into() {
+    use super::widths_into;
+
+    let mut buf = [0xFF; 3];
+    assert_eq!(widths_into("aＢ\u{301}cd", &mut buf), 3);
+    assert_eq!(buf, [1, 2, 0]);
+
+    let mut buf = [0xFF; 4];
+    assert_eq!(widths_into("a\x01", &mut buf), 2);
+    assert_eq!(buf, [1, 0, 0xFF, 0xFF]);
+
+    let mut buf = [0; 0];
+    assert_eq!(widths_into("abc", &mut buf), 0);
+}
+
+#[test]
+fn test_width_wcwidth() {
+    use super::width_wcwidth;
+
+    assert_eq!(width_wcwidth('\0'), 0);
+    assert_eq!(width_wcwidth('\x07'), -1);
+    assert_eq!(width_wcwidth('\u{9B}'), -1);
+    assert_eq!(width_wcwidth('a'), 1);
+    assert_eq!(width_wcwidth('\u{301}'), 0);
+    assert_eq!(width_wcwidth('ｈ'), 2);
+}
+
+#[cfg(feature = "conformance")]
+#[test]
+fn test_wcwidth_conformance() {
+    use super::width_wcwidth;
+    use std::char;
+
+    let expectations = include_str!("../conformance/wcwidth.txt");
+    let mut checked = 0;
+    for line in expectations.lines() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(';').map(str::trim).collect();
+        let c = char::from_u32(u32::from_str_radix(fields[0], 16).unwrap()).unwrap();
+        let python: isize = fields[1].parse().unwrap();
+        let expected = fields.get(2).map_or(python, |divergent| divergent.parse().unwrap());
+        assert_eq!(width_wcwidth(c), expected, "U+{:04X}", c as u32);
+        checked += 1;
+    }
+    assert!(checked > 0);
+}
+
+#[test]
+fn test_variation_selector_index() {
+    use super::UnicodeWidthChar;
+
+    assert_eq!('\u{FE00}'.variation_selector_index(), Some(1));
+    assert_eq!('\u{FE0E}'.variation_selector_index(), Some(15));
+    assert_eq!('\u{FE0F}'.variation_selector_index(), Some(16));
+    assert_eq!('\u{E0100}'.variation_selector_index(), Some(17));
+    assert_eq!('\u{E01EF}'.variation_selector_index(), Some(256));
+    assert_eq!('a'.variation_selector_index(), None);
+    assert_eq!('\u{FE10}'.variation_selector_index(), None);
+    assert_eq!('\u{E01F0}'.variation_selector_index(), None);
+
+    for &c in &['\u{FE00}', '\u{FE0F}', '\u{E0100}', '\u{E01EF}'] {
+        assert_eq!(UnicodeWidthChar::width(c), Some(0));
+        assert_eq!(c.width_cjk(), Some(0));
+    }
+}
+
+#[test]
+fn test_cmp_by_width() {
+    use super::{cmp_by_width, cmp_by_width_cjk, width_key};
+    use std::cmp::Ordering;
+
+    // Fewer characters, but more columns
+    assert_eq!(cmp_by_width("Ｈｉ", "Hii"), Ordering::Greater);
+    assert_eq!(cmp_by_width("Hii", "Ｈｉ"), Ordering::Less);
+    assert_eq!(cmp_by_width("ab", "Ｈ"), Ordering::Equal);
+    assert_eq!(cmp_by_width("\u{a1}", "a"), Ordering::Equal);
+    assert_eq!(cmp_by_width_cjk("\u{a1}", "a"), Ordering::Greater);
+
+    let mut rows = vec!["Hii", "Ｈｉ", "", "a"];
+    rows.sort_by(|a, b| cmp_by_width(a, b));
+    assert_eq!(rows, ["", "a", "Hii", "Ｈｉ"]);
+    rows.reverse();
+    rows.sort_by_key(|s| width_key(s));
+    assert_eq!(rows, ["", "a", "Hii", "Ｈｉ"]);
+}
+
+#[cfg(feature = "segmentation")]
+#[test]
+fn test_graphemes_wide_base() {
+    use super::{cluster_width, width_graphemes};
+
+    // Non-spacing marks don't widen a double-width base
+    assert_eq!(cluster_width("一\u{301}", false), 2);
+    assert_eq!(cluster_width("一\u{301}\u{302}", true), 2);
+    assert_eq!(cluster_width("Ｈ\u{20DD}", false), 2);
+    // Spacing marks do
+    assert_eq!(cluster_width("一\u{903}", false), 3);
+    assert_eq!(width_graphemes("一\u{301}一\u{903}", false), 5);
+    // ...and the same holds for narrow bases
+    assert_eq!(cluster_width("e\u{301}", false), 1);
+    assert_eq!(cluster_width("\u{915}\u{93E}", false), 2);
+}
+
+#[test]
+fn test_width_calculator() {
+    use super::{AmbiguousPolicy, ControlPolicy, UnicodeWidthStr, WidthCalculator};
+
+    for &s in &["hello", "\u{a1}\t\u{2600}\u{FE0F}", "Ｈｉ\n\u{200B}\x01", ""] {
+        assert_eq!(WidthCalculator::new().measure(s), s.width());
+        assert_eq!(WidthCalculator::new().cjk(true).measure(s), s.width_cjk());
+    }
+    // The default calculator honors variation selectors and pairs flags, like `width`
+    let sequences = [
+        "\u{2600}\u{FE0F}",
+        "\u{231A}\u{FE0E}",
+        "x\u{2764}\u{FE0F}y\u{FE0F}",
+        "\u{1F1FA}\u{1F1F8}",
+        "\u{1F1FA}\u{1F1F8}\u{1F1FA}",
+        "\u{1F1FA}a\u{1F1F8}",
+    ];
+    for &s in &sequences {
+        assert_eq!(WidthCalculator::new().measure(s), s.width());
+        assert_eq!(WidthCalculator::default().cjk(true).measure(s), s.width_cjk());
+    }
+    assert_eq!(WidthCalculator::new().measure("\u{2600}\u{FE0F}"), 2);
+    // ...unless told not to
+    assert_eq!(WidthCalculator::new().emoji_vs16(false).measure("\u{2600}\u{FE0F}"), 1);
+    assert_eq!(WidthCalculator::new().emoji_vs16(false).measure("\u{231A}\u{FE0E}"), 2);
+
+    // CJK + tabs + VS16: '\u{a1}' is 2 wide, so the tab only advances 2 columns
+    let calc = WidthCalculator::new().cjk(true).tab_width(4);
+    assert_eq!(calc.measure("\u{a1}\t\u{2600}\u{FE0F}"), 6);
+    assert_eq!(calc.cjk(false).measure("\u{a1}\t\u{2600}\u{FE0F}"), 6);
+    assert_eq!(calc.emoji_vs16(false).measure("\u{a1}\t\u{2600}\u{FE0F}"), 5);
+    // Tab stops restart on every line
+    assert_eq!(calc.measure("abcde\tf\n\tg"), 8 + 1 + 4 + 1);
+    // ...and after a carriage return, which returns to the start of the line
+    let tabs = WidthCalculator::new().tab_width(4);
+    assert_eq!(tabs.measure("ab\r\tc"), 2 + 4 + 1);
+    assert_eq!(tabs.measure("ab\r\n\tc"), 2 + 4 + 1);
+    assert_eq!(tabs.measure("\u{FF21}\u{FF22}\r\t\u{FF23}\t"), 4 + 4 + 2 + 2);
+    assert_eq!(tabs.measure("abcdef\rx\ty"), 6 + 1 + 3 + 1);
+    let visible_cr = tabs.control(ControlPolicy::Fixed(2));
+    assert_eq!(visible_cr.measure("ab\r\tc"), 2 + 2 + 4 + 1);
+    // VS16 only widens emoji
+    assert_eq!(calc.measure("a\u{FE0F}"), 1);
+    assert_eq!(calc.measure("\u{2600}"), 1);
+
+    let calc = WidthCalculator::new().ambiguous(AmbiguousPolicy::Wide);
+    assert_eq!(calc.measure("\u{a1}"), 2);
+    let calc = WidthCalculator::new().control(ControlPolicy::Fixed(2));
+    assert_eq!(calc.measure("a\x01\x7f\0"), 5);
+
+    let overrides = [('a'..='c', 3), ('\u{a1}'..='\u{a1}', 0)];
+    let calc = WidthCalculator::new().cjk(true).overrides(&overrides);
+    assert_eq!(calc.measure("abcd\u{a1}"), 10);
+}
+
+#[test]
+fn test_musical_symbols() {
+    use super::{UnicodeWidthChar, UnicodeWidthStr};
+
+    // Note heads and standalone symbols are narrow
+    for &c in &['\u{1D158}', '\u{1D15D}', '\u{1D15F}', '\u{1D16A}', '\u{1D183}'] {
+        assert_eq!(c.width(), Some(1));
+    }
+    // Combining stems, flags, augmentation dots, tremolos, and articulations are not,
+    // even though some of them are spacing marks
+    for &c in &[
+        '\u{1D165}', '\u{1D166}', '\u{1D167}', '\u{1D16D}', '\u{1D16E}', '\u{1D172}',
+        '\u{1D17B}', '\u{1D18B}', '\u{1D1AA}', '\u{1D242}',
+    ] {
+        assert_eq!(c.width(), Some(0));
+        assert_eq!(c.width_cjk(), Some(0));
+    }
+    // Beam, tie, slur, and phrase controls are format characters
+    assert_eq!('\u{1D173}'.width(), Some(0));
+
+    // Black note head + stem + flag-1 is an eighth note
+    assert_eq!("\u{1D158}\u{1D165}\u{1D16E}".width(), 1);
+    // ...and with an augmentation dot, a dotted eighth note
+    assert_eq!("\u{1D158}\u{1D165}\u{1D16E}\u{1D16D}".width(), 1);
+}
+
+#[test]
+fn test_custom_ambiguous_policy() {
+    use super::{AmbiguousPolicy, UnicodeWidthChar, UnicodeWidthStr, WidthCalculator, WidthOptions};
+
+    static PUNCTUATION: [core::ops::RangeInclusive<char>; 2] =
+        ['\u{2010}'..='\u{2027}', '\u{3000}'..='\u{303F}'];
+    let opts = WidthOptions::new().ambiguous(AmbiguousPolicy::Custom(&PUNCTUATION));
+
+    // Ambiguous punctuation in the ranges is wide
+    assert_eq!('\u{2018}'.width_with(opts), Some(2));
+    assert_eq!('\u{2026}'.width_with(opts), Some(2));
+    // Ambiguous Greek letters outside the ranges stay narrow
+    assert_eq!('\u{3B1}'.width_with(opts), Some(1));
+    assert_eq!('\u{3B1}'.width_cjk(), Some(2));
+    // Non-ambiguous characters in the ranges keep their width
+    assert_eq!('\u{2012}'.width_with(opts), Some(1));
+    assert_eq!('\u{3001}'.width_with(opts), Some(2));
+
+    assert_eq!("\u{201C}\u{3B1}\u{201D}".width_with(opts), 5);
+    let calc = WidthCalculator::new().ambiguous(AmbiguousPolicy::Custom(&PUNCTUATION));
+    assert_eq!(calc.measure("\u{201C}\u{3B1}\u{201D}"), 5);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_smart_pointers() {
+    use super::UnicodeWidthStr;
+    use alloc::boxed::Box;
+    use alloc::rc::Rc;
+    use alloc::sync::Arc;
+
+    fn generic_width<S: UnicodeWidthStr + ?Sized>(s: &S) -> (usize, usize) {
+        (s.width(), s.width_cjk())
+    }
+
+    let arc: Arc<str> = Arc::from("Ｈｉ\u{a1}");
+    assert_eq!(arc.width(), 5);
+    assert_eq!(generic_width(&arc), (5, 6));
+    let rc: Rc<str> = Rc::from("Ｈｉ\u{a1}");
+    assert_eq!(generic_width(&rc), (5, 6));
+    let boxed: Box<str> = Box::from("Ｈｉ\u{a1}");
+    assert_eq!(generic_width(&boxed), (5, 6));
+    assert_eq!(boxed.count_unassigned(), 0);
+    assert_eq!(boxed.width_with_min_cell(3), 9);
+}
+
+#[test]
+fn test_bidi_controls() {
+    use super::{UnicodeWidthChar, UnicodeWidthStr};
+
+    let controls = [
+        '\u{61C}', '\u{200E}', '\u{200F}', '\u{202A}', '\u{202B}', '\u{202C}', '\u{202D}',
+        '\u{202E}', '\u{2066}', '\u{2067}', '\u{2068}', '\u{2069}',
+    ];
+    for &c in &controls {
+        assert_eq!(c.width(), Some(0));
+        assert_eq!(c.width_cjk(), Some(0));
+    }
+
+    // LRE ... PDF
+    let s = "\u{202A}abc\u{202C}";
+    assert_eq!("\u{202A}\u{202C}".width(), 0);
+    assert_eq!(s.width(), 3);
+    assert!(s.contains_bidi_controls());
+    assert!("/* \u{202E} } \u{2066}if (admin)\u{2069} \u{2066} begin */".contains_bidi_controls());
+    assert!(!"abc \u{5D0}\u{5D1} \u{200B}\u{200D}".contains_bidi_controls());
+    assert!(!"".contains_bidi_controls());
+}
+
+#[test]
+fn test_specials() {
+    use super::{UnicodeWidthChar, UnicodeWidthStr, WidthCalculator, WidthOptions};
+
+    // Unassigned codepoints at the start of the block are tofu
+    assert_eq!('\u{FFF0}'.width(), Some(1));
+    assert_eq!('\u{FFF8}'.width(), Some(1));
+    // Interlinear annotation anchor, separator, and terminator are format characters
+    for &c in &['\u{FFF9}', '\u{FFFA}', '\u{FFFB}'] {
+        assert_eq!(c.width(), Some(0));
+        assert_eq!(c.width_cjk(), Some(0));
+    }
+    // Object replacement character is narrow; the replacement character is ambiguous
+    assert_eq!('\u{FFFC}'.width(), Some(1));
+    assert_eq!('\u{FFFC}'.width_cjk(), Some(1));
+    assert_eq!('\u{FFFD}'.width(), Some(1));
+    assert_eq!('\u{FFFD}'.width_cjk(), Some(2));
+    // Noncharacters are tofu by default
+    for &c in &['\u{FFFE}', '\u{FFFF}', '\u{FDD0}', '\u{FDEF}', '\u{1FFFE}', '\u{10FFFF}'] {
+        assert_eq!(c.width(), Some(1));
+        assert_eq!(c.width_with(WidthOptions::new()), Some(1));
+        assert_eq!(c.width_with(WidthOptions::new().noncharacter_width(None)), None);
+        assert_eq!(c.width_with(WidthOptions::new().noncharacter_width(Some(0))), Some(0));
+    }
+    // ...but their neighbors aren't noncharacters
+    for &c in &['\u{FDCF}', '\u{FDF0}', '\u{FFFD}', '\u{1FFFD}', '\u{20000}'] {
+        assert_eq!(
+            c.width_with(WidthOptions::new().noncharacter_width(None)),
+            c.width()
+        );
+    }
+
+    let flagged = WidthOptions::new().noncharacter_width(None);
+    assert_eq!("a\u{FFFE}\u{FFFD}".width(), 3);
+    assert_eq!("a\u{FFFE}\u{FFFD}".width_with(flagged), 2);
+    let calc = WidthCalculator::new().noncharacter_width(Some(2));
+    assert_eq!(calc.measure("a\u{FFFE}\u{FFFD}"), 4);
+}
+
+#[test]
+fn test_width_u16() {
+    use super::{UnicodeWidthChar, UnicodeWidthStr};
+
+    assert_eq!('a'.width_u16(), Some(1));
+    assert_eq!('Ｈ'.width_u16(), Some(2));
+    assert_eq!('\u{301}'.width_u16(), Some(0));
+    assert_eq!('\x01'.width_u16(), None);
+
+    assert_eq!("Ｈｉ!".width_u16(), 5);
+    let s = "Ｈ".repeat(40_000);
+    assert_eq!(s.width(), 80_000);
+    assert_eq!(s.width_u16(), u16::MAX);
+    let s = "a".repeat(u16::MAX as usize);
+    assert_eq!(s.width_u16(), u16::MAX);
+}
+
+#[test]
+fn test_width_assume_composed() {
+    use super::UnicodeWidthStr;
+
+    // NFC
+    assert_eq!("\u{e9}t\u{e9}".width_assume_composed(), (3, 0));
+    assert_eq!("\u{1EC7}".width_assume_composed(), (1, 0));
+    assert_eq!("".width_assume_composed(), (0, 0));
+    // NFD
+    assert_eq!("e\u{301}te\u{301}".width_assume_composed(), (3, 2));
+    assert_eq!("e\u{323}\u{302}".width_assume_composed(), (1, 2));
+    // Enclosing marks and spacing Grapheme_Extend marks count too, and are zero-width
+    assert_eq!("1\u{20E3}".width_assume_composed(), (1, 1));
+    assert_eq!("\u{995}\u{9BE}".width_assume_composed(), (1, 1));
+    // Other zero-width characters aren't marks
+    assert_eq!("a\u{200B}\u{200D}b".width_assume_composed(), (2, 0));
+    assert_eq!("Ｈｉ".width_assume_composed(), ("Ｈｉ".width(), 0));
+}
+
+#[test]
+fn test_astral_indic() {
+    use super::{UnicodeWidthChar, UnicodeWidthStr};
+
+    // Spacing marks (general category Mc) take a column ...
+    for &c in &[
+        '\u{11000}', '\u{11002}', // Brahmi candrabindu, visarga
+        '\u{11082}', '\u{110B0}', '\u{110B7}', // Kaithi visarga, vowel signs AA and O
+        '\u{1112C}', // Chakma vowel sign E
+        '\u{11182}', '\u{111B3}', // Sharada visarga, vowel sign AA
+    ] {
+        assert_eq!(c.width(), Some(1));
+    }
+    // ... including the few viramas which are spacing
+    for &c in &['\u{111C0}', '\u{11235}', '\u{1134D}', '\u{116B6}'] {
+        assert_eq!(c.width(), Some(1));
+    }
+    // Nonspacing marks, including most viramas and nuktas, don't
+    for &c in &[
+        '\u{11001}', '\u{11038}', '\u{11042}', '\u{11046}', '\u{11070}', '\u{11073}', '\u{1107F}',
+        '\u{11080}', '\u{110B3}', '\u{110B9}', '\u{110BA}', '\u{110C2}',
+        '\u{11100}', '\u{11127}', '\u{11133}', '\u{11134}',
+        '\u{11180}', '\u{111B6}', '\u{11A34}',
+    ] {
+        assert_eq!(c.width(), Some(0));
+        assert_eq!(c.width_cjk(), Some(0));
+    }
+    // Kaithi number signs are format characters
+    assert_eq!('\u{110BD}'.width(), Some(0));
+    assert_eq!('\u{110CD}'.width(), Some(0));
+
+    // Brahmi KA + virama + SSA
+    assert_eq!("\u{11013}\u{11046}\u{11031}".width(), 2);
+    // Kaithi KA + vowel sign AA, KA + vowel sign U
+    assert_eq!("\u{1108D}\u{110B0}".width(), 2);
+    assert_eq!("\u{1108D}\u{110B3}".width(), 1);
+}
+
+#[test]
+fn test_len_and_width() {
+    use super::UnicodeWidthStr;
+
+    for &s in &["", "a", "hello", "Ｈｅｌｌｏ", "\u{a1}e\u{301}\x01\0", "\u{1F600}\u{200D}\u{1F600}"] {
+        assert_eq!(s.len_and_width(), (s.chars().count(), s.width()));
+        assert_eq!(s.len_and_width_cjk(), (s.chars().count(), s.width_cjk()));
+    }
+    assert_eq!("\u{a1}e\u{301}".len_and_width(), (3, 2));
+    assert_eq!("\u{a1}e\u{301}".len_and_width_cjk(), (3, 3));
+}
+
+#[test]
+fn test_width_caret_notation() {
+    use super::UnicodeWidthStr;
+
+    assert_eq!("\x01".width_caret_notation(), 2);
+    assert_eq!("\0".width_caret_notation(), 2);
+    assert_eq!("\x1F\x7F".width_caret_notation(), 4);
+    // "a^Ib^Mｈ^[[0m"
+    assert_eq!("a\tb\rｈ\x1b[0m".width_caret_notation(), 1 + 2 + 1 + 2 + 2 + 2 + 3);
+    // C1 controls aren't shown in caret notation
+    assert_eq!("\u{85}\u{9F}".width_caret_notation(), 0);
+    assert_eq!("hello".width_caret_notation(), 5);
+    assert_eq!("".width_caret_notation(), 0);
+}
+
+#[cfg(feature = "segmentation")]
+#[test]
+fn test_grapheme_at_column() {
+    use super::{cluster_width, grapheme_at_column};
+
+    let s = "a\u{FF22}\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    assert_eq!(grapheme_at_column(s, 0), Some((0..1, 1)));
+    assert_eq!(grapheme_at_column(s, 1), Some((1..4, 2)));
+    assert_eq!(grapheme_at_column(s, 2), Some((1..4, 2)));
+    // The family emoji is a single cluster starting at column 3
+    let (range, width) = grapheme_at_column(s, 3).unwrap();
+    assert_eq!(range, 4..s.len());
+    assert_eq!(width, cluster_width(&s[range.clone()], false));
+    assert_eq!(grapheme_at_column(s, 4), Some((range, width)));
+    assert_eq!(grapheme_at_column(s, 3 + width), None);
+
+    // Combining marks belong to the cluster of their base
+    assert_eq!(grapheme_at_column("e\u{301}x", 0), Some((0..3, 1)));
+    assert_eq!(grapheme_at_column("e\u{301}x", 1), Some((3..4, 1)));
+    // Zero-width clusters are skipped
+    assert_eq!(grapheme_at_column("\u{200B}a", 0), Some((3..4, 1)));
+    assert_eq!(grapheme_at_column("", 0), None);
+}
+
+#[test]
+fn test_ideographic_space() {
+    use super::{Fit, UnicodeWidthChar, UnicodeWidthStr, WidthCalculator};
+
+    // U+3000 IDEOGRAPHIC SPACE is fullwidth whitespace, unlike U+0020
+    assert!('\u{3000}'.is_whitespace());
+    assert_eq!('\u{3000}'.width(), Some(2));
+    assert_eq!('\u{3000}'.width_cjk(), Some(2));
+    assert_eq!("\u{3000}".width(), 2);
+    assert_eq!("日本\u{3000}語".width(), 8);
+    assert_eq!("日本\u{3000}語".trim_matches('\u{3000}').width(), 8);
+    assert_eq!("\u{3000}語\u{3000}".trim().width(), 2);
+
+    // It's measured as a wide character when truncating and tab-expanding
+    assert_eq!(format!("[{}]", Fit("a\u{3000}b", 2)), "[a ]");
+    assert_eq!(format!("[{}]", Fit("a\u{3000}b", 3)), "[a\u{3000}]");
+    assert_eq!(WidthCalculator::new().tab_width(4).measure("\u{3000}\t"), 4);
+}
+
+#[test]
+fn test_split_at_width() {
+    use super::UnicodeWidthStr;
+
+    let s = "a\u{FF22}c";
+    assert_eq!(s.split_at_width(0), ("", s));
+    assert_eq!(s.split_at_width(1), ("a", "\u{FF22}c"));
+    // The wide char can't fit in the 1 remaining column
+    assert_eq!(s.split_at_width(2), ("a", "\u{FF22}c"));
+    assert_eq!(s.split_at_width(3), ("a\u{FF22}", "c"));
+    assert_eq!(s.split_at_width(4), (s, ""));
+    assert_eq!(s.split_at_width(100), (s, ""));
+    assert_eq!("".split_at_width(1), ("", ""));
+
+    // Zero-width characters stay with the preceding character
+    assert_eq!("e\u{301}x".split_at_width(1), ("e\u{301}", "x"));
+    assert_eq!("\u{301}x".split_at_width(0), ("\u{301}", "x"));
+
+    assert_eq!("\u{a1}\u{a1}".split_at_width(1), ("\u{a1}", "\u{a1}"));
+    assert_eq!("\u{a1}\u{a1}".split_at_width_cjk(1), ("", "\u{a1}\u{a1}"));
+    assert_eq!("\u{a1}\u{a1}".split_at_width_cjk(3), ("\u{a1}", "\u{a1}"));
+
+    for &s in &["hello", "Ｈｅｌｌｏ", "a\u{FF22}c\u{301}"] {
+        for column in 0..12 {
+            let (head, tail) = s.split_at_width(column);
+            assert!(head.width() <= column);
+            assert_eq!(head.width() + tail.width(), s.width());
+        }
+    }
+}
+
+#[test]
+fn test_east_asian_width() {
+    use super::{EastAsianWidth, UnicodeWidthChar};
+
+    assert_eq!('\u{0}'.east_asian_width(), EastAsianWidth::Neutral);
+    assert_eq!('\u{A9}'.east_asian_width(), EastAsianWidth::Neutral);
+    assert_eq!('\u{5D0}'.east_asian_width(), EastAsianWidth::Neutral);
+    assert_eq!('a'.east_asian_width(), EastAsianWidth::Narrow);
+    assert_eq!('\u{A2}'.east_asian_width(), EastAsianWidth::Narrow);
+    assert_eq!('\u{4E00}'.east_asian_width(), EastAsianWidth::Wide);
+    assert_eq!('\u{1F600}'.east_asian_width(), EastAsianWidth::Wide);
+    assert_eq!('\u{3000}'.east_asian_width(), EastAsianWidth::FullWidth);
+    assert_eq!('\u{FF21}'.east_asian_width(), EastAsianWidth::FullWidth);
+    assert_eq!('\u{FF61}'.east_asian_width(), EastAsianWidth::HalfWidth);
+    assert_eq!('\u{20A9}'.east_asian_width(), EastAsianWidth::HalfWidth);
+    assert_eq!('\u{A1}'.east_asian_width(), EastAsianWidth::Ambiguous);
+    assert_eq!('\u{3B1}'.east_asian_width(), EastAsianWidth::Ambiguous);
+    assert_eq!('\u{F0000}'.east_asian_width(), EastAsianWidth::Ambiguous);
+
+    // The raw property isn't the resolved width
+    assert_eq!('\u{301}'.east_asian_width(), EastAsianWidth::Ambiguous);
+    assert_eq!('\u{301}'.width_cjk(), Some(0));
+    // Unassigned codepoints in the CJK blocks default to Wide
+    assert_eq!('\u{3FFFD}'.east_asian_width(), EastAsianWidth::Wide);
+    assert_eq!('\u{E0000}'.east_asian_width(), EastAsianWidth::Neutral);
+}
+
+#[test]
+fn test_trimmed_width() {
+    use super::UnicodeWidthStr;
+
+    assert_eq!("ab  ".trimmed_width(), 2);
+    assert_eq!("ab\u{3000}".trimmed_width(), 2);
+    assert_eq!("ab \t\u{3000}\n".trimmed_width(), 2);
+    assert_eq!("  ab".trimmed_width(), 4);
+    assert_eq!("a b".trimmed_width(), 3);
+    assert_eq!("   ".trimmed_width(), 0);
+    assert_eq!("".trimmed_width(), 0);
+    // Zero-width characters aren't whitespace
+    assert_eq!("ab\u{200B}".trimmed_width(), 2);
+    assert_eq!("ab\u{200B} ".trimmed_width(), 2);
+
+    assert_eq!("\u{a1}\u{3000}".trimmed_width(), 1);
+    assert_eq!("\u{a1}\u{3000}".trimmed_width_cjk(), 2);
+}
+
+#[test]
+fn test_fast_path() {
+    use super::UnicodeWidthChar;
+
+    // Codepoints below U+0100 are stored as narrow in the lookup tables, which is wrong for the
+    // control codes, so this fails if any of them is measured through the tables.
+    for c in '\0'..'\u{A0}' {
+        let expected = match c {
+            '\0' => Some(0),
+            '\u{20}'..='\u{7E}' => Some(1),
+            _ => None,
+        };
+        assert_eq!(c.width(), expected, "{:?}", c);
+        assert_eq!(c.width_cjk(), expected, "{:?}", c);
+    }
+}
+
+#[test]
+fn test_latin1_table() {
+    use super::{EastAsianWidth, UnicodeWidthChar};
+
+    // The Latin-1 table bypasses the lookup tables, which store these codepoints as narrow, so
+    // ambiguous characters are only wide in CJK contexts if the table is consulted. U+00AD SOFT
+    // HYPHEN is ambiguous, but always narrow.
+    for c in '\u{A0}'..'\u{100}' {
+        let cjk = if c != '\u{AD}' && c.east_asian_width() == EastAsianWidth::Ambiguous {
+            2
+        } else {
+            1
+        };
+        assert_eq!(c.width(), Some(1), "{:?}", c);
+        assert_eq!(c.width_cjk(), Some(cjk), "{:?}", c);
+    }
+}
+
+#[test]
+fn test_grid_cells() {
+    use super::grid_cells;
+
+    assert_eq!(grid_cells("", 80), 0);
+    assert_eq!(grid_cells("\u{301}\n", 80), 0);
+    assert_eq!(grid_cells("a", 80), 80);
+    assert_eq!(grid_cells("abcd", 4), 4);
+    assert_eq!(grid_cells("abcde", 4), 8);
+    assert_eq!(grid_cells("abcdefghij", 3), 12);
+    // The wide char at the margin moves to the next row
+    assert_eq!(grid_cells("abcＤ", 4), 8);
+    assert_eq!(grid_cells("abＣＤ", 3), 9);
+    assert_eq!(grid_cells("ＡＢＣ", 5), 10);
+    // Wide chars in a one-column grid get their own rows
+    assert_eq!(grid_cells("ＡＢa", 1), 3);
+    // Combining marks don't wrap away from their base
+    assert_eq!(grid_cells("abc\u{301}", 3), 3);
+}
+
+#[test]
+#[should_panic]
+fn test_grid_cells_zero_cols() {
+    super::grid_cells("a", 0);
+}
+
+#[test]
+fn test_width_with_mark_classifier() {
+    use super::{MarkKind, UnicodeWidthStr};
+
+    let usual = |_| MarkKind::NotAMark;
+    let spacing = |_| MarkKind::Spacing;
+    let non_spacing = |_| MarkKind::NonSpacing;
+
+    for &s in &["hello", "e\u{301}", "\u{995}\u{9BE}", "Ｈ\u{20DD}\x01"] {
+        assert_eq!(s.width_with_mark_classifier(&usual), s.width());
+    }
+    // Forcing a normally-zero mark to be spacing adds a column
+    assert_eq!("e\u{301}".width_with_mark_classifier(&spacing), 2);
+    assert_eq!("Ｈ\u{20DD}".width_with_mark_classifier(&spacing), 3);
+    // Forcing a spacing mark to be nonspacing removes one
+    assert_eq!("\u{995}\u{9BE}".width_with_mark_classifier(&non_spacing), 1);
+
+    // The classifier only sees marks
+    let panicky = |c| {
+        assert_eq!(c, '\u{301}');
+        MarkKind::Spacing
+    };
+    assert_eq!("ab\u{200B}\u{301}Ｃ\x01".width_with_mark_classifier(&panicky), 5);
+}
+
+#[test]
+fn test_offset_exceeding_width() {
+    use super::{offset_exceeding_width, UnicodeWidthStr};
+
+    let s = "日本語のテキスト";
+    assert_eq!(offset_exceeding_width(s, 16), None);
+    assert_eq!(offset_exceeding_width(s, 100), None);
+    // The 4th char ends at column 8
+    assert_eq!(offset_exceeding_width(s, 7), Some(9));
+    assert_eq!(offset_exceeding_width(s, 6), Some(9));
+    assert!(s.is_char_boundary(9));
+    assert_eq!(s[..9].width(), 6);
+    assert_eq!(offset_exceeding_width(s, 0), Some(0));
+
+    // Zero-width characters never overflow
+    assert_eq!(offset_exceeding_width("a\u{301}b", 1), Some(3));
+    assert_eq!(offset_exceeding_width("\u{301}\x01", 0), None);
+    assert_eq!(offset_exceeding_width("", 0), None);
+}
+
+#[test]
+fn test_vertical_advance() {
+    use super::{vertical_advance, UnicodeWidthStr};
+
+    let s = "日本語のテキスト";
+    assert_eq!(vertical_advance(s), s.chars().count());
+    assert_eq!(s.width(), 2 * s.chars().count());
+    assert_eq!(vertical_advance("abc"), 3);
+    assert_eq!(vertical_advance("\u{a1}\u{3000}"), 2);
+    // Marks, controls, and format characters don't advance
+    assert_eq!(vertical_advance("\u{304B}\u{3099}"), 1);
+    assert_eq!(vertical_advance("e\u{301}\u{995}\u{9BE}"), 2);
+    assert_eq!(vertical_advance("\x01\0\u{200B}\n"), 0);
+    assert_eq!(vertical_advance(""), 0);
+}
+
+#[test]
+fn test_combining_half_marks() {
+    use super::{UnicodeWidthChar, UnicodeWidthStr};
+
+    for c in '\u{FE20}'..='\u{FE2F}' {
+        assert_eq!(c.width(), Some(0));
+        assert_eq!(c.width_cjk(), Some(0));
+    }
+    // A ligature tie spanning "oo": left half over the first o, right half over the second
+    assert_eq!("o\u{FE20}o\u{FE21}".width(), 2);
+    assert_eq!("Ｏ\u{FE26}".width(), 2);
+}
+
+#[cfg(feature = "segmentation")]
+#[test]
+fn test_combining_half_marks_graphemes() {
+    use super::{cluster_width, width_graphemes};
+
+    assert_eq!(cluster_width("o\u{FE20}", false), 1);
+    assert_eq!(cluster_width("Ｏ\u{FE2D}", true), 2);
+    assert_eq!(width_graphemes("o\u{FE20}o\u{FE21}", false), 2);
+}
+
+#[test]
+fn test_width_pattern_ws_zero() {
+    use super::{width_pattern_ws_zero, UnicodeWidthStr};
+
+    // Form feed and NEL
+    assert_eq!(width_pattern_ws_zero("\x0C"), 0);
+    assert_eq!(width_pattern_ws_zero("\u{85}"), 0);
+    assert_eq!(width_pattern_ws_zero("a\x0C\u{85}b"), 2);
+    // LINE SEPARATOR and PARAGRAPH SEPARATOR are normally narrow
+    assert_eq!("\u{2028}\u{2029}".width(), 2);
+    assert_eq!(width_pattern_ws_zero("\u{2028}\u{2029}"), 0);
+    assert_eq!(width_pattern_ws_zero("\t\n\x0B\r\u{200E}\u{200F}"), 0);
+    // Spaces keep their width, and other whitespace isn't affected
+    assert_eq!(width_pattern_ws_zero("    x"), 5);
+    assert_eq!(width_pattern_ws_zero("\u{A0}\u{3000}"), 3);
+    assert_eq!(width_pattern_ws_zero("Ｈｉ"), 4);
+}
+
+// "…" is 2 columns wide with the `cjk-punctuation-wide` feature
+#[cfg(all(feature = "alloc", not(feature = "cjk-punctuation-wide")))]
+#[test]
+fn test_ellipsize() {
+    use super::{ellipsize, UnicodeWidthStr};
+
+    let s = "日本語のテキスト";
+    assert_eq!(ellipsize(s, 16, "…"), s);
+    assert_eq!(ellipsize(s, 100, "…"), s);
+    assert_eq!(ellipsize(s, 15, "…"), "日本語のテキス…");
+    // The wide char which would straddle the limit is dropped
+    assert_eq!(ellipsize(s, 8, "…"), "日本語…");
+    assert_eq!(ellipsize(s, 8, "…").width(), 7);
+    assert_eq!(ellipsize(s, 2, "…"), "…");
+    assert_eq!(ellipsize(s, 1, "…"), "…");
+    assert_eq!(ellipsize("hello world", 8, "..."), "hello...");
+
+    // The ellipsis itself doesn't fit
+    assert_eq!(ellipsize(s, 0, "…"), "");
+    assert_eq!(ellipsize("hello", 2, "..."), "..");
+    assert_eq!(ellipsize("", 0, "..."), "");
+    assert_eq!(ellipsize("hello", 3, ""), "hel");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_join_within_width() {
+    use super::join_within_width;
+
+    assert_eq!(join_within_width(&[], "|", 10), ("".to_string(), 0));
+    assert_eq!(join_within_width(&["abc"], "|", 3), ("abc".to_string(), 0));
+    assert_eq!(join_within_width(&["abc"], "|", 2), ("".to_string(), 1));
+
+    // CJK segments and a wide separator in a narrow budget
+    let segments = ["日本", "語", "テキスト"];
+    assert_eq!(join_within_width(&segments, "・", 18), ("日本・語・テキスト".to_string(), 0));
+    assert_eq!(join_within_width(&segments, "・", 17), ("日本・語".to_string(), 1));
+    assert_eq!(join_within_width(&segments, "・", 8), ("日本・語".to_string(), 1));
+    assert_eq!(join_within_width(&segments, "・", 7), ("日本".to_string(), 2));
+    assert_eq!(join_within_width(&segments, "・", 4), ("日本".to_string(), 2));
+    assert_eq!(join_within_width(&segments, "・", 3), ("".to_string(), 3));
+    // A later segment which would fit doesn't skip over one which doesn't
+    assert_eq!(join_within_width(&["a", "ｗｉｄｅ", "b"], " ", 5), ("a".to_string(), 2));
+}
+
+#[test]
+fn test_is_simple_latin() {
+    use super::{UnicodeWidthChar, UnicodeWidthStr};
+
+    // The guarantee holds for every qualifying character
+    for c in ('\u{20}'..='\u{7E}').chain('\u{A0}'..='\u{24F}') {
+        assert_eq!(c.width(), Some(1), "{:?}", c);
+    }
+
+    assert!("".is_simple_latin());
+    assert!("Hello, world!".is_simple_latin());
+    // Precomposed accents
+    assert!("caf\u{E9} na\u{EF}ve \u{141}\u{F3}d\u{17A} \u{1F0}".is_simple_latin());
+    // Decomposed accents
+    assert!(!"cafe\u{301}".is_simple_latin());
+    // Controls, IPA, Greek, and wide characters
+    assert!(!"a\tb".is_simple_latin());
+    assert!(!"\u{85}".is_simple_latin());
+    assert!(!"\u{250}".is_simple_latin());
+    assert!(!"\u{3B1}".is_simple_latin());
+    assert!(!"Ｈｉ".is_simple_latin());
+}
+
+#[test]
+fn test_first_line_width() {
+    use super::UnicodeWidthStr;
+
+    assert_eq!("ＡＢ\ncd".first_line_width(), 4);
+    assert_eq!("ＡＢ\r\ncd".first_line_width(), 4);
+    assert_eq!("ＡＢ\u{2028}cd".first_line_width(), 4);
+    assert_eq!("ＡＢ\u{2029}cd".first_line_width(), 4);
+    // No newline
+    assert_eq!("ＡＢcd".first_line_width(), 6);
+    assert_eq!("a\rb".first_line_width(), 2);
+    // Empty first line
+    assert_eq!("\nabc".first_line_width(), 0);
+    assert_eq!("\r\nabc".first_line_width(), 0);
+    assert_eq!("".first_line_width(), 0);
+
+    assert_eq!("\u{a1}\n\u{a1}\u{a1}".first_line_width(), 1);
+    assert_eq!("\u{a1}\n\u{a1}\u{a1}".first_line_width_cjk(), 2);
+}
+
+#[test]
+fn test_thai_lao() {
+    use super::{UnicodeWidthChar, UnicodeWidthStr};
+
+    // Above and below vowels, tone marks, and other stacking marks
+    let thai = ('\u{E34}'..='\u{E3A}').chain('\u{E47}'..='\u{E4E}');
+    let lao = ('\u{EB4}'..='\u{EBC}').chain('\u{EC8}'..='\u{ECD}');
+    for c in thai.chain(lao).chain(['\u{E31}', '\u{EB1}'].iter().cloned()) {
+        assert_eq!(c.width(), Some(0), "{:?}", c);
+        assert_eq!(c.width_cjk(), Some(0), "{:?}", c);
+    }
+    // Consonants and spacing vowels, including the leading vowels and SARA AM
+    for &c in &[
+        '\u{E01}', '\u{E2E}', '\u{E30}', '\u{E32}', '\u{E33}', '\u{E40}', '\u{E44}', '\u{E45}',
+        '\u{E81}', '\u{EAE}', '\u{EB0}', '\u{EB2}', '\u{EB3}', '\u{EC0}', '\u{EC4}',
+    ] {
+        assert_eq!(c.width(), Some(1), "{:?}", c);
+    }
+
+    // KO KAI + SARA II + MAI EK: a consonant with a vowel and tone mark stacked above
+    assert_eq!("\u{E01}\u{E35}\u{E48}".width(), 1);
+    // NO NU + MAI THO + SARA AM
+    assert_eq!("\u{E19}\u{E49}\u{E33}".width(), 2);
+    // "Thai language"
+    assert_eq!("\u{E20}\u{E32}\u{E29}\u{E32}\u{E44}\u{E17}\u{E22}".width(), 7);
+    // Lao KO + vowel sign II + MAI THO
+    assert_eq!("\u{E81}\u{EB5}\u{EC9}".width(), 1);
+}
+
+#[test]
+fn test_sutton_signwriting() {
+    use super::{UnicodeWidthChar, UnicodeWidthStr};
+
+    // The fill and rotation modifiers are nonspacing marks
+    let modifiers = [
+        '\u{1DA00}'..='\u{1DA36}',
+        '\u{1DA3B}'..='\u{1DA6C}',
+        '\u{1DA75}'..='\u{1DA75}',
+        '\u{1DA84}'..='\u{1DA84}',
+        '\u{1DA9B}'..='\u{1DA9F}',
+        '\u{1DAA1}'..='\u{1DAAF}',
+    ];
+    // Everything else, including the punctuation and the unassigned gaps, is narrow
+    for c in '\u{1D800}'..='\u{1DAAF}' {
+        let expected = if modifiers.iter().any(|r| r.contains(&c)) {
+            0
+        } else {
+            1
+        };
+        assert_eq!(c.width(), Some(expected), "{:?}", c);
+        assert_eq!(c.width_cjk(), Some(expected), "{:?}", c);
+    }
+
+    // HAND-FIST INDEX with FILL MODIFIER-3 and ROTATION MODIFIER-4
+    assert_eq!("\u{1D800}\u{1DA9C}\u{1DAA3}".width(), 1);
+    // SIGNWRITING FULL STOP and COMMA
+    assert_eq!("\u{1DA88}\u{1DA87}".width(), 2);
+}
+
+#[test]
+fn test_width_runs() {
+    use super::UnicodeWidthStr;
+
+    let runs: Vec<_> = "abＣＤe".width_runs().collect();
+    assert_eq!(runs, [(1, 2), (2, 2), (1, 1)]);
+    assert_eq!("".width_runs().next(), None);
+    let runs: Vec<_> = "e\u{301}\u{302}\x01Ｘ".width_runs().collect();
+    assert_eq!(runs, [(1, 1), (0, 3), (2, 1)]);
+
+    let runs: Vec<_> = "a\u{a1}\u{a1}Ｘ".width_runs().collect();
+    assert_eq!(runs, [(1, 3), (2, 1)]);
+    let runs: Vec<_> = "a\u{a1}\u{a1}Ｘ".width_runs_cjk().collect();
+    assert_eq!(runs, [(1, 1), (2, 3)]);
+
+    for &s in &["hello", "Ｈｅｌｌｏ, world", "\u{a1}e\u{301}\x01\0"] {
+        let total: usize = s.width_runs().map(|(width, count)| width * count).sum();
+        assert_eq!(total, s.width());
+        let count: usize = s.width_runs_cjk().map(|(_, count)| count).sum();
+        assert_eq!(count, s.chars().count());
+    }
+}
+
+#[test]
+fn test_wrapped_line_count() {
+    use super::wrapped_line_count;
+
+    // Five wide chars fit in each row of 10 columns, but only four fit in 9
+    assert_eq!(wrapped_line_count("日本語のテキスト", 10), 2);
+    assert_eq!(wrapped_line_count("日本語のテキストです", 10), 2);
+    assert_eq!(wrapped_line_count("日本語のテキストです。", 10), 3);
+    assert_eq!(wrapped_line_count("日本語のテキスト", 9), 2);
+    assert_eq!(wrapped_line_count("日本語のテキスト", 3), 8);
+    assert_eq!(wrapped_line_count("aaaaaaaaa日", 10), 2);
+
+    // Every logical line takes at least one row
+    assert_eq!(wrapped_line_count("", 10), 1);
+    assert_eq!(wrapped_line_count("a\n\nb", 10), 3);
+    assert_eq!(wrapped_line_count("abc\r\n", 10), 2);
+    assert_eq!(wrapped_line_count("abcdefghijk\nabc", 10), 3);
+}
+
+#[test]
+#[should_panic]
+fn test_wrapped_line_count_zero_cols() {
+    super::wrapped_line_count("a", 0);
+}
+
+#[test]
+fn test_eaw_histogram() {
+    use super::{eaw_histogram, EastAsianWidth};
+
+    assert_eq!(eaw_histogram(""), [0; 6]);
+    // Neutral: U+05D0, U+00A9; Narrow: "ab"; Wide: 日本; FullWidth: Ａ;
+    // HalfWidth: U+FF71; Ambiguous: U+00A1, U+03B1, U+0301
+    let counts = eaw_histogram("\u{5D0}\u{A9}ab日本Ａ\u{FF71}\u{A1}\u{3B1}\u{301}");
+    assert_eq!(counts, [2, 2, 2, 1, 1, 3]);
+    assert_eq!(counts[EastAsianWidth::Ambiguous as usize], 3);
+    assert_eq!(counts[EastAsianWidth::Neutral as usize], 2);
+}
+
+#[test]
+fn test_redraw_info() {
+    use super::{redraw_info, UnicodeWidthStr};
+
+    assert_eq!(redraw_info("", ""), (0, 0));
+    assert_eq!(redraw_info("", "abc"), (0, 3));
+    assert_eq!(redraw_info("abc", ""), (0, -3));
+    // Only the suffix differs
+    assert_eq!(redraw_info("hello world", "hello there"), (6, 0));
+    assert_eq!(redraw_info("hello", "hello, 世界"), (5, 6));
+    assert_eq!(redraw_info("prefix 日本語", "prefix 日本"), (11, -2));
+    assert_eq!(redraw_info("ab\u{FF23}", "ab\u{FF23}d"), (4, 1));
+    // Zero-width characters in the prefix don't move the column
+    assert_eq!(redraw_info("a\u{301}b", "a\u{301}c"), (1, 0));
+    // A combining mark added to the last character
+    assert_eq!(redraw_info("e", "e\u{301}"), (1, 0));
+
+    for &(old, new) in &[("abc", "abd"), ("日本", "日"), ("x", "xｙz")] {
+        let (column, delta) = redraw_info(old, new);
+        assert!(column <= old.width() && column <= new.width());
+        assert_eq!(new.width() as isize - old.width() as isize, delta);
+    }
+}
+
+#[test]
+fn test_strict_width() {
+    use super::{strict_width, UnicodeWidthStr};
+
+    assert_eq!(strict_width(""), Ok(0));
+    let ascii = "The quick brown fox jumps over the lazy dog.";
+    assert_eq!(strict_width(ascii), Ok(ascii.len()));
+    assert_eq!(strict_width("caf\u{e9} \u{a1}"), Ok(6));
+    // Wide characters
+    assert_eq!(strict_width("abc日本"), Err((3, '日')));
+    assert_eq!(strict_width("\u{FF21}"), Err((0, '\u{FF21}')));
+    // Controls, including NUL
+    assert_eq!(strict_width("ab\x1b[0m"), Err((2, '\x1b')));
+    assert_eq!(strict_width("ab\0"), Err((2, '\0')));
+    assert_eq!(strict_width("line\n"), Err((4, '\n')));
+    // Zero-width characters
+    assert_eq!(strict_width("e\u{301}"), Err((1, '\u{301}')));
+    assert_eq!(strict_width("ab\u{200B}"), Err((2, '\u{200B}')));
+
+    let s = "\u{e9}\u{e9}\u{1F600}";
+    let (offset, _) = strict_width(s).unwrap_err();
+    assert_eq!(s[..offset].width(), 2);
+}
+
+#[test]
+fn test_measure_width() {
+    use super::{measure_width, UnicodeWidthStr, WidthSource};
+
+    struct TwoChunks<'a>(&'a str, &'a str);
+
+    impl<'a> WidthSource for &TwoChunks<'a> {
+        type Chars = core::iter::Chain<core::str::Chars<'a>, core::str::Chars<'a>>;
+
+        fn chars(self) -> Self::Chars {
+            self.0.chars().chain(self.1.chars())
+        }
+    }
+
+    let s = "ab\u{FF23}\u{A1}";
+    assert_eq!(measure_width(s, false), s.width());
+    assert_eq!(measure_width(s, true), s.width_cjk());
+
+    assert_eq!(measure_width(&TwoChunks("", ""), false), 0);
+    assert_eq!(measure_width(&TwoChunks("日本", "語!"), false), 7);
+    // A combining mark at the start of a chunk combines with the end of the previous one
+    assert_eq!(measure_width(&TwoChunks("cafe", "\u{301} au lait"), false), 12);
+    assert_eq!(measure_width(&TwoChunks("\u{A1}", "\u{A1}"), true), 4);
+    // So do variation selectors and the halves of a flag
+    assert_eq!(measure_width(&TwoChunks("\u{2600}", "\u{FE0F}"), false), 2);
+    assert_eq!(measure_width(&TwoChunks("\u{1F1FA}", "\u{1F1F8}"), false), 2);
+    assert_eq!(measure_width(&TwoChunks("\u{1F1FA}", "\u{1F1F8}\u{1F1FA}"), false), 4);
+}
+
+#[test]
+fn test_width_force_mono() {
+    use super::UnicodeWidthStr;
+
+    assert_eq!("".width_force_mono(), 0);
+    assert_eq!("hello".width_force_mono(), 5);
+    // Wide characters are clipped to one cell each
+    assert_eq!("日本語".width_force_mono(), 3);
+    assert_eq!("\u{FF21}\u{FF22}".width_force_mono(), 2);
+    // Combining marks and other zero-width characters get their own cell
+    assert_eq!("e\u{301}\u{302}".width_force_mono(), 3);
+    assert_eq!("a\u{200B}b".width_force_mono(), 3);
+    // Controls, including NUL, are still skipped
+    assert_eq!("a\tb\n\0".width_force_mono(), 2);
+}
+
+#[test]
+fn test_fits_with_cursor() {
+    use super::{fits, fits_with_cursor};
+
+    assert!(fits("", 0));
+    assert!(!fits_with_cursor("", 0));
+    assert!(fits_with_cursor("", 1));
+
+    // Exactly filling the line leaves no column for the cursor
+    assert!(fits("hello", 5));
+    assert!(!fits_with_cursor("hello", 5));
+    assert!(fits_with_cursor("hello", 6));
+    assert!(!fits("hello", 4));
+
+    assert!(fits("a\u{FF22}", 3));
+    assert!(!fits_with_cursor("a\u{FF22}", 3));
+    assert!(fits_with_cursor("e\u{301}", 2));
+}
+
+#[test]
+fn test_is_private_use() {
+    use super::UnicodeWidthChar;
+
+    assert!('\u{E000}'.is_private_use());
+    assert!('\u{F8FF}'.is_private_use());
+    // A Nerd Fonts glyph
+    assert!('\u{E0A0}'.is_private_use());
+    assert!('\u{F0000}'.is_private_use());
+    assert!('\u{10FFFD}'.is_private_use());
+
+    assert!(!'a'.is_private_use());
+    assert!(!'\u{F900}'.is_private_use());
+    assert!(!'\u{EFFFF}'.is_private_use());
+    // The last two codepoints of each supplementary plane are noncharacters
+    assert!(!'\u{FFFFE}'.is_private_use());
+    assert!(!'\u{10FFFF}'.is_private_use());
+}
+
+#[test]
+fn test_wrapping_zwj_sequences() {
+    use super::{grid_cells, wrapped_line_count};
+
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    // The sequence fits in one row, and moves to the next row as a whole if it doesn't fit
+    assert_eq!(wrapped_line_count(family, 6), 1);
+    assert_eq!(wrapped_line_count(&format!("a{}", family), 6), 2);
+    assert_eq!(wrapped_line_count(&format!("{}a", family), 6), 2);
+    assert_eq!(grid_cells(&format!("ab{}cd", family), 8), 16);
+    // A sequence wider than a row overflows it instead of being split
+    assert_eq!(wrapped_line_count(family, 4), 1);
+    assert_eq!(wrapped_line_count(family, 1), 1);
+    assert_eq!(wrapped_line_count(&format!("ab{}c", family), 4), 3);
+    // Unjoined emoji still wrap individually
+    assert_eq!(wrapped_line_count("\u{1F468}\u{1F469}\u{1F467}", 4), 2);
+    // A ZWJ with nothing before it doesn't join anything
+    assert_eq!(wrapped_line_count("\u{200D}\u{1F468}\u{1F469}", 2), 2);
+}
+
+#[test]
+fn test_width_with_wide_threshold() {
+    use super::UnicodeWidthStr;
+
+    assert_eq!("".width_with_wide_threshold('\u{1100}'), 0);
+    assert_eq!("hello".width_with_wide_threshold('\u{1100}'), 5);
+    // Hangul Jamo: a leading consonant is wide, but the vowel and trailing consonant are
+    // zero-width according to the tables
+    let jamo = "\u{1100}\u{1161}\u{11A8}";
+    assert_eq!(jamo.width(), 2);
+    assert_eq!(jamo.width_with_wide_threshold('\u{1100}'), 6);
+    // Narrow characters above the threshold are wide too
+    assert_eq!("\u{2192}\u{FF61}".width_with_wide_threshold('\u{1100}'), 4);
+    // Characters below the threshold keep their usual width, including controls
+    assert_eq!("e\u{301}\t\u{A1}".width_with_wide_threshold('\u{1100}'), 2);
+    assert_eq!("ab".width_with_wide_threshold('b'), 3);
+    assert_eq!("\0\x01".width_with_wide_threshold('\0'), 4);
+}
+
+#[test]
+fn test_width_range() {
+    use super::UnicodeWidthStr;
+
+    assert_eq!("".width_range(), (0, 0));
+    assert_eq!("hello, world".width_range(), (12, 12));
+    assert_eq!("\u{FF21}\u{FF22}".width_range(), (4, 4));
+    // Ambiguous characters, in and out of the Latin-1 Supplement
+    assert_eq!("\u{A1}\u{3B1}".width_range(), (2, 4));
+    #[cfg(not(feature = "cjk-punctuation-wide"))]
+    assert_eq!("a\u{2026}\u{4E00}\x1b".width_range(), (4, 5));
+
+    for &s in &["\u{A1}e\u{301}\x01\0", "\u{1F600}\u{200D}\u{1F600}", "\u{2192} \u{E000}\u{FE0F}"] {
+        assert_eq!(s.width_range(), (s.width(), s.width_cjk()));
+    }
+    let mut buf = [0; 4];
+    for c in (0..0x3000).filter_map(::core::char::from_u32) {
+        let s = c.encode_utf8(&mut buf);
+        assert_eq!(s.width_range(), (s.width(), s.width_cjk()), "{:?}", c);
+    }
+}
+
+#[test]
+fn test_recent_symbols() {
+    use super::UnicodeWidthChar;
+
+    // Currency signs added in Unicode 8.0, 10.0, 14.0, and 17.0 are narrow
+    for &c in &['\u{20BE}', '\u{20BF}', '\u{20C0}', '\u{20C1}'] {
+        assert_eq!(c.width(), Some(1), "{:?}", c);
+        assert_eq!(c.width_cjk(), Some(1), "{:?}", c);
+    }
+    // So are symbols without emoji presentation, like the Unicode 13.0 Symbols for Legacy
+    // Computing (and its 16.0 supplement) and the Unicode 14.0 LEFTWARDS ARROW ABOVE SHORT
+    // RIGHTWARDS ARROW
+    for &c in &['\u{1FB00}', '\u{1FBCA}', '\u{1FBF0}', '\u{1FBF9}', '\u{2B97}', '\u{1F10D}'] {
+        assert_eq!(c.width(), Some(1), "{:?}", c);
+    }
+    for &c in &['\u{1FBCB}', '\u{1CC00}'] {
+        assert_eq!(c.width(), Some(1), "{:?}", c);
+    }
+    // Emoji added in Unicode 13.0 and 14.0 are wide
+    let emoji = [
+        '\u{1F6D7}', // ELEVATOR
+        '\u{1F90C}', // PINCHED FINGERS
+        '\u{1F977}', // NINJA
+        '\u{1FAC3}', // PREGNANT MAN
+        '\u{1F6DD}', // PLAYGROUND SLIDE
+        '\u{1F6DF}', // RING BUOY
+        '\u{1F7F0}', // HEAVY EQUALS SIGN
+        '\u{1F979}', // FACE HOLDING BACK TEARS
+        '\u{1FA7B}', // X-RAY
+        '\u{1FAE0}', // MELTING FACE
+    ];
+    for &c in &emoji {
+        assert_eq!(c.width(), Some(2), "{:?}", c);
+        assert_eq!(c.width_cjk(), Some(2), "{:?}", c);
+    }
+}
+
+#[test]
+#[cfg(not(any(feature = "unicode-15-0", feature = "unicode-15-1")))]
+fn test_symbols_after_unicode_14() {
+    use super::{UnicodeWidthChar, UnicodeWidthStr};
+
+    // Emoji added in Unicode 15.0 through 17.0 are wide, not measured as unassigned
+    let emoji = [
+        '\u{1FA75}', // LIGHT BLUE HEART
+        '\u{1FAE8}', // SHAKING FACE
+        '\u{1F6DC}', // WIRELESS
+        '\u{1FAE9}', // FACE WITH BAGS UNDER EYES
+        '\u{1FA89}', // HARP
+        '\u{1FAEA}', // DISTORTED FACE
+        '\u{1FA8E}', // TROMBONE
+    ];
+    for &c in &emoji {
+        assert_eq!(c.width(), Some(2), "{:?}", c);
+        assert_eq!(c.width_cjk(), Some(2), "{:?}", c);
+    }
+    assert_eq!("\u{1FAE8}\u{1FA75}".width(), 4);
+    // So are the ideographs of CJK Unified Ideographs Extensions H, I, and J and the Unicode 15.1
+    // ideographic description characters
+    for &c in &['\u{31350}', '\u{2EBF0}', '\u{323B0}', '\u{2FFC}', '\u{31EF}'] {
+        assert_eq!(c.width(), Some(2), "{:?}", c);
+    }
+    // Nonspacing marks added since, like U+0ECE LAO YAMAKKAN, are zero-width
+    for &c in &['\u{ECE}', '\u{11F00}'] {
+        assert_eq!(c.width(), Some(0), "{:?}", c);
+    }
+}
+
+#[test]
+fn test_assert_width() {
+    assert_width!("", 0);
+    assert_width!("abc", 3);
+    assert_width!(String::from("\u{FF21}\u{FF22}"), 4,);
+    assert_width!("e\u{301}\x1b", 1);
+}
+
+#[test]
+fn test_assert_width_message() {
+    use display::WidthBreakdown;
+
+    assert_eq!(
+        format!("{}", WidthBreakdown("a\u{FF22}\u{301}\n")),
+        concat!(
+            "       0: U+0061 'a' => 1\n",
+            "       1: U+FF22 '\u{FF22}' => 2\n",
+            "       4: U+0301 '\\u{301}' => 0\n",
+            "       6: U+000A '\\n' => 0\n",
+        )
+    );
+
+    let message = ::std::panic::catch_unwind(|| assert_width!("a\u{FF22}", 2))
+        .unwrap_err()
+        .downcast::<String>()
+        .unwrap();
+    assert!(message.starts_with("assertion failed: `width(\"a\u{FF22}\") == 2`\n  actual width: 3\n"));
+    assert!(message.contains("U+FF22"));
+}
+
+#[test]
+fn test_cjk_compatibility_ideographs() {
+    use super::{EastAsianWidth, UnicodeWidthChar, UnicodeWidthStr};
+
+    // CJK Compatibility Ideographs and CJK Compatibility Ideographs Supplement, including the
+    // unassigned codepoints, which default to Wide
+    for c in ('\u{F900}'..='\u{FAFF}').chain('\u{2F800}'..='\u{2FA1F}') {
+        assert_eq!(c.width(), Some(2), "{:?}", c);
+        assert_eq!(c.width_cjk(), Some(2), "{:?}", c);
+    }
+    assert_eq!('\u{F900}'.east_asian_width(), EastAsianWidth::Wide);
+    assert_eq!('\u{2F800}'.east_asian_width(), EastAsianWidth::Wide);
+
+    // Each is as wide as the unified ideograph it decomposes to
+    let decompositions = [
+        ('\u{F900}', '\u{8C48}'),
+        ('\u{FA10}', '\u{585A}'),
+        ('\u{2F80C}', '\u{349E}'),
+    ];
+    for &(compat, unified) in &decompositions {
+        assert_eq!(compat.width(), unified.width());
+    }
+    // Standardized variants: a unified ideograph followed by a variation selector, which is
+    // equivalent to a compatibility ideograph
+    assert_eq!("\u{349E}\u{FE00}".width(), 2);
+    assert_eq!("\u{2F80C}\u{FE00}".width(), 2);
+}
+
+#[cfg(feature = "segmentation")]
+#[test]
+fn test_cjk_compatibility_ideographs_graphemes() {
+    use super::{cluster_width, width_graphemes};
+
+    assert_eq!(cluster_width("\u{F900}\u{FE00}", false), 2);
+    assert_eq!(cluster_width("\u{2F80C}\u{FE00}", true), 2);
+    assert_eq!(cluster_width("\u{349E}\u{FE00}", false), 2);
+    assert_eq!(width_graphemes("\u{FA10}\u{FE00}\u{2F800}\u{E0100}", false), 4);
+}
+
+#[test]
+fn test_force_emoji_wide() {
+    use super::{UnicodeWidthChar, UnicodeWidthStr, WidthCalculator, WidthOptions};
+
+    let forced = WidthOptions::new().force_emoji_wide(true);
+    // Text-presentation-default emoji
+    for &c in &['\u{2600}', '\u{263A}', '\u{2764}', '\u{A9}', '\u{2122}', '\u{1F1E6}'] {
+        assert_eq!(c.width(), Some(1), "{:?}", c);
+        assert_eq!(c.width_with(forced), Some(2), "{:?}", c);
+    }
+    // Even when text presentation is requested
+    assert_eq!("\u{2600}\u{FE0E}".width_with(forced), 2);
+    // Emoji-presentation-default emoji are already wide
+    assert_eq!('\u{1F600}'.width_with(forced), Some(2));
+    // The ASCII digits, '#', and '*' and non-emoji are unaffected
+    assert_eq!("#*0123456789".width_with(forced), 12);
+    assert_eq!("a\u{2192}\u{301}".width_with(forced), 2);
+
+    assert_eq!(WidthCalculator::new().force_emoji_wide(true).measure("\u{2600}!"), 3);
+}
+
+#[test]
+fn test_char_range_width() {
+    use super::{char_range_width, UnicodeWidthStr};
+
+    let v: Vec<char> = "a\u{FF22}\u{A1}e\u{301}\x1b".chars().collect();
+    assert_eq!(char_range_width(&v, 1..3, false), 3);
+    assert_eq!(char_range_width(&v, 1..3, true), 4);
+    let s: String = v[1..3].iter().collect();
+    assert_eq!(char_range_width(&v, 1..3, false), s.width());
+    assert_eq!(char_range_width(&v, 0..v.len(), false), 5);
+    assert_eq!(char_range_width(&v, 3..5, false), 1);
+    assert_eq!(char_range_width(&v, 2..2, false), 0);
+    assert_eq!(char_range_width(&v, v.len()..v.len(), false), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_char_range_width_out_of_bounds() {
+    use super::char_range_width;
+
+    char_range_width(&['a', 'b'], 1..3, false);
+}
+
+#[test]
+fn test_is_noncharacter() {
+    use super::UnicodeWidthChar;
+
+    assert!('\u{10FFFE}'.is_noncharacter());
+    assert!('\u{10FFFF}'.is_noncharacter());
+    assert!('\u{FFFE}'.is_noncharacter());
+    assert!('\u{1FFFF}'.is_noncharacter());
+    assert!('\u{FDD0}'.is_noncharacter());
+    assert!('\u{FDEF}'.is_noncharacter());
+    let count = (0..=0x10FFFF)
+        .filter_map(::core::char::from_u32)
+        .filter(|c| c.is_noncharacter())
+        .count();
+    assert_eq!(count, 66);
+
+    assert!(!'a'.is_noncharacter());
+    assert!(!'\u{FDCF}'.is_noncharacter());
+    assert!(!'\u{FDF0}'.is_noncharacter());
+    assert!(!'\u{FFFD}'.is_noncharacter());
+    assert!(!'\u{10FFFD}'.is_noncharacter());
+}
+
+#[cfg(feature = "segmentation")]
+#[test]
+fn test_width_graphemes_ascii_prefix() {
+    use super::{cluster_width, width_graphemes};
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let strings = [
+        "",
+        "a",
+        "hello, world",
+        "\r\n",
+        "ab\r\n\u{FF21}",
+        "a\x01b\x7F",
+        "#\u{FE0F}\u{20E3}",
+        "abc1\u{20E3}",
+        "abc1\u{FE0F}\u{20E3}!",
+        "cafe\u{301}",
+        "e\u{301}abc",
+        "ab\u{1F468}\u{200D}\u{1F9B0}cd",
+        "a\u{200D}\u{1F9B0}",
+        "x\u{A1}",
+        "\u{1F1FA}\u{1F1F8}us",
+    ];
+    for &s in &strings {
+        for &is_cjk in &[false, true] {
+            let expected: usize = s.graphemes(true).map(|g| cluster_width(g, is_cjk)).sum();
+            assert_eq!(width_graphemes(s, is_cjk), expected, "{:?}", s);
+        }
+    }
+}
+
+#[test]
+fn test_enclosed_blocks() {
+    use super::{EastAsianWidth, UnicodeWidthChar, UnicodeWidthStr};
+
+    // Enclosed Alphanumerics are all Ambiguous, except U+24EA CIRCLED DIGIT ZERO
+    for c in '\u{2460}'..='\u{24FF}' {
+        let (eaw, cjk) = if c == '\u{24EA}' {
+            (EastAsianWidth::Neutral, 1)
+        } else {
+            (EastAsianWidth::Ambiguous, 2)
+        };
+        assert_eq!(c.east_asian_width(), eaw, "{:?}", c);
+        assert_eq!(c.width(), Some(1), "{:?}", c);
+        assert_eq!(c.width_cjk(), Some(cjk), "{:?}", c);
+    }
+    // Enclosed CJK Letters and Months are Wide, except the circled numbers on black squares
+    // (U+3248..U+324F), which are Ambiguous, and the unassigned U+321F
+    for c in '\u{3200}'..='\u{32FF}' {
+        let (width, cjk) = match c {
+            '\u{3248}'..='\u{324F}' => (1, 2),
+            '\u{321F}' => (1, 1),
+            _ => (2, 2),
+        };
+        assert_eq!(c.width(), Some(width), "{:?}", c);
+        assert_eq!(c.width_cjk(), Some(cjk), "{:?}", c);
+    }
+
+    // CIRCLED DIGIT ONE, PARENTHESIZED HANGUL KIYEOK, CIRCLED IDEOGRAPH ONE
+    assert_eq!("\u{2460}\u{3200}\u{3280}".width(), 5);
+    assert_eq!("\u{2460}\u{3200}\u{3280}".width_cjk(), 6);
+    // CIRCLED NUMBER TEN ON BLACK SQUARE, CIRCLED KATAKANA A
+    assert_eq!("\u{3248}\u{32D0}".width(), 3);
+}
+
+#[test]
+fn test_width_and_rtl() {
+    use super::{width_and_rtl, UnicodeWidthStr};
+
+    assert_eq!(width_and_rtl(""), (0, false));
+    assert_eq!(width_and_rtl("Hello, world!"), (13, false));
+    assert_eq!(width_and_rtl("\u{65E5}\u{672C}"), (4, false));
+    // Hebrew, with a point, which is a nonspacing mark
+    let hebrew = "\u{5E9}\u{5B8}\u{5DC}\u{5D5}\u{5DD}";
+    assert_eq!(width_and_rtl(hebrew), (hebrew.width(), true));
+    assert_eq!(width_and_rtl(hebrew), (4, true));
+    // Arabic, and a single RTL character in otherwise LTR text
+    assert_eq!(width_and_rtl("\u{633}\u{644}\u{627}\u{645}"), (4, true));
+    assert_eq!(width_and_rtl("abc \u{5D0} def"), (9, true));
+    // Presentation forms and astral RTL scripts
+    assert_eq!(width_and_rtl("\u{FB1D}"), (1, true));
+    assert_eq!(width_and_rtl("\u{10900}"), (1, true));
+    // Arabic-Indic digits, Hebrew points, and explicit overrides aren't strong RTL...
+    assert_eq!(width_and_rtl("\u{661}\u{662}\u{5B8}"), (2, false));
+    assert_eq!(width_and_rtl("\u{202E}abc\u{202C}"), (3, false));
+    // ...but RIGHT-TO-LEFT MARK is
+    assert_eq!(width_and_rtl("\u{200F}abc"), (3, true));
+}
+
+#[test]
+fn test_cursor_stops() {
+    use super::UnicodeWidthStr;
+
+    assert_eq!("".cursor_stops(), 0);
+    assert_eq!("hello".cursor_stops(), 5);
+    // Wide characters are one stop each, though they're two columns wide
+    let cjk = "日本語のテキスト";
+    assert_eq!(cjk.cursor_stops(), cjk.chars().count());
+    assert_eq!(cjk.width(), 2 * cjk.chars().count());
+    // Combining marks, including spacing marks, share a stop with their base
+    let combining = "e\u{301}\u{302}a";
+    assert!(combining.cursor_stops() < combining.chars().count());
+    assert_eq!(combining.cursor_stops(), 2);
+    assert_eq!("\u{915}\u{93F}".cursor_stops(), 1);
+    // Zero-width characters which aren't marks are still stops
+    assert_eq!("a\u{200B}b".cursor_stops(), 3);
+}
+
+#[cfg(feature = "segmentation")]
+#[test]
+fn test_cursor_stops_graphemes() {
+    use super::{cursor_stops_graphemes, UnicodeWidthStr};
+
+    assert_eq!(cursor_stops_graphemes(""), 0);
+    assert_eq!(cursor_stops_graphemes("日本語"), 3);
+    assert_eq!(cursor_stops_graphemes("e\u{301}a"), 2);
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    assert_eq!(family.cursor_stops(), 5);
+    assert_eq!(cursor_stops_graphemes(family), 1);
+    assert_eq!(cursor_stops_graphemes("\u{1F1FA}\u{1F1F8}\r\n"), 2);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_width_by_script() {
+    use super::{width_by_script, Script, UnicodeWidthStr};
+
+    assert!(width_by_script("").is_empty());
+    let s = "Unicode 日本語 text";
+    let widths = width_by_script(s);
+    assert_eq!(widths[&Script::Latin], 11);
+    assert_eq!(widths[&Script::Han], 6);
+    assert_eq!(widths[&Script::Common], 2);
+    assert_eq!(widths.len(), 3);
+    assert_eq!(widths.values().sum::<usize>(), s.width());
+    // Combining marks belong to no particular script, and have no width of their own
+    let widths = width_by_script("e\u{301}");
+    assert_eq!(widths[&Script::Latin], 1);
+    assert_eq!(widths[&Script::Inherited], 0);
+    // Kana and private use characters
+    assert_eq!(width_by_script("\u{30AB}")[&Script::Katakana], 2);
+    assert_eq!(width_by_script("\u{E000}")[&Script::Unknown], 1);
+}
+
+#[test]
+fn test_width_with_mark_limit() {
+    use super::{width_with_mark_limit, UnicodeWidthStr};
+
+    let five = "e\u{301}\u{302}\u{303}\u{304}\u{305}";
+    assert_eq!(five.width(), 1);
+    assert_eq!(width_with_mark_limit(five, 3), 3);
+    assert_eq!(width_with_mark_limit(five, 5), 1);
+    assert_eq!(width_with_mark_limit(five, 0), 6);
+    // The count starts over at each base character
+    let two = "a\u{301}\u{302}b\u{303}\u{304}";
+    assert_eq!(width_with_mark_limit(two, 2), two.width());
+    assert_eq!(width_with_mark_limit(two, 1), two.width() + 2);
+    assert_eq!(width_with_mark_limit("日本語", 0), 6);
+}
+
+#[cfg(feature = "segmentation")]
+#[test]
+fn test_grapheme_cells() {
+    use super::{width_graphemes, UnicodeWidthStr};
+
+    let s = "a\u{FF22}\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    let cells: Vec<_> = s.grapheme_cells().collect();
+    assert_eq!(cells, [(0..1, 1), (1..4, 2), (4..s.len(), 2)]);
+    assert_eq!(cells.iter().map(|c| c.1).collect::<Vec<_>>(), [1, 2, 2]);
+    assert_eq!(width_graphemes(s, false), 5);
+    // Zero-width clusters are yielded too
+    assert_eq!("\u{301}a".grapheme_cells().collect::<Vec<_>>(), [(0..2, 0), (2..3, 1)]);
+    assert_eq!("".grapheme_cells().next(), None);
+}
+
+#[test]
+fn test_width_with_replacement() {
+    use super::{width_with_replacement, UnicodeWidthStr};
+
+    let s = "the quick fox";
+    assert_eq!(width_with_replacement(s, 4..9, "日本語"), s.width() - 5 + 6);
+    assert_eq!(width_with_replacement(s, 4..9, "日本語"), "the 日本語 fox".width());
+    // Empty ranges insert, and empty replacements delete
+    assert_eq!(width_with_replacement(s, 0..0, "ＡＢ"), s.width() + 4);
+    assert_eq!(width_with_replacement(s, 3..13, ""), 3);
+    assert_eq!(width_with_replacement("日本", 3..6, "a"), 3);
+}
+
+#[test]
+#[should_panic(expected = "char boundaries")]
+fn test_width_with_replacement_mid_char() {
+    use super::width_with_replacement;
+
+    width_with_replacement("日本", 1..3, "a");
+}
+
+#[test]
+fn test_width_complex() {
+    use super::{UnicodeWidthChar, UnicodeWidthStr};
+
+    // U+093F DEVANAGARI VOWEL SIGN I adds a column after U+0915 KA, and none after anything else
+    let rule = |base, mark: char| match (base, mark) {
+        ('\u{915}', '\u{93F}') => 1,
+        (_, '\u{93F}') => 0,
+        _ => mark.width().unwrap_or(0) as u8,
+    };
+    assert_eq!("\u{915}\u{93F}".width_complex(&rule), 2);
+    assert_eq!("\u{916}\u{93F}".width_complex(&rule), 1);
+    // The base is the last character which isn't a mark, even across other marks
+    assert_eq!("\u{915}\u{301}\u{93F}".width_complex(&rule), 2);
+    // A rule which returns each mark's own width matches `width`
+    let passthrough = |_, mark: char| mark.width().unwrap_or(0) as u8;
+    for &s in &["", "hello", "e\u{301}", "\u{915}\u{93F}\u{916}", "\u{301}日本"] {
+        assert_eq!(s.width_complex(&passthrough), s.width(), "{:?}", s);
+    }
+    // Marks without a base aren't passed to the rule
+    let never = |_, _| -> u8 { unreachable!() };
+    assert_eq!("\u{301}".width_complex(&never), 0);
+}
+
+#[test]
+fn test_astral_kana() {
+    use super::{EastAsianWidth, UnicodeWidthChar, UnicodeWidthStr};
+
+    // The assigned characters of Kana Extended-B, Kana Supplement, Kana Extended-A and Small
+    // Kana Extension, all of which are Wide
+    let assigned = [
+        '\u{1AFF0}'..='\u{1AFF3}',
+        '\u{1AFF5}'..='\u{1AFFB}',
+        '\u{1AFFD}'..='\u{1AFFE}',
+        '\u{1B000}'..='\u{1B122}',
+        '\u{1B150}'..='\u{1B152}',
+        '\u{1B164}'..='\u{1B167}',
+    ];
+    for c in assigned.iter().cloned().flatten() {
+        assert_eq!(c.east_asian_width(), EastAsianWidth::Wide, "{:?}", c);
+        assert_eq!(c.width(), Some(2), "{:?}", c);
+        assert_eq!(c.width_cjk(), Some(2), "{:?}", c);
+    }
+    // Unlike the CJK ideograph planes, unassigned codepoints in these blocks aren't Wide
+    assert_eq!('\u{1B123}'.width(), Some(1));
+    assert_eq!('\u{1B130}'.width(), Some(1));
+    // HIRAGANA LETTER SMALL WI, KATAKANA LETTER SMALL N
+    assert_eq!("\u{1B150}\u{1B167}".width(), 4);
+}
+
+#[cfg(feature = "segmentation")]
+#[test]
+fn test_astral_kana_graphemes() {
+    use super::{cluster_width, width_graphemes};
+
+    // Small kana followed by COMBINING KATAKANA-HIRAGANA VOICED SOUND MARK
+    assert_eq!(cluster_width("\u{1B150}\u{3099}", false), 2);
+    assert_eq!(cluster_width("\u{1B164}\u{309A}", true), 2);
+    assert_eq!(width_graphemes("\u{1B000}\u{3099}\u{1B167}\u{1AFF0}", false), 6);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_truncate_with_marker() {
+    use super::{ellipsize, truncate_with_marker};
+    use std::borrow::Cow;
+
+    let s = "日本語のテキスト";
+    let fits = truncate_with_marker(s, 16, " [more]");
+    assert!(matches!(fits, Cow::Borrowed(b) if core::ptr::eq(b, s)));
+    assert!(matches!(truncate_with_marker("", 0, "…"), Cow::Borrowed("")));
+    let truncated = truncate_with_marker(s, 12, " [more]");
+    assert!(matches!(truncated, Cow::Owned(ref o) if o == "日本 [more]"));
+    assert!(matches!(truncate_with_marker("hello", 3, "..."), Cow::Owned(ref o) if o == "..."));
+    // Matches `ellipsize` whenever it truncates
+    for max in 0..18 {
+        assert_eq!(truncate_with_marker(s, max, "…"), ellipsize(s, max, "…"), "{}", max);
+    }
+}
+
+#[test]
+fn test_wrap_waste() {
+    use super::{grid_cells, wrap_waste, UnicodeWidthStr};
+
+    // Alternating narrow and wide characters at an odd width: "a日" fills a row of 3 exactly,
+    // while each row of 5 ends up one cell short
+    let s = "a日b本c語d字";
+    assert_eq!(wrap_waste(s, 3), 0);
+    assert_eq!(wrap_waste(s, 5), 1);
+    assert_eq!(grid_cells(s, 5), 15);
+    // Only wide characters, so every row of an odd width wastes a cell
+    let wide = "日本語のテキスト";
+    assert_eq!(wrap_waste(wide, 3), 7);
+    assert_eq!(wrap_waste(wide, 5), 3);
+    assert_eq!(wrap_waste(wide, 2), 0);
+    // Apart from the end of the last row, the waste is every cell without a character in it
+    for cols in 2..20 {
+        let unused_tail = grid_cells(wide, cols) - wide.width() - wrap_waste(wide, cols);
+        assert!(unused_tail < cols);
+    }
+    // Narrow text never wastes cells, and neither does the end of the last row
+    assert_eq!(wrap_waste("hello world", 4), 0);
+    assert_eq!(wrap_waste("", 4), 0);
+    assert_eq!(wrap_waste("日", 7), 0);
+}
+
+#[test]
+fn test_push_cell() {
+    use super::{push_cell, push_cell_padded, UnicodeWidthStr};
+    use std::string::String;
+
+    // A three-column row with cells starting at columns 0, 8 and 16
+    let mut row = String::new();
+    let mut column = 0;
+    for &(cell, target) in &[("名前", 8), ("Ｂｏｂ", 16), ("x", 20)] {
+        row.push_str(cell);
+        let padding = push_cell_padded(&mut column, cell, target);
+        row.push_str(&" ".repeat(padding));
+        assert_eq!(column, target);
+        assert_eq!(row.width(), column);
+    }
+    assert_eq!(row, "名前    Ｂｏｂ  x   ");
+
+    // A cell which overflows its target pushes the rest of the row along
+    let mut column = 0;
+    assert_eq!(push_cell_padded(&mut column, "日本語", 4), 0);
+    assert_eq!(column, 6);
+    push_cell(&mut column, "ab");
+    assert_eq!(column, 8);
+    push_cell(&mut column, "");
+    assert_eq!(column, 8);
+}
+
+#[cfg(feature = "segmentation")]
+#[test]
+fn test_emoji_tag_sequences() {
+    use super::{cluster_width, width_graphemes, UnicodeWidthStr};
+
+    // WAVING BLACK FLAG followed by the tags for "gbeng", "gbsct" and "gbwls", then CANCEL TAG
+    let england = "\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}";
+    let scotland = "\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}";
+    let wales = "\u{1F3F4}\u{E0067}\u{E0062}\u{E0077}\u{E006C}\u{E0073}\u{E007F}";
+    for &flag in &[england, scotland, wales] {
+        assert_eq!(cluster_width(flag, false), 2, "{:?}", flag);
+        assert_eq!(width_graphemes(flag, true), 2, "{:?}", flag);
+        assert_eq!(flag.grapheme_cells().count(), 1);
+    }
+    assert_eq!(width_graphemes(&format!("a{}{}b", wales, scotland), false), 6);
+
+    // A tag sequence makes a text-presentation emoji (CLOUD) wide, with or without VS16
+    assert_eq!(cluster_width("\u{2601}\u{E0067}\u{E007F}", false), 2);
+    assert_eq!(cluster_width("\u{2601}\u{FE0F}\u{E0067}\u{E007F}", false), 2);
+    // Incomplete or invalid sequences fall back to summing their characters
+    assert_eq!(cluster_width("\u{2601}\u{E0067}", false), 1);
+    assert_eq!(cluster_width("\u{2601}\u{E007F}", false), 1);
+    assert_eq!(cluster_width("a\u{E0067}\u{E007F}", false), 1);
+    assert_eq!(cluster_width("\u{1F3F4}\u{E0067}\u{E0062}", false), 2);
+}
+
+#[test]
+fn test_last_ink_column() {
+    use super::UnicodeWidthStr;
+
+    assert_eq!("ab  ".last_ink_column(), Some(2));
+    assert_eq!("ab".last_ink_column(), Some(2));
+    assert_eq!("  ab\t\n".last_ink_column(), Some(4));
+    assert_eq!("".last_ink_column(), None);
+    assert_eq!("   ".last_ink_column(), None);
+    // IDEOGRAPHIC SPACE is whitespace, even though it's 2 columns wide
+    assert_eq!("\u{3000}\u{3000}".last_ink_column(), None);
+    assert_eq!("日本語\u{3000}".last_ink_column(), Some(6));
+    // Ambiguous characters, like GREEK SMALL LETTER ALPHA, are wide in the CJK variant
+    assert_eq!("\u{3B1}\u{3B2} ".last_ink_column(), Some(2));
+    assert_eq!("\u{3B1}\u{3B2} ".last_ink_column_cjk(), Some(4));
+    assert_eq!(" ".last_ink_column_cjk(), None);
+    // Zero-width characters still count as content
+    assert_eq!("a\u{200B} ".last_ink_column(), Some(1));
+}
+
+#[test]
+fn test_width_with_trailing_marks() {
+    use super::UnicodeWidthStr;
+
+    let is_trailing = |c| c == '\u{302}' || c == '\u{20DD}';
+    // Matching marks get a cell of their own, other marks stay zero-width
+    assert_eq!("e\u{302}".width_with_trailing_marks(&is_trailing), 2);
+    assert_eq!("e\u{301}".width_with_trailing_marks(&is_trailing), 1);
+    assert_eq!("e\u{301}\u{302}\u{303}".width_with_trailing_marks(&is_trailing), 2);
+    assert_eq!("a\u{20DD}b\u{302}".width_with_trailing_marks(&is_trailing), 4);
+    // Spacing marks are already a column wide
+    assert_eq!("\u{915}\u{93F}".width_with_trailing_marks(&is_trailing), 2);
+    // The predicate is only consulted for combining marks
+    let all = |_| true;
+    assert_eq!("日本 ab".width_with_trailing_marks(&all), "日本 ab".width());
+    assert_eq!("e\u{301}\u{302}".width_with_trailing_marks(&all), 3);
+    let none = |_| false;
+    assert_eq!("e\u{301}\u{302}".width_with_trailing_marks(&none), 1);
+}
+
+#[test]
+fn test_ambiguous_ranges() {
+    use super::{EastAsianWidth, UnicodeWidthChar, AMBIGUOUS_RANGES};
+
+    for range in AMBIGUOUS_RANGES {
+        assert!(range.start() <= range.end());
+        for c in range.clone() {
+            assert_eq!(c.east_asian_width(), EastAsianWidth::Ambiguous, "{:?}", c);
+            if !cfg!(feature = "cjk-punctuation-wide") {
+                assert_eq!(c.width(), Some(1), "{:?}", c);
+            }
+            assert_eq!(c.width_cjk(), Some(2), "{:?}", c);
+        }
+    }
+    // The ranges are sorted and don't touch, and include every character with a CJK width
+    for pair in AMBIGUOUS_RANGES.windows(2) {
+        assert!(*pair[0].end() as u32 + 1 < *pair[1].start() as u32);
+    }
+    let listed = |c: char| AMBIGUOUS_RANGES.iter().any(|r| r.contains(&c));
+    #[cfg(not(feature = "cjk-punctuation-wide"))]
+    for c in (0..0x30000).filter_map(::core::char::from_u32) {
+        assert_eq!(listed(c), c.width() != c.width_cjk(), "{:?}", c);
+    }
+    // Ambiguous combining marks are zero-width either way, so they aren't listed
+    assert_eq!('\u{300}'.east_asian_width(), EastAsianWidth::Ambiguous);
+    assert!(!listed('\u{300}'));
+    assert!(listed('\u{3B1}'));
+    assert!(!listed('a') && !listed('\u{4E00}'));
+}
+
+#[test]
+fn test_width_with_tabs_from() {
+    use super::{UnicodeWidthStr, WidthCalculator};
+
+    assert_eq!("\t".width_with_tabs_from(4, 0), 4);
+    assert_eq!("\t".width_with_tabs_from(4, 2), 2);
+    assert_eq!("\t".width_with_tabs_from(4, 4), 4);
+    assert_eq!("a\tb".width_with_tabs_from(8, 5), 4);
+    // Wide characters before the tab count toward the column
+    assert_eq!("日\t".width_with_tabs_from(4, 1), 3);
+    // After a line break, columns count from 0 rather than from the start column
+    assert_eq!("\t\n\t".width_with_tabs_from(4, 2), 6);
+    assert_eq!("ab\r\t".width_with_tabs_from(4, 3), 6);
+    // Without tab stops, the start column doesn't matter
+    assert_eq!("a\tb".width_with_tabs_from(0, 3), "a\tb".width());
+    let calc = WidthCalculator::new().tab_width(4);
+    assert_eq!(calc.measure_from("x\ty", 0), calc.measure("x\ty"));
+}
+
+#[test]
+fn test_max_char_width() {
+    use super::{ControlPolicy, UnicodeWidthChar, UnicodeWidthStr, WidthCalculator, WidthOptions};
+
+    // The default doesn't clamp anything
+    assert_eq!("日本\u{a1}".width_with(WidthOptions::new()), 5);
+    // A forced-wide emoji is capped like any other wide character
+    let forced = WidthOptions::new().force_emoji_wide(true);
+    assert_eq!('\u{2600}'.width_with(forced), Some(2));
+    assert_eq!('\u{2600}'.width_with(forced.max_char_width(1)), Some(1));
+    assert_eq!("日本".width_with(forced.max_char_width(1)), 2);
+    // It bounds the other options too
+    let controls = WidthOptions::new().control(ControlPolicy::Fixed(4));
+    assert_eq!("\x01".width_with(controls), 4);
+    assert_eq!("\x01".width_with(controls.max_char_width(2)), 2);
+    let visible = WidthOptions::new().default_ignorable_width(3).max_char_width(2);
+    assert_eq!("\u{200B}".width_with(visible), 2);
+    // Ignored control characters stay ignored, and narrower characters are unaffected
+    assert_eq!('\x01'.width_with(WidthOptions::new().max_char_width(1)), None);
+    assert_eq!("e\u{301}".width_with(WidthOptions::new().max_char_width(1)), 1);
+    assert_eq!("a".width_with(WidthOptions::new().max_char_width(0)), 0);
+
+    // The calculator caps emoji presentation, but not tabs
+    let calc = WidthCalculator::new().tab_width(4).max_char_width(1);
+    assert_eq!(calc.measure("\u{2600}\u{FE0F}"), 1);
+    assert_eq!(calc.measure("\t\u{4E00}"), 5);
+}
+
+#[test]
+fn test_wide_char_columns() {
+    use super::UnicodeWidthStr;
+
+    let columns: Vec<_> = "a\u{FF22}c\u{FF24}".wide_char_columns().collect();
+    assert_eq!(columns, [1, 4]);
+    assert_eq!("日本語".wide_char_columns().collect::<Vec<_>>(), [0, 2, 4]);
+    assert_eq!("hello".wide_char_columns().next(), None);
+    assert_eq!("".wide_char_columns().next(), None);
+    // Zero-width characters don't move the later columns
+    assert_eq!("e\u{301}\u{200B}日".wide_char_columns().collect::<Vec<_>>(), [1]);
+    // Ambiguous characters are only wide in the CJK variant
+    let s = "\u{3B1}日\u{3B2}";
+    assert_eq!(s.wide_char_columns().collect::<Vec<_>>(), [1]);
+    assert_eq!(s.wide_char_columns_cjk().collect::<Vec<_>>(), [0, 2, 4]);
+}
+
+#[test]
+fn test_wrapped_line_width() {
+    use super::{wrapped_line_width, UnicodeWidthStr};
+
+    // "extra-ordinary" with soft hyphens at each syllable, broken after "extra"
+    let lines = ["ex\u{AD}tra\u{AD}", "or\u{AD}di\u{AD}nary"];
+    assert_eq!(wrapped_line_width(lines[0]), 6);
+    assert_eq!(wrapped_line_width(lines[1]), 8);
+    // The same text broken at zero width spaces instead
+    let lines = ["extra\u{200B}", "ordinary"];
+    assert_eq!(wrapped_line_width(lines[0]), 5);
+    assert_eq!(wrapped_line_width(lines[1]), 8);
+    // `width` doesn't know where lines break, so it counts every soft hyphen
+    assert_eq!("ex\u{AD}tra\u{AD}".width(), 7);
+    assert_eq!(wrapped_line_width(""), 0);
+    assert_eq!(wrapped_line_width("\u{AD}"), 1);
+    assert_eq!(wrapped_line_width("\u{AD}\u{AD}"), 1);
+    assert_eq!(wrapped_line_width("日本\u{AD}"), 5);
+    // Only a soft hyphen at the very end is drawn
+    assert_eq!(wrapped_line_width("a\u{AD}\u{200B}"), 1);
+}
+
+#[test]
+fn test_aligned() {
+    use super::{Aligned, UnicodeWidthStr};
+
+    assert_eq!(format!("{:>6}", Aligned("Ｈｉ")), "  Ｈｉ");
+    assert_eq!(format!("{:>6}", Aligned("Ｈｉ")).width(), 6);
+    // Unlike plain strings, which are padded by `char`s
+    assert_eq!(format!("{:>6}", "Ｈｉ").width(), 8);
+    assert_eq!(format!("{:6}|", Aligned("日本")), "日本  |");
+    assert_eq!(format!("{:^7}|", Aligned("日本")), " 日本  |");
+    assert_eq!(format!("{:-<5}", Aligned("e\u{301}")), "e\u{301}----");
+    // A field narrower than the string doesn't truncate it
+    assert_eq!(format!("{:2}", Aligned("日本語")), "日本語");
+    assert_eq!(format!("{}", Aligned("日本語")), "日本語");
+    // The precision truncates to columns, dropping a straddling wide character
+    assert_eq!(format!("{:.4}", Aligned("日本語")), "日本");
+    assert_eq!(format!("{:.3}", Aligned("日本語")), "日");
+    assert_eq!(format!("{:>4.3}", Aligned("日本語")), "  日");
+    assert_eq!(format!("{:.0}", Aligned("日本語")), "");
+}
+
+#[cfg(feature = "normalization")]
+#[test]
+fn test_composed_width() {
+    use super::{composed_width, UnicodeWidthStr};
+
+    // Decomposed sequences whose NFC form is as wide as they are
+    for &(decomposed, composed) in &[
+        ("e\u{301}", "\u{E9}"),
+        ("A\u{30A}\u{301}", "\u{1FA}"),
+        ("\u{304B}\u{3099}", "\u{304C}"),
+        ("\u{1100}\u{1161}\u{11A8}", "\u{AC01}"),
+    ] {
+        assert_eq!(composed_width(decomposed), composed.width(), "{:?}", decomposed);
+        assert_eq!(composed_width(decomposed), decomposed.width(), "{:?}", decomposed);
+    }
+    // Two-part vowel signs are narrower when composed
+    assert_eq!("\u{995}\u{9C7}\u{9BE}".width(), 3);
+    assert_eq!(composed_width("\u{995}\u{9C7}\u{9BE}"), 2);
+    assert_eq!(composed_width("\u{995}\u{9CB}"), 2);
+    // Already-composed text is unaffected
+    assert_eq!(composed_width("日本語 text"), "日本語 text".width());
+    assert_eq!(composed_width(""), 0);
+}
+
+#[test]
+fn test_historic_scripts() {
+    use super::{EastAsianWidth, UnicodeWidthChar, UnicodeWidthStr};
+
+    // Ogham, Runic, and Old Italic are all Neutral, so every character is 1 column wide
+    let blocks = [
+        '\u{1680}'..='\u{169C}',
+        '\u{16A0}'..='\u{16F8}',
+        '\u{10300}'..='\u{10323}',
+        '\u{1032D}'..='\u{1032F}',
+    ];
+    for c in blocks.iter().cloned().flatten() {
+        assert_eq!(c.east_asian_width(), EastAsianWidth::Neutral, "{:?}", c);
+        assert_eq!(c.width(), Some(1), "{:?}", c);
+        assert_eq!(c.width_cjk(), Some(1), "{:?}", c);
+    }
+
+    // OGHAM SPACE MARK is a space separator, but unlike most spaces it's drawn as a visible
+    // stem line, so it isn't zero-width
+    assert!('\u{1680}'.is_whitespace());
+    assert_eq!('\u{1680}'.width(), Some(1));
+    // OGHAM FEATHER MARK, BEITH, SPACE MARK, LUIS, REVERSED FEATHER MARK
+    assert_eq!("\u{169B}\u{1681}\u{1680}\u{1682}\u{169C}".width(), 5);
+    // Being whitespace, it's still trimmed like any other space
+    assert_eq!("\u{1681}\u{1680}".trimmed_width(), 1);
+
+    // RUNIC LETTER FEHU FEOH FE F, RUNIC SINGLE PUNCTUATION, RUNIC LETTER ANSUZ A
+    assert_eq!("\u{16A0}\u{16EB}\u{16A8}".width(), 3);
+    // OLD ITALIC LETTER A, OLD ITALIC NUMERAL ONE
+    assert_eq!("\u{10300}\u{10320}".width(), 2);
+}
+
+#[test]
+fn test_width_and_scripts() {
+    use super::{width_and_scripts, Script, ScriptSet, UnicodeWidthStr};
+
+    let s = "Hello \u{645}\u{631}\u{62D}\u{628}\u{627}";
+    let (width, scripts) = width_and_scripts(s);
+    assert_eq!(width, s.width());
+    assert!(scripts.contains(Script::Latin));
+    assert!(scripts.contains(Script::Arabic));
+    assert!(scripts.contains(Script::Common));
+    assert!(!scripts.contains(Script::Han));
+    assert_eq!(scripts.iter().collect::<Vec<_>>(), [Script::Arabic, Script::Common, Script::Latin]);
+
+    let (width, scripts) = width_and_scripts("");
+    assert_eq!((width, scripts), (0, ScriptSet::new()));
+    assert!(scripts.is_empty());
+    let (width, scripts) = width_and_scripts("\u{E000}\u{301}日本");
+    assert_eq!(width, 5);
+    assert_eq!(scripts.len(), 3);
+    assert!(scripts.contains(Script::Unknown) && scripts.contains(Script::Inherited));
+
+    // Every script fits in the set
+    let mut all = ScriptSet::new();
+    for &script in Script::ALL.iter() {
+        assert!(all.insert(script));
+    }
+    assert_eq!(all.len(), Script::ALL.len());
+    assert!(all.iter().eq(Script::ALL.iter().cloned()));
+}
+
+#[cfg(feature = "cjk-punctuation-wide")]
+#[test]
+fn test_cjk_punctuation_wide() {
+    use super::{UnicodeWidthChar, UnicodeWidthStr};
+
+    for &c in &['\u{2014}', '\u{2018}', '\u{201D}', '\u{2026}', '\u{2033}', '\u{203B}'] {
+        assert_eq!(c.width(), Some(2));
+        assert_eq!(c.width_cjk(), Some(2));
+    }
+    assert_eq!("\u{201C}引用\u{201D}\u{2026}".width(), 10);
+    assert_eq!("a\u{2026}\u{4E00}\x1b".width_range(), (5, 5));
+
+    // Other ambiguous characters are unaffected
+    for &c in &['\u{A1}', '\u{B7}', '\u{2010}', '\u{2020}', '\u{2460}', '\u{3B1}'] {
+        assert_eq!(c.width(), Some(1));
+        assert_eq!(c.width_cjk(), Some(2));
+    }
+}
+
+#[cfg(feature = "capi")]
+#[test]
+fn test_cstr_width() {
+    use super::{cstr_width, UnicodeWidthStr};
+    use core::ffi::CStr;
+
+    let s = CStr::from_bytes_with_nul("日本語のテキスト\0".as_bytes()).unwrap();
+    assert_eq!(cstr_width(s), 16);
+    let s = CStr::from_bytes_with_nul(b"\0").unwrap();
+    assert_eq!(cstr_width(s), 0);
+    // Measurement stops at the first nul
+    let s = CStr::from_bytes_until_nul("ab\0ｃｄ\0".as_bytes()).unwrap();
+    assert_eq!(cstr_width(s), 2);
+    // Each maximal invalid sequence is one U+FFFD, as with `String::from_utf8_lossy`
+    let bytes = b"\xFF\xFEa\xE6\x97\xA5\xE6\x97\0";
+    let s = CStr::from_bytes_with_nul(bytes).unwrap();
+    let lossy = String::from_utf8_lossy(&bytes[..bytes.len() - 1]);
+    assert_eq!(lossy, "\u{FFFD}\u{FFFD}a日\u{FFFD}");
+    assert_eq!(cstr_width(s), 6);
+    // The decoded text is measured as one string, so it agrees with `width` even when sequences
+    // touch invalid bytes
+    for bytes in [
+        &b"\xE2\x98\x80\xEF\xB8\x8F\xFF\0"[..],
+        b"\xE2\x98\x80\xFF\xEF\xB8\x8F\0",
+        b"\xF0\x9F\x87\xBA\xFF\xF0\x9F\x87\xB8\xF0\x9F\x87\xBA\0",
+        b"\xF0\x9F\x87\xBA\xF0\x9F\x87\xB8\xF0\x9F\x87\0",
+        b"a\xCC\x81\xE0\x80\xCC\x81\0",
+    ] {
+        let s = CStr::from_bytes_with_nul(bytes).unwrap();
+        let lossy = String::from_utf8_lossy(&bytes[..bytes.len() - 1]);
+        assert_eq!(cstr_width(s), lossy.width(), "{:?}", lossy);
+    }
+    let s = CStr::from_bytes_with_nul(b"\xE2\x98\x80\xEF\xB8\x8F\xFF\0").unwrap();
+    assert_eq!(cstr_width(s), 3);
+}
+
+#[test]
+fn test_collapsed_width() {
+    use super::UnicodeWidthStr;
+
+    assert_eq!("a   b".collapsed_width(), 3);
+    assert_eq!("".collapsed_width(), 0);
+    assert_eq!("ab".collapsed_width(), 2);
+    // Leading and trailing runs count as one column
+    assert_eq!("   ".collapsed_width(), 1);
+    assert_eq!("  a  ".collapsed_width(), 3);
+    assert_eq!("  a  ".trim().collapsed_width(), 1);
+    // Mixed ASCII whitespace forms a single run
+    assert_eq!("a \t\r\n\x0C b".collapsed_width(), 3);
+    // Other whitespace isn't collapsed, and breaks up runs
+    assert_eq!("a \u{3000} b".collapsed_width(), 6);
+    assert_eq!("日本  語".collapsed_width(), 7);
+
+    assert_eq!("\u{3B1}  \u{3B2}".collapsed_width(), 3);
+    assert_eq!("\u{3B1}  \u{3B2}".collapsed_width_cjk(), 5);
+}
+
+#[test]
+fn test_width_clusters() {
+    use super::UnicodeWidthStr;
+
+    // Family: MAN, ZWJ, WOMAN, ZWJ, GIRL, ZWJ, BOY
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}";
+    assert_eq!(family.width(), 8);
+    assert_eq!(family.width_clusters(), 2);
+    assert_eq!(format!("a{}b", family).width_clusters(), 4);
+    assert_eq!(family.repeat(2).width_clusters(), 4);
+    // Professions: WOMAN, ZWJ, ROCKET and WOMAN, skin tone, ZWJ, ROCKET
+    assert_eq!("\u{1F469}\u{200D}\u{1F680}".width_clusters(), 2);
+    assert_eq!("\u{1F469}\u{1F3FD}\u{200D}\u{1F680}".width_clusters(), 2);
+    // HEART (narrow without VS16), VS16, ZWJ, FIRE is as wide as its widest emoji
+    assert_eq!("\u{2764}\u{FE0F}\u{200D}\u{1F525}".width_clusters(), 2);
+
+    // Dangling joiners join nothing
+    assert_eq!("\u{1F469}\u{200D}".width_clusters(), 2);
+    assert_eq!("\u{200D}\u{1F469}".width_clusters(), 2);
+    assert_eq!("\u{200D}".width_clusters(), 0);
+    assert_eq!("\u{1F469}\u{200D}a\u{1F680}".width_clusters(), 5);
+    assert_eq!("a\u{200D}\u{1F469}".width_clusters(), 3);
+    // Adjacent emoji without a joiner are separate glyphs
+    assert_eq!("\u{1F469}\u{1F680}".width_clusters(), 4);
+
+    for &s in &["", "hello", "日本語", "e\u{301}", "\u{3B1}\u{3B2}"] {
+        assert_eq!(s.width_clusters(), s.width());
+        assert_eq!(s.width_clusters_cjk(), s.width_cjk());
+    }
+}
+
+#[test]
+fn test_cell_kinds() {
+    use super::{CellKind, UnicodeWidthStr};
+
+    let cells: Vec<_> = "a\u{FF22}".cell_kinds().collect();
+    assert_eq!(cells, [CellKind::Narrow('a'), CellKind::WideLeft('\u{FF22}'), CellKind::WideRight]);
+    assert_eq!("".cell_kinds().count(), 0);
+
+    let cells: Vec<_> = "e\u{301}\u{1F600}\x01".cell_kinds().collect();
+    assert_eq!(
+        cells,
+        [
+            CellKind::Narrow('e'),
+            CellKind::ZeroWidth('\u{301}'),
+            CellKind::WideLeft('\u{1F600}'),
+            CellKind::WideRight,
+            CellKind::ZeroWidth('\x01'),
+        ]
+    );
+
+    // Ambiguous characters are only split in CJK contexts
+    let cells: Vec<_> = "\u{3B1}".cell_kinds().collect();
+    assert_eq!(cells, [CellKind::Narrow('\u{3B1}')]);
+    let cells: Vec<_> = "\u{3B1}".cell_kinds_cjk().collect();
+    assert_eq!(cells, [CellKind::WideLeft('\u{3B1}'), CellKind::WideRight]);
+
+    for &s in &["hello", "日本語", "a\u{200D}b\u{FE0F}", "\u{1F469}\u{200D}\u{1F680}"] {
+        let columns = s.cell_kinds().filter(|k| !matches!(k, CellKind::ZeroWidth(_))).count();
+        assert_eq!(columns, s.width());
+        let columns = s.cell_kinds_cjk().filter(|k| !matches!(k, CellKind::ZeroWidth(_))).count();
+        assert_eq!(columns, s.width_cjk());
+    }
+}
+
+#[test]
+fn test_variation_selectors() {
+    use super::{UnicodeWidthStr, WidthCalculator};
+
+    // VS16 widens a text-presentation emoji, such as the keycap base '#'
+    assert_eq!("\u{2600}".width(), 1);
+    assert_eq!("\u{2600}\u{FE0F}".width(), 2);
+    assert_eq!("#\u{FE0F}\u{20E3}".width(), 2);
+    assert_eq!("#\u{20E3}".width(), 1);
+    // ...but doesn't double-count an emoji which is already wide
+    assert_eq!("\u{1F600}\u{FE0F}".width(), 2);
+    // ...or affect characters which aren't emoji
+    assert_eq!("a\u{FE0F}".width(), 1);
+    assert_eq!("\u{FF21}\u{FE0F}".width(), 2);
+    // A selector with no base is zero-width
+    assert_eq!("\u{FE0F}".width(), 0);
+    assert_eq!("\u{FE0F}\u{FE0F}".width(), 0);
+    assert_eq!("\u{2600}\u{FE0F}\u{FE0F}".width(), 2);
+
+    // VS15 narrows an emoji-presentation emoji
+    assert_eq!("\u{231A}\u{FE0E}".width(), 1);
+    assert_eq!("\u{231A}\u{FE0E}".width_cjk(), 1);
+    assert_eq!("\u{2600}\u{FE0E}".width(), 1);
+    assert_eq!("\u{FE0E}".width(), 0);
+    // ...but an Ambiguous emoji stays wide in CJK contexts
+    assert_eq!("\u{2660}\u{FE0E}".width(), 1);
+    assert_eq!("\u{2660}\u{FE0E}".width_cjk(), 2);
+    assert_eq!("\u{2660}\u{FE0F}".width(), 2);
+    assert_eq!("\u{2660}\u{FE0F}".width_cjk(), 2);
+
+    let calc = WidthCalculator::new();
+    let strings = ["x\u{2600}\u{FE0F}y", "\u{231A}\u{FE0E}\u{231A}", "\u{FE0F}#\u{FE0F}", "\u{2660}\u{FE0E}"];
+    for &s in &strings {
+        assert_eq!(s.len_and_width(), (s.chars().count(), s.width()));
+        assert_eq!(s.len_and_width_cjk(), (s.chars().count(), s.width_cjk()));
+        assert_eq!(s.width_range(), (s.width(), s.width_cjk()));
+        assert_eq!(calc.measure(s), s.width());
+        assert_eq!(calc.cjk(true).measure(s), s.width_cjk());
+    }
+}
+
+#[test]
+fn test_nel_line_break() {
+    use super::WidthCalculator;
+
+    // By default, NEL is a zero-width control character which doesn't end a line
+    let calc = WidthCalculator::new();
+    assert_eq!(calc.lines_widths("ab\u{85}cd").collect::<Vec<_>>(), [4]);
+    assert_eq!(calc.tab_width(4).measure("ab\u{85}\tc"), 5);
+
+    let nel = calc.nel_line_break(true);
+    assert_eq!(nel.lines_widths("ab\u{85}cd").collect::<Vec<_>>(), [2, 2]);
+    assert_eq!(nel.lines_widths("ab\u{85}").collect::<Vec<_>>(), [2]);
+    assert_eq!(nel.lines_widths("\u{85}\u{85}ｘ").collect::<Vec<_>>(), [0, 0, 2]);
+    assert_eq!(nel.tab_width(4).measure("ab\u{85}\tc"), 7);
+    // NEL is still measured as a control character
+    assert_eq!(nel.measure("ab\u{85}cd"), 4);
+
+    // The other terminators split lines either way
+    let s = "a\nbb\r\nccc\u{2028}dddd\u{2029}\u{FF25}";
+    assert_eq!(calc.lines_widths(s).collect::<Vec<_>>(), [1, 2, 3, 4, 2]);
+    assert_eq!(nel.lines_widths(s).collect::<Vec<_>>(), [1, 2, 3, 4, 2]);
+    assert_eq!(calc.lines_widths("").count(), 0);
+}
+
+#[test]
+fn test_width_of_scalar() {
+    use super::{width_of_scalar, UnicodeWidthChar};
+
+    // Surrogates and values past U+10FFFF aren't scalar values
+    for &cp in &[0xD800, 0xDBFF, 0xDC00, 0xDFFF, 0x110000, 0x7FFF_FFFF, u32::MAX] {
+        assert_eq!(width_of_scalar(cp, false), None);
+        assert_eq!(width_of_scalar(cp, true), None);
+    }
+    // U+10FFFF is a noncharacter, but still a scalar value
+    assert_eq!(width_of_scalar(0x10FFFF, false), '\u{10FFFF}'.width());
+    assert_eq!(width_of_scalar(0x10FFFF, false), Some(1));
+    assert_eq!(width_of_scalar(0, false), Some(0));
+    assert_eq!(width_of_scalar(0x1B, false), None);
+    assert_eq!(width_of_scalar(0x3B1, false), Some(1));
+    assert_eq!(width_of_scalar(0x3B1, true), Some(2));
+
+    for c in (0..0x30000).filter_map(::core::char::from_u32).step_by(13) {
+        assert_eq!(width_of_scalar(c as u32, false), c.width());
+        assert_eq!(width_of_scalar(c as u32, true), c.width_cjk());
+    }
+}
+
+#[test]
+fn test_width_with_matches_width() {
+    use super::{ControlPolicy, UnicodeWidthChar, UnicodeWidthStr, WidthOptions};
+
+    let narrow = WidthOptions::new();
+    let wide = WidthOptions::new().ambiguous_wide(true);
+    for c in (0..0x110000).filter_map(::core::char::from_u32) {
+        assert_eq!(c.width_with(narrow), c.width());
+        assert_eq!(c.width_with(wide), c.width_cjk());
+    }
+    let strings = [
+        "",
+        "hello\n\x1B[0m",
+        "\u{3B1}\u{2026}日本語",
+        "\u{2600}\u{FE0F}\u{231A}\u{FE0E}\u{2660}\u{FE0E}",
+        "e\u{301}\u{200B}\u{FEFF}\u{FFFF}",
+    ];
+    for &s in &strings {
+        assert_eq!(s.width_with(narrow), s.width());
+        assert_eq!(s.width_with(wide), s.width_cjk());
+    }
+
+    // control_width is shorthand for the two control policies
+    assert_eq!(
+        WidthOptions::new().control_width(None),
+        WidthOptions::new().control(ControlPolicy::Ignore)
+    );
+    assert_eq!(
+        WidthOptions::new().control_width(Some(0)),
+        WidthOptions::new().control(ControlPolicy::Fixed(0))
+    );
+    let opts = WidthOptions::new().ambiguous_wide(true).control_width(Some(0));
+    assert_eq!('\x07'.width_with(opts), Some(0));
+    assert_eq!("\x07\u{3B1}".width_with(opts), 2);
+}
+
+#[test]
+fn test_truncate_to_width() {
+    use super::UnicodeWidthStr;
+
+    let s = "日本語abc";
+    assert_eq!(s.truncate_to_width(0), "");
+    assert_eq!(s.truncate_to_width(100), s);
+    assert_eq!(s.truncate_to_width(9), s);
+    assert_eq!(s.truncate_to_width(5), "日本");
+    assert_eq!(s.truncate_to_width(7), "日本語a");
+    // A wide char straddling the limit is excluded
+    assert_eq!("\u{FF21}".truncate_to_width(1), "");
+    assert_eq!("a\u{FF21}".truncate_to_width(2), "a");
+    // Zero-width characters stay with their base
+    assert_eq!("e\u{301}x".truncate_to_width(1), "e\u{301}");
+    assert_eq!("".truncate_to_width(3), "");
+    // The result is a subslice, not a copy
+    assert_eq!(s.truncate_to_width(5).as_ptr(), s.as_ptr());
+
+    assert_eq!("\u{3B1}\u{3B2}".truncate_to_width(1), "\u{3B1}");
+    assert_eq!("\u{3B1}\u{3B2}".truncate_to_width_cjk(1), "");
+    assert_eq!("\u{3B1}\u{3B2}".truncate_to_width_cjk(3), "\u{3B1}");
+
+    // An emoji is measured with its variation selector, and never split from it
+    assert_eq!("a\u{2600}\u{FE0F}".truncate_to_width(2), "a");
+    assert_eq!("a\u{2600}\u{FE0F}".truncate_to_width(3), "a\u{2600}\u{FE0F}");
+    assert_eq!("\u{231A}\u{FE0E}b".truncate_to_width(1), "\u{231A}\u{FE0E}");
+
+    for &s in &["日本語abc", "\u{2600}\u{FE0F}\u{2600}", "\u{231A}\u{FE0E}\u{231A}", "e\u{301}\u{3B1}"] {
+        for max in 0..=s.width_cjk() {
+            assert!(s.truncate_to_width(max).width() <= max);
+            assert!(s.truncate_to_width_cjk(max).width_cjk() <= max);
+        }
+    }
+}
+
+#[test]
+fn test_char_width_indices() {
+    use super::UnicodeWidthStr;
+
+    let s = "ab日本e\u{301}\u{3B1}\x07";
+    let triples: Vec<_> = s.char_width_indices().collect();
+    assert_eq!(
+        triples,
+        [
+            (0, 'a', 1),
+            (1, 'b', 1),
+            (2, '日', 2),
+            (5, '本', 2),
+            (8, 'e', 1),
+            (9, '\u{301}', 0),
+            (11, '\u{3B1}', 1),
+            (13, '\x07', 0),
+        ]
+    );
+    assert_eq!(s.char_width_indices().map(|(_, _, w)| w).sum::<usize>(), s.width());
+    assert_eq!(s.char_width_indices_cjk().map(|(_, _, w)| w).sum::<usize>(), s.width_cjk());
+    for (i, c, _) in s.char_width_indices() {
+        assert_eq!(s[i..].chars().next(), Some(c));
+    }
+
+    // The selector's adjustment is attributed to the emoji
+    let s = "\u{2600}\u{FE0F}\u{231A}\u{FE0E}";
+    let widths: Vec<_> = s.char_width_indices().map(|(_, _, w)| w).collect();
+    assert_eq!(widths, [2, 0, 1, 0]);
+    assert_eq!(widths.iter().sum::<usize>(), s.width());
+    assert_eq!("".char_width_indices().count(), 0);
+}
+
+#[test]
+fn test_width_with_tabs() {
+    use super::UnicodeWidthStr;
+
+    // "a" ends at column 1, the tab advances to 4, and "bc" ends at 6
+    assert_eq!("a\tbc".width_with_tabs(4), 6);
+    assert_eq!("\t".width_with_tabs(4), 4);
+    assert_eq!("abcd\t".width_with_tabs(4), 8);
+    assert_eq!("日\t".width_with_tabs(4), 4);
+    assert_eq!("a\t\tb".width_with_tabs(8), 17);
+    assert_eq!("ab\n\tc".width_with_tabs(4), 7);
+    assert_eq!("a\tbc".width_with_tabs(0), 3);
+    assert_eq!("a\tbc".width_with_tabs(0), "a\tbc".width());
+    assert_eq!("".width_with_tabs(4), 0);
+}
+
+#[test]
+fn test_const_width() {
+    use super::{const_width, UnicodeWidthChar};
+
+    const _: () = assert!(matches!(const_width('\u{FF21}'), Some(2)));
+    const _: () = assert!(matches!(const_width('a'), Some(1)));
+    const _: () = assert!(matches!(const_width('\u{301}'), Some(0)));
+    const _: () = assert!(const_width('\x07').is_none());
+    const WIDTHS: [Option<usize>; 4] = [
+        const_width('\u{A1}'),
+        const_width('\u{2014}'),
+        const_width('\u{1F600}'),
+        const_width('\u{10FFFF}'),
+    ];
+    for (c, &w) in ['\u{A1}', '\u{2014}', '\u{1F600}', '\u{10FFFF}'].iter().zip(&WIDTHS) {
+        assert_eq!(w, c.width(), "{:?}", c);
+    }
+    for c in (0..0x3000).filter_map(core::char::from_u32) {
+        assert_eq!(const_width(c), c.width(), "{:?}", c);
+    }
+}
+
+#[test]
+fn test_ascii_fast_path() {
+    use super::{chars_width, UnicodeWidthStr};
+
+    let corpus = [
+        "",
+        "a",
+        "\x07",
+        "plain ASCII text that is longer than one sixteen byte chunk",
+        "tabs\tand\nnewlines\r\x1B[0m and DEL \x7F and NUL \0 in ASCII",
+        "0123456789abcdefé after exactly one chunk",
+        "0123456789abcde\u{301}",
+        "Ｈｅｌｌｏ, ｗｏｒｌｄ! caf\u{E9}",
+        "ASCII first, then 日本語 and \u{1F600} and \u{0915}\u{094D}\u{0937}",
+        "keycap #\u{FE0F}\u{20E3} and *\u{FE0F}",
+        "0123456789abcde#\u{FE0F}",
+        "watch \u{231A}\u{FE0E} and sun \u{2600}\u{FE0F}",
+        "\u{A0}\u{AD}\u{2014}\u{3000}\x00\x01 end",
+    ];
+    for s in corpus.iter() {
+        // Every suffix starts the non-ASCII part at a different offset
+        for (i, _) in s.char_indices() {
+            let s = &s[i..];
+            assert_eq!(s.width(), chars_width(s, false), "{:?}", s);
+            assert_eq!(s.width_cjk(), chars_width(s, true), "{:?}", s);
+        }
+    }
+    assert_eq!("0123456789abcde#\u{FE0F}".width(), 17);
+    assert_eq!("a\x07b\x7Fc".width(), 3);
+}
+
+#[test]
+fn test_east_asian_width_fn() {
+    use super::{east_asian_width, EastAsianWidth, UnicodeWidthChar};
+
+    assert_eq!(east_asian_width('\u{FF21}'), EastAsianWidth::FullWidth);
+    assert_eq!(east_asian_width('\u{3042}'), EastAsianWidth::Wide);
+    assert_eq!(east_asian_width('\u{D7}'), EastAsianWidth::Ambiguous);
+    assert_eq!(east_asian_width('\u{FF76}'), EastAsianWidth::HalfWidth);
+    assert_eq!(east_asian_width('a'), EastAsianWidth::Narrow);
+    assert_eq!(east_asian_width('\u{5D0}'), EastAsianWidth::Neutral);
+    for c in (0..0x20000).filter_map(core::char::from_u32) {
+        assert_eq!(east_asian_width(c), c.east_asian_width(), "{:?}", c);
+    }
+}
+
+#[test]
+fn test_max_line_width() {
+    use super::UnicodeWidthStr;
+
+    assert_eq!("".max_line_width(), 0);
+    assert_eq!("\n".max_line_width(), 0);
+    assert_eq!("abc".max_line_width(), 3);
+    assert_eq!("abc\n".max_line_width(), 3);
+    assert_eq!("a\nｂｃ\ndef".max_line_width(), 4);
+    assert_eq!("ab\r\nｃｄｅ\r\nf\r\n".max_line_width(), 6);
+    assert_eq!("\n\nabcd".max_line_width(), 4);
+    assert_eq!("\u{231A}\u{FE0E}\nab".max_line_width(), 2);
+    assert_eq!("\u{2600}\u{FE0F}\na".max_line_width(), 2);
+    assert_eq!("\u{3B1}\u{3B2}\nx".max_line_width(), 2);
+    assert_eq!("\u{3B1}\u{3B2}\nx".max_line_width_cjk(), 4);
+
+    let s = "one\r\ntwo three\nｆｏｕｒ\n\n日本語のテキスト\n";
+    let expected = s.lines().map(UnicodeWidthStr::width).max().unwrap_or(0);
+    assert_eq!(s.max_line_width(), expected);
+}
+
+#[test]
+fn test_zero_width_classes() {
+    use super::{cw, UnicodeWidthChar};
+
+    // Pinned so that regenerating the tables can't silently change them
+    for &c in &['\u{301}', '\u{200B}', '\u{FEFF}', '\u{2060}'] {
+        assert_eq!(c.width(), Some(0), "{:?}", c);
+        assert_eq!(c.width_cjk(), Some(0), "{:?}", c);
+    }
+    // Nonspacing and enclosing marks, including ones outside the BMP
+    for &c in &['\u{300}', '\u{36F}', '\u{488}', '\u{20DD}', '\u{FE20}', '\u{1D167}'] {
+        assert_eq!(c.width(), Some(0), "{:?}", c);
+    }
+    // Other default-ignorables: joiners, bidi controls, selectors and tags
+    for &c in &['\u{34F}', '\u{200C}', '\u{200D}', '\u{200E}', '\u{202E}', '\u{2064}'] {
+        assert_eq!(c.width(), Some(0), "{:?}", c);
+    }
+    for &c in &['\u{180E}', '\u{FE00}', '\u{FE0F}', '\u{1BCA0}', '\u{E0001}', '\u{E01EF}'] {
+        assert_eq!(c.width(), Some(0), "{:?}", c);
+    }
+
+    // The documented exceptions
+    assert_eq!('\u{AD}'.width(), Some(1));
+    assert_eq!('\u{115F}'.width(), Some(2));
+    assert_eq!('\u{3164}'.width(), Some(2));
+    assert_eq!('\u{FFA0}'.width(), Some(1));
+    for c in (0..0x110000).filter_map(core::char::from_u32) {
+        if cw::is_default_ignorable(c) && cw::is_assigned(c) {
+            let expected = match c {
+                '\u{AD}' | '\u{FFA0}' => 1,
+                '\u{115F}' | '\u{3164}' => 2,
+                _ => 0,
+            };
+            assert_eq!(c.width(), Some(expected), "{:?}", c);
+        }
+    }
+}
+
+#[test]
+fn test_width_of_chars() {
+    use super::{width_of_chars, UnicodeWidthStr};
+
+    assert_eq!(width_of_chars("\u{FF28}\u{FF45}\u{FF4C}\u{FF4C}\u{FF4F}".chars(), false), 10);
+    for s in &[
+        "",
+        "\u{FF28}\u{FF45}\u{FF4C}\u{FF4C}\u{FF4F}",
+        "ascii \x07 and e\u{301}",
+        "\u{3B1}\u{2014}\u{25CB}",
+        "\u{2600}\u{FE0F} \u{231A}\u{FE0E} #\u{FE0F}\u{20E3}",
+    ] {
+        assert_eq!(width_of_chars(s.chars(), false), s.width(), "{:?}", s);
+        assert_eq!(width_of_chars(s.chars(), true), s.width_cjk(), "{:?}", s);
+    }
+
+    // Any `IntoIterator`, not just `Chars`
+    let chars = vec!['a', '\u{3042}', '\u{301}'];
+    assert_eq!(width_of_chars(chars, false), 3);
+    assert_eq!(width_of_chars(core::iter::repeat_n('\u{FF21}', 4), false), 8);
+}
+
+#[test]
+fn test_regional_indicators() {
+    use super::{width_of_chars, UnicodeWidthStr, WidthCalculator};
+
+    let us = "\u{1F1FA}\u{1F1F8}";
+    let jp = "\u{1F1EF}\u{1F1F5}";
+    assert_eq!(us.width(), 2);
+    assert_eq!(format!("{}{}", us, jp).width(), 4);
+    assert_eq!(format!("{}\u{1F1EF}", us).width(), 4);
+    assert_eq!("\u{1F1EF}".width(), 2);
+    assert_eq!("\u{1F1EF}\u{FE0F}".width(), 2);
+    // Anything else between two indicators ends the first flag
+    assert_eq!("\u{1F1FA}a\u{1F1F8}".width(), 5);
+    assert_eq!(format!("a{}b", us).width_cjk(), 4);
+
+    let strings = [
+        format!("{}{}\u{1F1EF}", us, jp),
+        format!("flags: {} and {}!", us, jp),
+        format!("\u{1F1EF}\u{301}{}", us),
+    ];
+    for s in strings.iter() {
+        let s = s.as_str();
+        let expected = s.width();
+        assert_eq!(width_of_chars(s.chars(), false), expected, "{:?}", s);
+        assert_eq!(s.len_and_width().1, expected, "{:?}", s);
+        assert_eq!(s.width_range(), (expected, s.width_cjk()), "{:?}", s);
+        assert_eq!(s.max_line_width(), expected, "{:?}", s);
+        assert_eq!(s.char_width_indices().map(|(_, _, w)| w).sum::<usize>(), expected);
+        assert_eq!(WidthCalculator::new().measure(s), expected, "{:?}", s);
+        assert_eq!(s.truncate_to_width(expected), s);
+    }
+
+    // A flag is never split
+    assert_eq!(format!("{}{}", us, jp).truncate_to_width(3), us);
+    assert_eq!(us.truncate_to_width(1), "");
+
+    #[cfg(feature = "segmentation")]
+    {
+        use super::width_graphemes;
+
+        for s in strings.iter() {
+            assert_eq!(width_graphemes(s, false), s.width(), "{:?}", s);
+        }
+    }
+}
+
+#[test]
+fn test_control_policy_caret() {
+    use super::{ControlPolicy, UnicodeWidthChar, UnicodeWidthStr, WidthCalculator, WidthOptions};
+
+    let caret = WidthOptions::new().control(ControlPolicy::Caret);
+    assert_eq!("\x03abc".width_with(caret), 5);
+    assert_eq!("\x1B[0m".width_with(caret), 5);
+    // C0, DEL and C1 are all drawn as two characters
+    for &c in &['\x01', '\x07', '\x1F', '\x7F', '\u{80}', '\u{85}', '\u{9F}'] {
+        assert_eq!(c.width_with(caret), Some(2), "{:?}", c);
+    }
+    // '\0' and non-controls are unaffected
+    assert_eq!('\0'.width_with(caret), Some(0));
+    assert_eq!("a\u{3042}\u{301}".width_with(caret), 3);
+    assert_eq!(WidthCalculator::new().control(ControlPolicy::Caret).measure("\x03abc"), 5);
+    // Tab stops take precedence over the policy
+    let calc = WidthCalculator::new().control(ControlPolicy::Caret).tab_width(4);
+    assert_eq!(calc.measure("\t\x03"), 6);
+
+    fn c1_hex(c: char) -> usize {
+        if c < '\u{80}' {
+            2
+        } else {
+            4
+        }
+    }
+    let custom = WidthOptions::new().control(ControlPolicy::Custom(c1_hex));
+    assert_eq!("\x03\u{85}x".width_with(custom), 7);
+    assert_eq!('\u{9B}'.width_with(custom), Some(4));
+    assert_eq!('\0'.width_with(custom), Some(0));
+    // Clamped like the other policies
+    assert_eq!('\u{9B}'.width_with(custom.max_char_width(3)), Some(3));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_wrap_to_width() {
+    use super::UnicodeWidthStr;
+
+    // "日" would overflow the first line by a column, so it starts the next one
+    let s = "abc日本語";
+    let lines = s.wrap_to_width(4);
+    assert_eq!(lines, ["abc", "日本", "語"]);
+    assert_eq!(lines.concat(), s);
+
+    assert_eq!("abcdef".wrap_to_width(3), ["abc", "def"]);
+    assert_eq!("ab\ncdef\n".wrap_to_width(3), ["ab\n", "cde", "f\n"]);
+    assert_eq!("\n\n".wrap_to_width(3), ["\n", "\n"]);
+    assert_eq!("ab\r\ncd".wrap_to_width(2), ["ab\r\n", "cd"]);
+    assert!("".wrap_to_width(3).is_empty());
+    // Zero-width characters stay with the character before them
+    assert_eq!("abe\u{301}f".wrap_to_width(3), ["abe\u{301}", "f"]);
+    assert_eq!("\u{301}ab".wrap_to_width(1), ["\u{301}a", "b"]);
+    // Emoji presentation sequences and flags aren't split
+    assert_eq!("a\u{2600}\u{FE0F}".wrap_to_width(2), ["a", "\u{2600}\u{FE0F}"]);
+    assert_eq!("a\u{1F1FA}\u{1F1F8}".wrap_to_width(2), ["a", "\u{1F1FA}\u{1F1F8}"]);
+    // Characters wider than a line get a line to themselves
+    assert_eq!("a日b".wrap_to_width(1), ["a", "日", "b"]);
+    assert_eq!("ab".wrap_to_width(0), ["a", "b"]);
+
+    let s = "The quick 茶色の狐 jumps\nover the lazy dog";
+    for max in 1..12 {
+        let lines = s.wrap_to_width(max);
+        assert_eq!(lines.concat(), s);
+        for line in &lines {
+            assert!(line.width() <= max || line.chars().count() == 1);
+        }
+    }
+    assert_eq!(Box::<str>::from("abcd").wrap_to_width(2), ["ab", "cd"]);
+}
+
+#[test]
+fn test_hangul_conjoining_jamo() {
+    use super::{UnicodeWidthChar, UnicodeWidthStr};
+
+    // HANGUL SYLLABLE HAN, and HIEUH + A + NIEUN
+    assert_eq!("\u{D55C}".width(), 2);
+    assert_eq!("\u{1112}\u{1161}\u{11AB}".width(), "\u{D55C}".width());
+    assert_eq!("\u{1112}\u{1161}\u{11AB}".width_cjk(), "\u{D55C}".width_cjk());
+    // Leading consonants are wide, and vowels and trailing consonants are zero-width
+    for &c in &['\u{1100}', '\u{115E}', '\u{A960}', '\u{A97C}'] {
+        assert_eq!(c.width(), Some(2), "{:?}", c);
+    }
+    for &c in &['\u{1160}', '\u{11A7}', '\u{11A8}', '\u{11FF}'] {
+        assert_eq!(c.width(), Some(0), "{:?}", c);
+    }
+    // Hangul Jamo Extended-B vowels and trailing consonants
+    for &c in &['\u{D7B0}', '\u{D7C6}', '\u{D7CB}', '\u{D7FB}'] {
+        assert_eq!(c.width(), Some(0), "{:?}", c);
+        assert_eq!(c.width_cjk(), Some(0), "{:?}", c);
+    }
+    // Their unassigned neighbors are measured like other unassigned codepoints
+    assert_eq!('\u{D7C7}'.width(), Some(1));
+    assert_eq!('\u{D7FC}'.width(), Some(1));
+    // An archaic syllable spelled with Extended-A and Extended-B jamo
+    assert_eq!("\u{A960}\u{D7B0}\u{D7CB}".width(), 2);
+}
+
+#[test]
+fn test_checked_width() {
+    use super::{accumulate_width, UnicodeWidthStr};
+
+    for &s in &["", "abc", "Ｈｅｌｌｏ", "\u{231A}\u{FE0E}\u{1F1FA}\u{1F1F8}", "a\u{300}\x1B"] {
+        assert_eq!(s.checked_width(), Some(s.width()), "{:?}", s);
+        assert_eq!(s.saturating_width(), s.width(), "{:?}", s);
+    }
+    // A crafted accumulator close to the limit
+    let near_max = usize::MAX - 2;
+    assert_eq!(accumulate_width("ab", near_max, usize::checked_add), Some(usize::MAX));
+    assert_eq!(accumulate_width("日", near_max, usize::checked_add), Some(usize::MAX));
+    assert_eq!(accumulate_width("日a", near_max, usize::checked_add), None);
+    assert_eq!(accumulate_width("日\u{300}", near_max, usize::checked_add), Some(usize::MAX));
+    let saturating_add = |total: usize, w| Some(total.saturating_add(w));
+    assert_eq!(accumulate_width("日日日", near_max, saturating_add), Some(usize::MAX));
+}
+
+#[test]
+fn test_table_width() {
+    use super::{table_width, UnicodeWidthChar};
+
+    let w: usize = table_width('\u{FF21}', false);
+    assert_eq!(w, 2);
+    assert_eq!(table_width('a', false), 1);
+    assert_eq!(table_width('\u{B1}', false), 1);
+    assert_eq!(table_width('\u{B1}', true), 2);
+    // Control characters aren't special-cased
+    for &c in &['\0', '\x01', '\n', '\x1B', '\x7F', '\u{85}', '\u{9F}'] {
+        assert_eq!(c.width(), if c == '\0' { Some(0) } else { None });
+        assert_eq!(table_width(c, false), 0, "{:?}", c);
+    }
+    // Everything else agrees with the trait methods
+    for c in (0xA0..0x3_0000).filter_map(::core::char::from_u32) {
+        assert_eq!(Some(table_width(c, false)), c.width(), "{:?}", c);
+        assert_eq!(Some(table_width(c, true)), c.width_cjk(), "{:?}", c);
+    }
+}
+
+#[test]
+fn test_byte_offset_at_column() {
+    use super::{UnicodeWidthChar, UnicodeWidthStr};
+
+    // "a日本b": columns 1-2 are 日 at byte 1, and columns 3-4 are 本 at byte 4
+    let s = "a日本b";
+    let offsets: Vec<_> = (0..7).map(|col| s.byte_offset_at_column(col)).collect();
+    assert_eq!(offsets, [Some(0), Some(1), Some(1), Some(4), Some(4), Some(7), None]);
+    let columns: Vec<_> = (0..10).map(|off| s.column_at_byte_offset(off)).collect();
+    assert_eq!(columns, [0, 1, 1, 1, 3, 3, 3, 5, 6, 6]);
+    for col in 0..s.width() {
+        let off = s.byte_offset_at_column(col).unwrap();
+        let start = s.column_at_byte_offset(off);
+        let width = s[off..].chars().next().unwrap().width().unwrap();
+        assert!(start <= col && col < start + width);
+    }
+
+    // Zero-width characters are skipped, and variation selectors widen the emoji before them
+    let s = "e\u{301}\u{2600}\u{FE0F}x";
+    assert_eq!(s.byte_offset_at_column(1), Some(3));
+    assert_eq!(s.byte_offset_at_column(2), Some(3));
+    assert_eq!(s.byte_offset_at_column(3), Some(9));
+    assert_eq!(s.column_at_byte_offset(3), 1);
+    assert_eq!(s.column_at_byte_offset(9), 3);
+    // Both halves of a flag resolve to its first regional indicator
+    let s = "\u{1F1EF}\u{1F1F5}!";
+    assert_eq!(s.byte_offset_at_column(1), Some(0));
+    assert_eq!(s.byte_offset_at_column(2), Some(8));
+    assert_eq!(s.column_at_byte_offset(8), 2);
+    assert_eq!("".byte_offset_at_column(0), None);
+    assert_eq!("".column_at_byte_offset(0), 0);
+}
+
+#[test]
+fn test_variation_selectors_in_derived_widths() {
+    use super::display::WidthBreakdown;
+    use super::{
+        grid_cells, redraw_info, strict_width, width_and_rtl, width_and_scripts,
+        width_pattern_ws_zero, width_with_mark_limit, Aligned, Fit, UnicodeWidthStr,
+    };
+
+    // SUN, which VARIATION SELECTOR-16 widens to 2 columns
+    let sun = "\u{2600}\u{FE0F}";
+    assert_eq!(sun.width(), 2);
+
+    assert_eq!(redraw_info("a\u{2600}", "a\u{2600}\u{FE0F}"), (1, 1));
+    assert_eq!(redraw_info("a\u{2600}\u{FE0F}", "a\u{2600}"), (1, -1));
+    // Replacing the second half of a flag leaves its first half unpaired
+    assert_eq!(redraw_info("\u{1F1FA}\u{1F1F8}", "\u{1F1FA}x"), (2, 1));
+
+    assert_eq!(format!("{}", Fit("\u{2600}\u{FE0F}x", 2)), sun);
+    assert_eq!(format!("[{}]", Fit("\u{2600}\u{FE0F}x", 1)), "[ ]");
+    assert_eq!(format!("[{}]", Fit("\u{1F1FA}\u{1F1F8}", 1)), "[ ]");
+    assert_eq!(format!("[{:.2}]", Aligned("\u{2600}\u{FE0F}x")), "[\u{2600}\u{FE0F}]");
+    assert_eq!(format!("[{:4}]", Aligned(sun)), "[\u{2600}\u{FE0F}  ]");
+
+    assert_eq!(width_and_rtl(sun), (2, false));
+    assert_eq!(width_and_scripts(sun).0, 2);
+    assert_eq!("\u{2600}\u{FE0F}\nab".first_line_width(), 2);
+    assert_eq!("\u{2600}\u{FE0F}\nab".first_line_width_cjk(), 2);
+    assert_eq!("\u{2600}\u{FE0F}  x".collapsed_width(), 4);
+    assert_eq!(" \u{FE0F}".collapsed_width(), 1);
+    assert_eq!(sun.width_clusters(), 2);
+    assert_eq!("\u{231A}\u{FE0E}".width_clusters(), 1);
+    // "a" fills the first column, so the widened sun moves to the second row
+    assert_eq!(grid_cells("a\u{2600}\u{FE0F}", 2), 4);
+    assert_eq!(strict_width("a\u{2600}\u{FE0F}"), Err((1, '\u{2600}')));
+    assert_eq!(strict_width("\u{1F1FA}"), Err((0, '\u{1F1FA}')));
+    assert_eq!(width_pattern_ws_zero(sun), 2);
+    assert_eq!(width_with_mark_limit(sun, 1), 2);
+
+    // The breakdown printed by `assert_width!` adds up to the width
+    let breakdown = format!("{}", WidthBreakdown(sun));
+    let lines: Vec<_> = breakdown.lines().map(str::trim).collect();
+    assert_eq!(lines, ["0: U+2600 '\u{2600}' => 2", r"3: U+FE0F '\u{fe0f}' => 0"]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_variation_selectors_in_width_by_script() {
+    use super::{width_by_script, Script, UnicodeWidthStr};
+
+    for &s in &["\u{2600}\u{FE0F}", "a\u{231A}\u{FE0E}", "\u{1F1FA}\u{1F1F8}\u{1F1EF}"] {
+        assert_eq!(width_by_script(s).values().sum::<usize>(), s.width(), "{:?}", s);
+    }
+    assert_eq!(width_by_script("\u{2600}\u{FE0F}")[&Script::Common], 2);
+}
+
+#[cfg(feature = "ansi")]
+#[test]
+fn test_variation_selectors_in_visible_width() {
+    use super::visible_width;
+
+    assert_eq!(visible_width("\x1b[31m\u{2600}\u{FE0F}\x1b[0m"), 2);
+    assert_eq!(visible_width("\u{2600}\x1b[0m\u{FE0F}"), 2);
+    assert_eq!(visible_width("\u{1F1FA}\x1b[1m\u{1F1F8}"), 2);
+}
+
+#[cfg(feature = "normalization")]
+#[test]
+fn test_variation_selectors_in_composed_width() {
+    use super::composed_width;
+
+    assert_eq!(composed_width("\u{2600}\u{FE0F}"), 2);
+    assert_eq!(composed_width("\u{1F1FA}\u{1F1F8}"), 2);
+}
+
+#[cfg(feature = "segmentation")]
+#[test]
+fn test_cluster_width_variation_selectors() {
+    use super::{cluster_width, grapheme_at_column, width_graphemes, UnicodeWidthStr};
+
+    // HEAVY BLACK HEART and SUN are narrow unless VS16 requests emoji presentation
+    for &s in &["\u{2764}\u{FE0F}", "\u{2600}\u{FE0F}"] {
+        assert_eq!(cluster_width(s, false), 2, "{:?}", s);
+        assert_eq!(cluster_width(s, true), 2, "{:?}", s);
+        assert_eq!(width_graphemes(s, false), s.width(), "{:?}", s);
+    }
+    // WATCH is wide unless VS15 requests text presentation
+    assert_eq!(cluster_width("\u{231A}\u{FE0E}", false), 1);
+    assert_eq!(width_graphemes("a\u{231A}\u{FE0E}", false), 2);
+    // An emoji ZWJ sequence starting with a VS16-qualified emoji: HEART ON FIRE
+    assert_eq!(cluster_width("\u{2764}\u{FE0F}\u{200D}\u{1F525}", false), 2);
+    // A selector after a non-emoji changes nothing
+    assert_eq!(cluster_width("a\u{FE0F}", false), 1);
+    assert_eq!(grapheme_at_column("x\u{2764}\u{FE0F}y", 2), Some((1..7, 2)));
+}
+
+#[test]
+fn test_cell_iterators_sequences() {
+    use super::{CellKind, UnicodeWidthStr};
+
+    let flags = "\u{1F1FA}\u{1F1F8}\u{1F1EF}";
+    let cells: Vec<_> = flags.cell_kinds().collect();
+    assert_eq!(
+        cells,
+        [
+            CellKind::WideLeft('\u{1F1FA}'),
+            CellKind::WideRight,
+            CellKind::ZeroWidth('\u{1F1F8}'),
+            CellKind::WideLeft('\u{1F1EF}'),
+            CellKind::WideRight,
+        ]
+    );
+    let sun: Vec<_> = "\u{2600}\u{FE0F}".cell_kinds().collect();
+    assert_eq!(
+        sun,
+        [CellKind::WideLeft('\u{2600}'), CellKind::WideRight, CellKind::ZeroWidth('\u{FE0F}')]
+    );
+    let watch: Vec<_> = "\u{231A}\u{FE0E}".cell_kinds().collect();
+    assert_eq!(watch, [CellKind::Narrow('\u{231A}'), CellKind::ZeroWidth('\u{FE0E}')]);
+
+    for &s in &[flags, "\u{2600}\u{FE0F}", "a\u{231A}\u{FE0E}b", "\u{2660}\u{FE0E}x"] {
+        let cells = s.cell_kinds().filter(|k| !matches!(k, CellKind::ZeroWidth(_))).count();
+        assert_eq!(cells, s.width(), "{:?}", s);
+        let cells = s.cell_kinds_cjk().filter(|k| !matches!(k, CellKind::ZeroWidth(_))).count();
+        assert_eq!(cells, s.width_cjk(), "{:?}", s);
+        let runs: usize = s.width_runs().map(|(width, count)| width * count).sum();
+        assert_eq!(runs, s.width(), "{:?}", s);
+    }
+
+    assert_eq!(flags.width_runs().collect::<Vec<_>>(), [(2, 1), (0, 1), (2, 1)]);
+    assert_eq!("\u{2600}\u{FE0F}a".width_runs().collect::<Vec<_>>(), [(2, 1), (0, 1), (1, 1)]);
+    assert_eq!(flags.wide_char_columns().collect::<Vec<_>>(), [0, 2]);
+    assert_eq!("a\u{2600}\u{FE0F}\u{2600}".wide_char_columns().collect::<Vec<_>>(), [1]);
+    assert_eq!("\u{231A}\u{FE0E}\u{231A}".wide_char_columns().collect::<Vec<_>>(), [1]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_wrap_to_width_zwj() {
+    use super::{wrapped_line_count, UnicodeWidthStr};
+
+    // MAN, ZWJ, WOMAN, ZWJ, GIRL
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    assert_eq!(family.wrap_to_width(4), [family]);
+    assert_eq!(format!("ab{}", family).wrap_to_width(4), ["ab", family]);
+    assert_eq!(format!("{}c", family).wrap_to_width(6), [family, "c"]);
+    assert_eq!(format!("{}\ncd", family).wrap_to_width(2), [&*format!("{}\n", family), "cd"]);
+    // A joiner at the start of a line, or after a line break, joins nothing
+    assert_eq!("\u{200D}ab".wrap_to_width(1), ["\u{200D}a", "b"]);
+    for &s in &["ab\u{1F468}\u{200D}\u{1F469}cd", "x\u{2600}\u{FE0F}\u{200D}y z", "日本\u{200D}語"] {
+        for max in 1..8 {
+            let lines = s.wrap_to_width(max);
+            assert_eq!(lines.concat(), s);
+            assert_eq!(lines.len(), wrapped_line_count(s, max), "{:?} at {}", s, max);
+        }
+    }
+
+    // Ambiguous characters are 2 columns wide in CJK contexts
+    assert_eq!("\u{B1}\u{B1}\u{B1}".wrap_to_width(2), ["\u{B1}\u{B1}", "\u{B1}"]);
+    assert_eq!("\u{B1}\u{B1}\u{B1}".wrap_to_width_cjk(2), ["\u{B1}", "\u{B1}", "\u{B1}"]);
+    assert_eq!(Box::<str>::from("\u{B1}a").wrap_to_width_cjk(2), ["\u{B1}", "a"]);
+}
+
+#[test]
+fn test_width_traits_provided_methods() {
+    use super::{EastAsianWidth, UnicodeWidthChar, UnicodeWidthStr};
+
+    // Implementing only the required methods is enough to get the rest
+    struct Label(&'static str);
+
+    impl UnicodeWidthStr for Label {
+        fn width(&self) -> usize { self.0.width() }
+
+        fn width_cjk(&self) -> usize { self.0.width_cjk() }
+    }
+
+    impl AsRef<str> for Label {
+        fn as_ref(&self) -> &str { self.0 }
+    }
+
+    let label = Label("a\u{FF22}\u{3B1}");
+    assert_eq!(label.width_u16(), 4);
+    assert_eq!(label.width_range(), (4, 5));
+    assert_eq!(label.truncate_to_width(2), "a");
+    assert_eq!(label.char_width_indices_cjk().map(|(_, _, w)| w).sum::<usize>(), 5);
+
+    #[derive(Clone, Copy)]
+    struct Cell(char);
+
+    impl UnicodeWidthChar for Cell {
+        fn width(self) -> Option<usize> { self.0.width() }
+
+        fn width_cjk(self) -> Option<usize> { self.0.width_cjk() }
+    }
+
+    impl From<Cell> for char {
+        fn from(cell: Cell) -> char { cell.0 }
+    }
+
+    assert_eq!(Cell('\u{FF22}').width_u16(), Some(2));
+    assert_eq!(Cell('\u{FF22}').east_asian_width(), EastAsianWidth::FullWidth);
+    assert_eq!(Cell('\u{FE0F}').variation_selector_index(), Some(16));
+    assert!(Cell('\u{E000}').is_private_use());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_wrap_to_width_break_opportunities() {
+    use super::{wrapped_line_width, UnicodeWidthStr};
+
+    // Breaking at a soft hyphen draws it as a hyphen, which takes up a column
+    let lines = "foo\u{AD}bar".wrap_to_width(4);
+    assert_eq!(lines, ["foo\u{AD}", "bar"]);
+    assert_eq!(lines.iter().map(|line| wrapped_line_width(line)).collect::<Vec<_>>(), [4, 3]);
+    // ...so it isn't used if the hyphen wouldn't fit, and it isn't left at the end of a line
+    assert_eq!("foo\u{AD}bar".wrap_to_width(3), ["foo", "\u{AD}bar"]);
+    // Breaking at a zero width space adds nothing
+    assert_eq!("foo\u{200B}bar".wrap_to_width(3), ["foo\u{200B}", "bar"]);
+    assert_eq!("ab\u{200B}cd\u{200B}ef".wrap_to_width(4), ["ab\u{200B}cd\u{200B}", "ef"]);
+    // The last break opportunity on a line is preferred to breaking inside a word
+    assert_eq!("foo\u{200B}barbaz".wrap_to_width(5), ["foo\u{200B}", "barba", "z"]);
+    assert_eq!("ab\u{AD}cd\u{AD}ef".wrap_to_width(4), ["ab\u{AD}", "cd\u{AD}ef"]);
+    assert_eq!("hyphen\u{AD}ation".wrap_to_width(11), ["hyphen\u{AD}ation"]);
+    // The hyphen is 1 column wide in CJK contexts too
+    assert_eq!("\u{B1}\u{AD}\u{B1}".wrap_to_width(3), ["\u{B1}\u{AD}\u{B1}"]);
+    assert_eq!("\u{B1}\u{AD}\u{B1}".wrap_to_width_cjk(3), ["\u{B1}\u{AD}", "\u{B1}"]);
+
+    let s = "in\u{AD}com\u{AD}pre\u{AD}hen\u{AD}si\u{AD}ble\u{200B}日本\u{200B}語 \u{AD}x";
+    for max in 1..20 {
+        let lines = s.wrap_to_width(max);
+        assert_eq!(lines.concat(), s);
+        let overflowing: Vec<_> = lines
+            .iter()
+            .filter(|line| wrapped_line_width(line) > max && line.width() > 2)
+            .collect();
+        assert!(overflowing.is_empty());
+    }
+}
+
+#[test]
+#[cfg(not(any(feature = "unicode-15-0", feature = "unicode-15-1")))]
+fn test_unicode_version_default() {
+    use super::{Script, UnicodeWidthChar, UNICODE_VERSION};
+
+    assert_eq!(UNICODE_VERSION, (17, 0, 0));
+    assert_eq!(Script::ALL.len(), 175);
+    // U+1FAE9 FACE WITH BAGS UNDER EYES was added in Unicode 16.0
+    assert_eq!('\u{1FAE9}'.width(), Some(2));
+}
+
+#[test]
+#[cfg(feature = "unicode-15-0")]
+fn test_unicode_version_15_0() {
+    use super::{Script, UnicodeWidthChar, UNICODE_VERSION};
+
+    assert_eq!(UNICODE_VERSION, (15, 0, 0));
+    assert_eq!(Script::ALL.len(), 164);
+    assert_eq!('\u{1FAE8}'.width(), Some(2));
+    // The ideographic description characters added in Unicode 15.1 are still unassigned
+    assert_eq!('\u{2FFC}'.width(), Some(1));
+    assert_eq!('\u{31EF}'.width(), Some(1));
+    assert_eq!('\u{1FAE9}'.width(), Some(1));
+}
+
+#[test]
+#[cfg(feature = "unicode-15-1")]
+fn test_unicode_version_15_1() {
+    use super::{Script, UnicodeWidthChar, UNICODE_VERSION};
+
+    assert_eq!(UNICODE_VERSION, (15, 1, 0));
+    assert_eq!(Script::ALL.len(), 164);
+    assert_eq!('\u{1FAE8}'.width(), Some(2));
+    assert_eq!('\u{2FFC}'.width(), Some(2));
+    assert_eq!('\u{31EF}'.width(), Some(2));
+    // Emoji added in Unicode 16.0 are still unassigned
+    assert_eq!('\u{1FAE9}'.width(), Some(1));
+}